@@ -0,0 +1,200 @@
+// benches/collision.rs
+use std::hint::black_box;
+use std::sync::OnceLock;
+
+use bevy::math::{IVec2, Vec2};
+use chapter9::collision::CollisionMap;
+use chapter9::headless::{self, READY_TIMEOUT_TICKS};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const PLAYER_RADIUS: f32 = 24.0;
+
+/// Worlds the maps are generated from, fixed so runs compare.
+const SEEDS: [u64; 2] = [42, 1337];
+
+/// Map sizes in tiles: a single chunk, a 4x4 chunk area, and the full
+/// 10x10 chunk world the game generates.
+const SIZES: [(i32, i32); 3] = [(25, 18), (97, 69), (241, 171)];
+
+/// A map cut from a generated world, with its benchmark label.
+struct Snapshot {
+    label: String,
+    map: CollisionMap,
+}
+
+/// Every seed's world generated once, and every size cut out of the middle
+/// of it, shared by all the benchmark groups.
+fn snapshots() -> &'static [Snapshot] {
+    static SNAPSHOTS: OnceLock<Vec<Snapshot>> = OnceLock::new();
+    SNAPSHOTS.get_or_init(|| {
+        SEEDS
+            .iter()
+            .flat_map(|&seed| {
+                let world = generated_map(seed);
+                SIZES.map(|(width, height)| Snapshot {
+                    label: format!("{}x{}/seed{}", width, height, seed),
+                    map: crop(&world, width, height),
+                })
+            })
+            .collect()
+    })
+}
+
+/// The collision map the game builds for `seed`.
+fn generated_map(seed: u64) -> CollisionMap {
+    let mut app = headless::headless_app(seed);
+    assert!(
+        headless::run_until_ready(&mut app, READY_TIMEOUT_TICKS),
+        "world for seed {seed} was not ready after {READY_TIMEOUT_TICKS} ticks"
+    );
+    app.world_mut()
+        .remove_resource::<CollisionMap>()
+        .expect("a ready world has a collision map")
+}
+
+/// The `width` x `height` cells in the middle of `map`, at the same world
+/// positions.
+fn crop(map: &CollisionMap, width: i32, height: i32) -> CollisionMap {
+    let min = IVec2::new(map.width() - width, map.height() - height) / 2;
+    let origin = map.origin() + min.as_vec2() * map.tile_size();
+    let mut cropped = CollisionMap::new(width, height, map.tile_size(), origin.x, origin.y);
+    for y in 0..height {
+        for x in 0..width {
+            if let Some(tile) = map.get_tile(min.x + x, min.y + y) {
+                cropped.set_tile(x, y, tile);
+            }
+        }
+    }
+    cropped
+}
+
+/// Random points spread over the whole map, fixed per map.
+fn sample_points(map: &CollisionMap, count: usize) -> Vec<Vec2> {
+    let mut rng = StdRng::seed_from_u64(11);
+    (0..count)
+        .map(|_| {
+            let cell = IVec2::new(rng.gen_range(0..map.width()), rng.gen_range(0..map.height()));
+            map.grid_to_world(cell.x, cell.y)
+                + Vec2::new(rng.gen_range(-0.5..0.5), rng.gen_range(-0.5..0.5)) * map.tile_size()
+        })
+        .collect()
+}
+
+/// Cell lookups through the tile type compared with the walkability bitset.
+fn walkability(c: &mut Criterion) {
+    let mut group = c.benchmark_group("walkability");
+    for Snapshot { label, map } in snapshots() {
+        let (width, height) = (map.width(), map.height());
+
+        group.bench_function(BenchmarkId::new("tile_type", label), |b| {
+            b.iter(|| {
+                let mut walkable = 0;
+                for y in 0..height {
+                    for x in 0..width {
+                        walkable += map.get_tile(x, y).is_some_and(|t| t.is_walkable()) as u32;
+                    }
+                }
+                black_box(walkable)
+            })
+        });
+        group.bench_function(BenchmarkId::new("bitset", label), |b| {
+            b.iter(|| {
+                let mut walkable = 0;
+                for y in 0..height {
+                    for x in 0..width {
+                        walkable += map.is_walkable(x, y) as u32;
+                    }
+                }
+                black_box(walkable)
+            })
+        });
+    }
+    group.finish();
+}
+
+/// 1024 player-sized overlap tests per iteration.
+fn circle_clear(c: &mut Criterion) {
+    let mut group = c.benchmark_group("is_circle_clear");
+    for Snapshot { label, map } in snapshots() {
+        let points = sample_points(map, 1024);
+
+        group.bench_function(label, |b| {
+            b.iter(|| {
+                points
+                    .iter()
+                    .filter(|p| map.is_circle_clear(black_box(**p), PLAYER_RADIUS))
+                    .count()
+            })
+        });
+    }
+    group.finish();
+}
+
+/// 256 sweeps per iteration, each about ten tiles long, so most of them
+/// hit something and slide.
+fn sweep(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sweep_circle");
+    for Snapshot { label, map } in snapshots() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let sweeps: Vec<(Vec2, Vec2)> = sample_points(map, 256)
+            .into_iter()
+            .map(|start| {
+                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                (start, start + Vec2::from_angle(angle) * map.tile_size() * 10.0)
+            })
+            .collect();
+
+        group.bench_function(label, |b| {
+            b.iter(|| {
+                for (start, end) in &sweeps {
+                    black_box(map.sweep_circle(*start, *end, PLAYER_RADIUS));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+/// A* between opposite corners (the worst case for an enemy chase), and
+/// the nearest-walkable search used to retarget blocked goals.
+fn pathfinding(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pathfinding");
+    group.sample_size(20);
+    for Snapshot { label, map } in snapshots() {
+        let (width, height) = (map.width(), map.height());
+        let walkable_near = |x: i32, y: i32| {
+            let cell = IVec2::new(x, y);
+            let cell = if map.is_walkable(x, y) {
+                cell
+            } else {
+                map.find_nearest_walkable(cell).expect("generated terrain has open ground near corners")
+            };
+            map.grid_to_world(cell.x, cell.y)
+        };
+        let start = walkable_near(1, 1);
+        let goal = walkable_near(width - 2, height - 2);
+
+        group.bench_function(BenchmarkId::new("find_path_corners", label), |b| {
+            b.iter(|| map.find_path(black_box(start), black_box(goal)))
+        });
+
+        let blocked: Vec<IVec2> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| IVec2::new(x, y)))
+            .filter(|cell| !map.is_walkable(cell.x, cell.y))
+            .take(256)
+            .collect();
+        group.bench_function(BenchmarkId::new("find_nearest_walkable", label), |b| {
+            b.iter(|| {
+                for cell in &blocked {
+                    black_box(map.find_nearest_walkable(*cell));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, walkability, circle_clear, sweep, pathfinding);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+// src/assets/shaders/circular_fog.wgsl
+// Fog of war: clear inside a circle around the player, fading to the fog color
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+struct FogParams {
+    color: vec4<f32>,
+    edge_color: vec4<f32>,
+    center: vec2<f32>,
+    radius: f32,
+    softness: f32,
+    edge_width: f32,
+    light_count: u32,
+    // xy: world position, z: radius
+    lights: array<vec4<f32>, 16>,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> fog: FogParams;
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    let dist = distance(mesh.world_position.xy, fog.center);
+    if abs(dist - fog.radius) < fog.edge_width * 0.5 {
+        return fog.edge_color;
+    }
+    // smoothstep is undefined for equal edges, so a hard edge uses step
+    var density = step(fog.radius, dist);
+    if fog.softness > 0.0 {
+        density = smoothstep(fog.radius, fog.radius + fog.softness, dist);
+    }
+    // Lights are visible through the fog, fading out towards their edge
+    for (var i = 0u; i < min(fog.light_count, 16u); i++) {
+        let light = fog.lights[i];
+        let lit = smoothstep(light.z * 0.5, light.z, distance(mesh.world_position.xy, light.xy));
+        density = min(density, lit);
+    }
+    return vec4<f32>(fog.color.rgb, fog.color.a * density);
+}
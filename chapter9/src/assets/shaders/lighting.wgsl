@@ -0,0 +1,40 @@
+// src/assets/shaders/lighting.wgsl
+// Night darkness with warm point lights punched out of it
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+const MAX_LIGHTS: u32 = 16u;
+
+struct LightingParams {
+    night_color: vec4<f32>,
+    darkness: f32,
+    light_count: u32,
+    // xy: world position, z: radius, w: brightness
+    lights: array<vec4<f32>, 16>,
+    colors: array<vec4<f32>, 16>,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> lighting: LightingParams;
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    var light = 0.0;
+    var glow = vec3<f32>(0.0);
+    for (var i = 0u; i < min(lighting.light_count, MAX_LIGHTS); i++) {
+        let l = lighting.lights[i];
+        let falloff = 1.0 - smoothstep(0.0, l.z, distance(mesh.world_position.xy, l.xy));
+        let strength = falloff * falloff * l.w;
+        light = light + strength;
+        glow = glow + lighting.colors[i].rgb * strength;
+    }
+    light = clamp(light, 0.0, 1.0);
+
+    // Dark where unlit, a faint warm tint where lit
+    let dark = lighting.night_color.a * lighting.darkness * (1.0 - light);
+    let tint = 0.25 * lighting.darkness * light;
+    let alpha = dark + tint;
+    if alpha <= 0.0 {
+        return vec4<f32>(0.0);
+    }
+    let rgb = (lighting.night_color.rgb * dark + min(glow, vec3<f32>(1.0)) * tint) / alpha;
+    return vec4<f32>(rgb, alpha);
+}
@@ -0,0 +1,44 @@
+// src/assets/shaders/outline.wgsl
+// Outline around the opaque pixels of a sprite, drawn on a slightly larger quad
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+
+struct OutlineParams {
+    color: vec4<f32>,
+    uv_min: vec2<f32>,
+    uv_max: vec2<f32>,
+    size: vec2<f32>,
+    thickness: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> outline: OutlineParams;
+@group(#{MATERIAL_BIND_GROUP}) @binding(1) var sprite_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(2) var sprite_sampler: sampler;
+
+// Sprite alpha at a pixel position, transparent outside the sprite
+fn alpha_at(pixel: vec2<f32>) -> f32 {
+    if any(pixel < vec2<f32>(0.0)) || any(pixel >= outline.size) {
+        return 0.0;
+    }
+    let uv = mix(outline.uv_min, outline.uv_max, pixel / outline.size);
+    return textureSampleLevel(sprite_texture, sprite_sampler, uv, 0.0).a;
+}
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    // Quad UVs cover the sprite plus `thickness` on each side
+    let pixel = mesh.uv * (outline.size + 2.0 * outline.thickness) - outline.thickness;
+    if alpha_at(pixel) > 0.5 {
+        return vec4<f32>(0.0);
+    }
+
+    let reach = i32(ceil(outline.thickness));
+    for (var y = -reach; y <= reach; y++) {
+        for (var x = -reach; x <= reach; x++) {
+            let offset = vec2<f32>(f32(x), f32(y));
+            if length(offset) <= outline.thickness && alpha_at(pixel + offset) > 0.5 {
+                return outline.color;
+            }
+        }
+    }
+    return vec4<f32>(0.0);
+}
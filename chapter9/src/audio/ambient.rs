@@ -0,0 +1,189 @@
+// src/audio/ambient.rs
+use bevy::audio::{SpatialScale, Volume};
+use bevy::prelude::*;
+
+use crate::characters::input::Player;
+use crate::collision::{CollisionMap, TileType};
+use crate::config::audio::{
+    AMBIENT_REGION_TILES, CAMPFIRE_RADIUS, FOREST_MIN_TILES, FOREST_RADIUS, LISTENER_EAR_GAP,
+    WATER_MIN_TILES, WATER_RADIUS,
+};
+use crate::placement::{PlaceableKind, Placed};
+use crate::settings::GameSettings;
+use crate::state::WorldScoped;
+
+/// Looping environmental sounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmbientSound {
+    Water,
+    Forest,
+    Campfire,
+}
+
+impl AmbientSound {
+    pub fn path(&self) -> &'static str {
+        match self {
+            AmbientSound::Water => "audio/ambient/water.wav",
+            AmbientSound::Forest => "audio/ambient/forest.wav",
+            AmbientSound::Campfire => "audio/ambient/campfire.wav",
+        }
+    }
+}
+
+/// A point in the world that plays a looping sound near the player.
+///
+/// Volume follows `(1 - distance / radius) ^ falloff`; panning comes from
+/// spatial playback against the player's `SpatialListener`.
+#[derive(Component, Debug)]
+pub struct AmbientEmitter {
+    pub sound: Handle<AudioSource>,
+    pub radius: f32,
+    pub falloff: f32,
+    /// Volume when standing right on the emitter
+    pub volume: f32,
+}
+
+impl AmbientEmitter {
+    pub fn new(sound: Handle<AudioSource>, radius: f32) -> Self {
+        Self {
+            sound,
+            radius,
+            falloff: 2.0,
+            volume: 1.0,
+        }
+    }
+
+    /// Volume heard at `distance`, zero at or beyond the radius.
+    pub fn volume_at(&self, distance: f32) -> f32 {
+        let t = (1.0 - distance / self.radius).clamp(0.0, 1.0);
+        self.volume * t.powf(self.falloff)
+    }
+}
+
+/// Resource to track if ambient emitters have been placed.
+#[derive(Resource, Default, PartialEq, Eq)]
+pub struct AmbientEmittersSpawned(pub bool);
+
+/// Places emitters over lakes and forests.
+///
+/// The map is split into square regions; a region with enough water or
+/// tree tiles gets an emitter at the centroid of those tiles.
+pub fn spawn_ambient_emitters(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    collision_map: Option<Res<CollisionMap>>,
+    mut spawned: ResMut<AmbientEmittersSpawned>,
+) {
+    let Some(map) = collision_map else {
+        return;
+    };
+
+    let water = asset_server.load(AmbientSound::Water.path());
+    let forest = asset_server.load(AmbientSound::Forest.path());
+    let mut count = 0;
+
+    for region_y in (0..map.height()).step_by(AMBIENT_REGION_TILES as usize) {
+        for region_x in (0..map.width()).step_by(AMBIENT_REGION_TILES as usize) {
+            let mut water_tiles = Vec::new();
+            let mut tree_tiles = Vec::new();
+            for y in region_y..(region_y + AMBIENT_REGION_TILES).min(map.height()) {
+                for x in region_x..(region_x + AMBIENT_REGION_TILES).min(map.width()) {
+                    match map.get_tile(x, y) {
+                        Some(TileType::Water) => water_tiles.push(map.grid_to_world(x, y)),
+                        Some(TileType::Tree) => tree_tiles.push(map.grid_to_world(x, y)),
+                        _ => {}
+                    }
+                }
+            }
+
+            let regions = [
+                (water_tiles, WATER_MIN_TILES, &water, WATER_RADIUS),
+                (tree_tiles, FOREST_MIN_TILES, &forest, FOREST_RADIUS),
+            ];
+            for (tiles, min_tiles, sound, radius) in regions {
+                if tiles.len() < min_tiles {
+                    continue;
+                }
+                let centroid = tiles.iter().sum::<Vec2>() / tiles.len() as f32;
+                commands.spawn((
+                    Transform::from_translation(centroid.extend(0.0)),
+                    AmbientEmitter::new(sound.clone(), radius),
+                    WorldScoped,
+                ));
+                count += 1;
+            }
+        }
+    }
+
+    spawned.0 = true;
+    info!("Placed {} ambient emitters", count);
+}
+
+/// Campfires crackle once they are put down.
+pub fn attach_campfire_emitter(
+    add: On<Add, Placed>,
+    placed: Query<&Placed>,
+    asset_server: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    let Ok(placed) = placed.get(add.entity) else {
+        return;
+    };
+    if placed.kind == PlaceableKind::Campfire {
+        let sound = asset_server.load(AmbientSound::Campfire.path());
+        commands
+            .entity(add.entity)
+            .insert(AmbientEmitter::new(sound, CAMPFIRE_RADIUS));
+    }
+}
+
+/// Gives the player ears for spatial audio.
+pub fn attach_listener(mut commands: Commands, players: Query<Entity, Added<Player>>) {
+    for entity in players.iter() {
+        commands
+            .entity(entity)
+            .insert(SpatialListener::new(LISTENER_EAR_GAP));
+    }
+}
+
+/// Starts emitters the player walks into range of, stops the ones left
+/// behind, and sets each playing emitter's volume from its distance.
+pub fn update_ambient_emitters(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    player_query: Query<&GlobalTransform, With<Player>>,
+    emitters: Query<(Entity, &GlobalTransform, &AmbientEmitter, Has<AudioPlayer>)>,
+    mut sinks: Query<(&GlobalTransform, &AmbientEmitter, &mut SpatialAudioSink)>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation().truncate();
+
+    for (entity, transform, emitter, playing) in emitters.iter() {
+        let distance = transform.translation().truncate().distance(player_pos);
+        let in_range = distance < emitter.radius;
+
+        if in_range && !playing {
+            // Scaling by the radius keeps rodio's own distance attenuation out
+            // of the way inside the radius, leaving only its left/right panning
+            let settings = PlaybackSettings::LOOP
+                .with_volume(Volume::SILENT)
+                .with_spatial(true)
+                .with_spatial_scale(SpatialScale::new_2d(1.0 / emitter.radius));
+            commands
+                .entity(entity)
+                .insert((AudioPlayer::new(emitter.sound.clone()), settings));
+        } else if !in_range && playing {
+            commands
+                .entity(entity)
+                .remove::<(AudioPlayer, PlaybackSettings, SpatialAudioSink)>();
+        }
+    }
+
+    // The sink only appears once the sound has loaded
+    for (transform, emitter, mut sink) in sinks.iter_mut() {
+        let distance = transform.translation().truncate().distance(player_pos);
+        sink.set_volume(Volume::Linear(emitter.volume_at(distance) * settings.sfx_gain()));
+    }
+}
@@ -0,0 +1,61 @@
+// src/audio/mod.rs
+mod ambient;
+mod music;
+mod sfx;
+
+use bevy::prelude::*;
+use crate::collision::CollisionMapBuilt;
+use crate::settings::GameSettings;
+use crate::state::GameState;
+
+use music::CurrentMusic;
+use sfx::SfxThrottle;
+
+pub use ambient::AmbientEmittersSpawned;
+pub use sfx::{PlaySfx, SfxId};
+
+/// Plugin for game audio.
+///
+/// Music follows the player's biome, switching to night and combat tracks
+/// when those apply, and crossfades on every change. Ambient emitters over
+/// lakes, forests and placed campfires fade in and pan as the player walks
+/// past them.
+///
+/// Sound effects are requested by triggering `PlaySfx` with a logical id.
+pub struct GameAudioPlugin;
+
+impl Plugin for GameAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CurrentMusic>()
+            .init_resource::<AmbientEmittersSpawned>()
+            .init_resource::<SfxThrottle>()
+            .add_systems(Startup, sfx::load_sfx_registry)
+            .add_observer(sfx::play_sfx)
+            .add_observer(ambient::attach_campfire_emitter)
+            .add_systems(
+                Update,
+                music::select_music_track.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                ambient::spawn_ambient_emitters
+                    .run_if(resource_equals(CollisionMapBuilt(true)))
+                    .run_if(resource_equals(AmbientEmittersSpawned(false)))
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (ambient::attach_listener, sfx::play_footsteps)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Keeps running while paused so volume changes apply immediately
+            .add_systems(
+                Update,
+                (
+                    music::crossfade_music,
+                    ambient::update_ambient_emitters,
+                    sfx::apply_sfx_volume.run_if(resource_changed::<GameSettings>),
+                ),
+            );
+    }
+}
@@ -0,0 +1,118 @@
+// src/audio/music.rs
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use crate::clock::TimeOfDay;
+use crate::config::audio::MUSIC_CROSSFADE_SECS;
+use crate::enemy::PlayerInCombat;
+use crate::map::biome::{Biome, PlayerBiome};
+use crate::settings::GameSettings;
+
+/// Background music tracks, one per mood.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MusicTrack {
+    Meadow,
+    Plains,
+    Wilds,
+    Coast,
+    Night,
+    Combat,
+}
+
+impl MusicTrack {
+    pub fn for_biome(biome: Biome) -> Self {
+        match biome {
+            Biome::Meadow => MusicTrack::Meadow,
+            Biome::Plains => MusicTrack::Plains,
+            Biome::Wilds => MusicTrack::Wilds,
+            Biome::Coast => MusicTrack::Coast,
+        }
+    }
+
+    pub fn path(&self) -> &'static str {
+        match self {
+            MusicTrack::Meadow => "audio/music/meadow.wav",
+            MusicTrack::Plains => "audio/music/plains.wav",
+            MusicTrack::Wilds => "audio/music/wilds.wav",
+            MusicTrack::Coast => "audio/music/coast.wav",
+            MusicTrack::Night => "audio/music/night.wav",
+            MusicTrack::Combat => "audio/music/combat.wav",
+        }
+    }
+}
+
+/// The track that is playing (or fading in).
+#[derive(Resource, Default)]
+pub struct CurrentMusic(pub Option<MusicTrack>);
+
+/// A looping music entity and how far through its fade it is.
+#[derive(Component)]
+pub struct MusicPlayer {
+    /// 0.0 = silent, 1.0 = full music volume
+    pub gain: f32,
+    pub fading_out: bool,
+}
+
+/// Picks the track for the current situation: combat beats night beats biome.
+pub fn select_music_track(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    biome: Res<PlayerBiome>,
+    clock: Res<TimeOfDay>,
+    in_combat: Res<PlayerInCombat>,
+    mut current: ResMut<CurrentMusic>,
+    mut music_players: Query<&mut MusicPlayer>,
+) {
+    let desired = if in_combat.0 {
+        MusicTrack::Combat
+    } else if clock.is_night() {
+        MusicTrack::Night
+    } else {
+        MusicTrack::for_biome(biome.current)
+    };
+
+    if current.0 == Some(desired) {
+        return;
+    }
+
+    for mut music in music_players.iter_mut() {
+        music.fading_out = true;
+    }
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(desired.path())),
+        PlaybackSettings::LOOP.with_volume(Volume::SILENT),
+        MusicPlayer {
+            gain: 0.0,
+            fading_out: false,
+        },
+    ));
+    current.0 = Some(desired);
+    info!("Music: {:?}", desired);
+}
+
+/// Fades tracks in and out and applies the music volume setting.
+pub fn crossfade_music(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    mut music_players: Query<(Entity, &mut MusicPlayer, Option<&mut AudioSink>)>,
+) {
+    let step = time.delta_secs() / MUSIC_CROSSFADE_SECS;
+
+    for (entity, mut music, sink) in music_players.iter_mut() {
+        if music.fading_out {
+            music.gain = (music.gain - step).max(0.0);
+            if music.gain <= 0.0 {
+                commands.entity(entity).despawn();
+                continue;
+            }
+        } else {
+            music.gain = (music.gain + step).min(1.0);
+        }
+
+        // The sink only appears once the track has loaded
+        if let Some(mut sink) = sink {
+            sink.set_volume(Volume::Linear(music.gain * settings.music_gain()));
+        }
+    }
+}
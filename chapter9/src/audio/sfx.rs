@@ -0,0 +1,154 @@
+// src/audio/sfx.rs
+use std::collections::HashMap;
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::characters::input::Player;
+use crate::characters::state::CharacterState;
+use crate::collision::{CollisionMap, TileType};
+use crate::config::audio::SFX_PITCH_VARIATION;
+use crate::settings::GameSettings;
+
+/// Logical sound effect ids; gameplay code names a sound, never a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SfxId {
+    FootstepGrass,
+    FootstepDirt,
+    Pickup,
+    Harvest,
+    Till,
+    Splash,
+    Hit,
+    UiClick,
+}
+
+impl SfxId {
+    const ALL: [SfxId; 8] = [
+        SfxId::FootstepGrass,
+        SfxId::FootstepDirt,
+        SfxId::Pickup,
+        SfxId::Harvest,
+        SfxId::Till,
+        SfxId::Splash,
+        SfxId::Hit,
+        SfxId::UiClick,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SfxId::FootstepGrass => "footstep_grass",
+            SfxId::FootstepDirt => "footstep_dirt",
+            SfxId::Pickup => "pickup",
+            SfxId::Harvest => "harvest",
+            SfxId::Till => "till",
+            SfxId::Splash => "splash",
+            SfxId::Hit => "hit",
+            SfxId::UiClick => "ui_click",
+        }
+    }
+
+    /// Minimum seconds between two plays of this sound
+    pub fn min_interval(&self) -> f32 {
+        match self {
+            SfxId::FootstepGrass | SfxId::FootstepDirt => 0.32,
+            _ => 0.05,
+        }
+    }
+}
+
+/// Request to play a sound effect; every game sound goes through this.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PlaySfx(pub SfxId);
+
+/// Audio handles for every `SfxId`.
+#[derive(Resource)]
+pub struct SfxRegistry {
+    handles: HashMap<SfxId, Handle<AudioSource>>,
+}
+
+/// When each sound last played, for throttling.
+#[derive(Resource, Default)]
+pub struct SfxThrottle {
+    last_played: HashMap<SfxId, f32>,
+}
+
+/// Marks a playing one-shot sound effect.
+#[derive(Component)]
+pub struct SfxPlayer;
+
+pub fn load_sfx_registry(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let handles = SfxId::ALL
+        .into_iter()
+        .map(|id| (id, asset_server.load(format!("audio/sfx/{}.wav", id.name()))))
+        .collect();
+    commands.insert_resource(SfxRegistry { handles });
+}
+
+/// Observer that plays a sound effect, skipping repeats that come too fast.
+pub fn play_sfx(
+    request: On<PlaySfx>,
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    registry: Res<SfxRegistry>,
+    settings: Res<GameSettings>,
+    mut throttle: ResMut<SfxThrottle>,
+) {
+    let id = request.0;
+    let now = time.elapsed_secs();
+    if let Some(&last) = throttle.last_played.get(&id)
+        && now - last < id.min_interval()
+    {
+        return;
+    }
+    let Some(handle) = registry.handles.get(&id) else {
+        return;
+    };
+    throttle.last_played.insert(id, now);
+
+    // Slight pitch changes keep repeated sounds from feeling mechanical
+    let speed = 1.0 + rand::thread_rng().gen_range(-SFX_PITCH_VARIATION..SFX_PITCH_VARIATION);
+    commands.spawn((
+        AudioPlayer::new(handle.clone()),
+        PlaybackSettings::DESPAWN
+            .with_speed(speed)
+            .with_volume(Volume::Linear(settings.sfx_gain())),
+        SfxPlayer,
+    ));
+}
+
+/// Plays footsteps for the ground under a walking or running player.
+pub fn play_footsteps(
+    mut commands: Commands,
+    collision_map: Option<Res<CollisionMap>>,
+    player_query: Query<(&Transform, &CharacterState), With<Player>>,
+) {
+    let Some(map) = collision_map else {
+        return;
+    };
+    let Ok((transform, state)) = player_query.single() else {
+        return;
+    };
+    if !matches!(state, CharacterState::Walking | CharacterState::Running) {
+        return;
+    }
+
+    let cell = map.world_to_grid(transform.translation.truncate());
+    let id = match map.get_tile(cell.x, cell.y) {
+        Some(TileType::Grass | TileType::YellowGrass) => SfxId::FootstepGrass,
+        Some(_) => SfxId::FootstepDirt,
+        None => return,
+    };
+    commands.trigger(PlaySfx(id));
+}
+
+/// Applies volume changes to sound effects that are already playing.
+pub fn apply_sfx_volume(
+    settings: Res<GameSettings>,
+    mut sinks: Query<&mut AudioSink, With<SfxPlayer>>,
+) {
+    for mut sink in sinks.iter_mut() {
+        sink.set_volume(Volume::Linear(settings.sfx_gain()));
+    }
+}
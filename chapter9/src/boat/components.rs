@@ -0,0 +1,12 @@
+// src/boat/components.rs
+use bevy::prelude::*;
+
+/// A boat the player can board from the shore to cross water.
+#[derive(Component, Debug, Default)]
+pub struct Boat;
+
+/// On the player while they ride a boat.
+#[derive(Component, Debug)]
+pub struct Riding {
+    pub boat: Entity,
+}
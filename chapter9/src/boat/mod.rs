@@ -0,0 +1,23 @@
+// src/boat/mod.rs
+mod components;
+mod systems;
+
+use bevy::prelude::*;
+use crate::state::GameState;
+
+pub use components::{Boat, Riding};
+
+/// Plugin for boats, placed on water and boarded from the shore.
+///
+/// Riding puts the player's collider on `CollisionLayer::Water`, so water
+/// and shore are passable and the rest of the land blocks them.
+pub struct BoatPlugin;
+
+impl Plugin for BoatPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(systems::board_or_leave_boat).add_systems(
+            Update,
+            systems::carry_boats.run_if(in_state(GameState::Playing)),
+        );
+    }
+}
@@ -0,0 +1,91 @@
+// src/boat/systems.rs
+use bevy::prelude::*;
+
+use super::components::{Boat, Riding};
+use crate::audio::{PlaySfx, SfxId};
+use crate::characters::collider::Collider;
+use crate::characters::facing::Facing;
+use crate::characters::input::Player;
+use crate::collision::{CollisionLayer, CollisionMap, TileType};
+use crate::interaction::{facing_cell, Interact};
+use crate::placement::{Placed, PlacedObjects};
+use crate::ui::ShowToast;
+
+/// Observer that boards a boat when the player interacts with it from the
+/// shore, or steps off onto the land in front of them while riding.
+pub fn board_or_leave_boat(
+    interact: On<Interact>,
+    mut commands: Commands,
+    collision_map: Option<Res<CollisionMap>>,
+    mut placed: ResMut<PlacedObjects>,
+    mut boats: Query<(&Boat, &Transform, &mut Placed), Without<Player>>,
+    mut player_query: Query<(Entity, &mut Transform, &mut Collider, &Facing), With<Player>>,
+    riders: Query<(), With<Riding>>,
+) {
+    let Some(map) = collision_map else {
+        return;
+    };
+    let Ok((_, boat_transform, mut boat)) = boats.get_mut(interact.target) else {
+        return;
+    };
+    let Ok((player, mut transform, mut collider, facing)) = player_query.single_mut() else {
+        return;
+    };
+    let boat_pos = boat_transform.translation.truncate();
+
+    if riders.contains(player) {
+        // Step off onto the tile ahead, if the player fits there on foot
+        let cell = facing_cell(&map, &transform, facing);
+        let landing = map.grid_to_world(cell.x, cell.y);
+        if !map.is_circle_clear(landing, collider.radius) || placed.is_occupied(cell) {
+            commands.trigger(ShowToast::new("Can't land there"));
+            return;
+        }
+        boat.cell = map.world_to_grid(boat_pos);
+        placed.insert(boat.cell, interact.target);
+
+        let position = landing - collider.offset;
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        collider.layer = CollisionLayer::Land;
+        commands.entity(player).remove::<Riding>();
+        info!("Left the boat at {:?}", boat.cell);
+    } else {
+        let feet = map.world_to_grid(collider.world_position(&transform));
+        if map.get_tile(feet.x, feet.y) != Some(TileType::Shore) {
+            commands.trigger(ShowToast::new("Boats are boarded from the shore"));
+            return;
+        }
+        if !map.is_circle_clear_on(boat_pos, collider.radius, CollisionLayer::Water) {
+            commands.trigger(ShowToast::new("The boat is stuck"));
+            return;
+        }
+        // The boat moves with the player until they leave it
+        placed.remove(boat.cell);
+
+        let position = boat_pos - collider.offset;
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        collider.layer = CollisionLayer::Water;
+        commands.entity(player).insert(Riding {
+            boat: interact.target,
+        });
+        info!("Boarded the boat");
+    }
+    commands.trigger(PlaySfx(SfxId::Splash));
+}
+
+/// Keeps each ridden boat under its rider's feet.
+pub fn carry_boats(
+    riders: Query<(&Transform, &Collider, &Riding)>,
+    mut boats: Query<&mut Transform, (With<Boat>, Without<Riding>)>,
+) {
+    for (transform, collider, riding) in riders.iter() {
+        let Ok(mut boat_transform) = boats.get_mut(riding.boat) else {
+            continue;
+        };
+        let feet = collider.world_position(transform);
+        boat_transform.translation.x = feet.x;
+        boat_transform.translation.y = feet.y;
+    }
+}
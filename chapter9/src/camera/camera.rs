@@ -8,6 +8,20 @@ use crate::config::camera::{CAMERA_LERP_SPEED, CAMERA_Z};
 #[derive(Component)]
 pub struct MainCamera;
 
+/// What drives the main camera.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Default)]
+pub enum CameraMode {
+    #[default]
+    FollowPlayer,
+    /// Debug free-fly; remembers the follow zoom to restore on the way back
+    FreeFly { follow_scale: f32 },
+}
+
+/// Run condition: true while the camera (and player input) follow the player.
+pub fn camera_follows_player(mode: Res<CameraMode>) -> bool {
+    *mode == CameraMode::FollowPlayer
+}
+
 /// Spawn the main 2D camera.
 pub fn setup_camera(mut commands: Commands) {
     commands.spawn((Camera2d::default(), MainCamera));
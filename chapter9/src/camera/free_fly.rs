@@ -0,0 +1,103 @@
+// src/camera/free_fly.rs
+use bevy::input::mouse::{AccumulatedMouseScroll, MouseScrollUnit};
+use bevy::prelude::*;
+
+use super::camera::{CameraMode, MainCamera};
+use crate::characters::input::Player;
+use crate::characters::physics::Velocity;
+use crate::characters::state::CharacterState;
+use crate::config::camera::{
+    CAMERA_Z, FREE_FLY_MAX_SCALE, FREE_FLY_MIN_SCALE, FREE_FLY_SPEED, FREE_FLY_ZOOM_STEP,
+};
+use crate::controls::{ControlBindings, InputAction};
+use crate::fog::FogOverlay;
+
+/// Pixels of trackpad scrolling that count as one wheel line
+const PIXELS_PER_LINE: f32 = 40.0;
+
+/// Switches between following the player and free-fly.
+///
+/// Entering free-fly stops the player and hides the fog; leaving it restores
+/// the follow zoom, shows the fog again and snaps the camera onto the player.
+pub fn toggle_free_fly(
+    mut mode: ResMut<CameraMode>,
+    mut cameras: Query<(&mut Transform, &mut Projection), With<MainCamera>>,
+    player_transforms: Query<&Transform, (With<Player>, Without<MainCamera>)>,
+    mut players: Query<(&mut Velocity, &mut CharacterState), With<Player>>,
+    mut fog: Query<&mut Visibility, With<FogOverlay>>,
+) {
+    let Ok((mut camera_transform, mut projection)) = cameras.single_mut() else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = projection.as_mut() else {
+        return;
+    };
+
+    match *mode {
+        CameraMode::FollowPlayer => {
+            *mode = CameraMode::FreeFly { follow_scale: ortho.scale };
+            // Player input is paused, so leave the player standing rather than walking on
+            for (mut velocity, mut state) in players.iter_mut() {
+                velocity.0 = Vec2::ZERO;
+                state.set_if_neq(CharacterState::Idle);
+            }
+            for mut visibility in fog.iter_mut() {
+                *visibility = Visibility::Hidden;
+            }
+            info!("Free-fly camera on (F6 to return to the player)");
+        }
+        CameraMode::FreeFly { follow_scale } => {
+            *mode = CameraMode::FollowPlayer;
+            ortho.scale = follow_scale;
+            if let Ok(player) = player_transforms.single() {
+                camera_transform.translation = player.translation.truncate().round().extend(CAMERA_Z);
+            }
+            for mut visibility in fog.iter_mut() {
+                *visibility = Visibility::Inherited;
+            }
+            info!("Camera following the player again");
+        }
+    }
+}
+
+/// Pans with the movement keys and zooms with the mouse wheel.
+pub fn fly_camera(
+    time: Res<Time<Real>>,
+    input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<ControlBindings>,
+    scroll: Res<AccumulatedMouseScroll>,
+    mut cameras: Query<(&mut Transform, &mut Projection), With<MainCamera>>,
+) {
+    let Ok((mut transform, mut projection)) = cameras.single_mut() else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = projection.as_mut() else {
+        return;
+    };
+
+    let lines = match scroll.unit {
+        MouseScrollUnit::Line => scroll.delta.y,
+        MouseScrollUnit::Pixel => scroll.delta.y / PIXELS_PER_LINE,
+    };
+    if lines != 0.0 {
+        // Scrolling up zooms in
+        ortho.scale = (ortho.scale * (1.0 - FREE_FLY_ZOOM_STEP).powf(lines))
+            .clamp(FREE_FLY_MIN_SCALE, FREE_FLY_MAX_SCALE);
+    }
+
+    let direction: Vec2 = [
+        (InputAction::MoveLeft, Vec2::NEG_X),
+        (InputAction::MoveRight, Vec2::X),
+        (InputAction::MoveUp, Vec2::Y),
+        (InputAction::MoveDown, Vec2::NEG_Y),
+    ]
+    .into_iter()
+    .filter(|(action, _)| bindings.pressed(*action, &input))
+    .map(|(_, dir)| dir)
+    .sum();
+    if direction != Vec2::ZERO {
+        // Pan speed follows the zoom so the view moves at the same rate on screen
+        let step = direction.normalize() * FREE_FLY_SPEED * ortho.scale * time.delta_secs();
+        transform.translation += step.extend(0.0);
+    }
+}
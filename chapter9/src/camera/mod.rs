@@ -1,24 +1,45 @@
 // src/camera/mod.rs
 mod camera;
+#[cfg(debug_assertions)]
+mod free_fly;
 
+#[cfg(debug_assertions)]
+use bevy::input::common_conditions::input_just_pressed;
 use bevy::prelude::*;
 use crate::state::GameState;
 
 // Re-export public items
-pub use camera::MainCamera;
+pub use camera::{camera_follows_player, CameraMode, MainCamera};
 
 /// Plugin for camera systems.
+///
+/// Debug builds add a free-fly mode on F6 for looking around the generated
+/// map; pressing it again snaps back to the player.
 pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<CameraMode>()
+            .add_systems(
                 Startup,
                 camera::setup_camera,
             )
             .add_systems(
                 Update,
-                camera::follow_camera.run_if(in_state(GameState::Playing)),
+                camera::follow_camera
+                    .run_if(camera_follows_player)
+                    .run_if(in_state(GameState::Playing)),
             );
+
+        #[cfg(debug_assertions)]
+        app.add_systems(
+            Update,
+            (
+                free_fly::toggle_free_fly.run_if(input_just_pressed(KeyCode::F6)),
+                free_fly::fly_camera.run_if(not(camera_follows_player)),
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
     }
 }
\ No newline at end of file
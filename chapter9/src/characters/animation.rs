@@ -3,17 +3,20 @@ use serde::{Deserialize, Serialize};
 use crate::characters::config::{CharacterEntry, AnimationType};
 use crate::characters::facing::Facing;
 use crate::characters::state::CharacterState; 
+use crate::rendering::CullBand;
 
 // Default animation timing (10 FPS = 0.1 seconds per frame)
 pub const DEFAULT_ANIMATION_FRAME_TIME: f32 = 0.1;
 
-#[derive(Component, Default)]
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
 pub struct AnimationController {
     pub current_animation: AnimationType,
 }
 
 
-#[derive(Component, Deref, DerefMut)]
+#[derive(Component, Deref, DerefMut, Reflect)]
+#[reflect(Component)]
 pub struct AnimationTimer(pub Timer);
 
 #[derive(Clone, Copy)]
@@ -103,9 +106,15 @@ pub fn animations_playback(
         &mut AnimationTimer,
         &mut Sprite,
         &CharacterEntry,
+        &CullBand,
     )>,
 ) {
-    for (state, facing, controller, mut timer, mut sprite, config) in query.iter_mut() {
+    for (state, facing, controller, mut timer, mut sprite, config, band) in query.iter_mut() {
+        // Nobody sees off-screen characters animate
+        if !band.is_on_screen() {
+            continue;
+        }
+
         // Don't animate when idle
         if *state == CharacterState::Idle {
             // Ensure idle sprite is at frame 0
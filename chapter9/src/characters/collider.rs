@@ -1,19 +1,24 @@
+use bevy::ecs::entity::EntityHashSet;
 use bevy::prelude::*;
 
-use crate::collision::CollisionMap;
+use crate::collision::{CollisionLayer, CollisionMap};
 use crate::characters::physics::Velocity;
+use crate::config::physics::BUMP_BLOCKED_FRACTION;
 use crate::config::player::{COLLIDER_RADIUS};
 
 /// A circular collider for collision detection.
 /// 
 /// The collider position is offset from the entity's transform,
 /// typically to represent the character's feet position.
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct Collider {
     /// Radius of the circular collider in world units
     pub radius: f32,
     /// Offset from entity center (e.g., Vec2(0, -25) for feet)
     pub offset: Vec2,
+    /// Which tiles the collider can move through
+    pub layer: CollisionLayer,
 }
 
 impl Default for Collider {
@@ -21,10 +26,23 @@ impl Default for Collider {
         Self {
             radius: COLLIDER_RADIUS,
             offset: Vec2::ZERO,
+            layer: CollisionLayer::Land,
         }
     }
 }
 
+/// Lets a character move through terrain and other characters (debug noclip).
+#[derive(Component, Debug, Default)]
+pub struct Noclip;
+
+/// Triggered when a moving character walks into terrain that stops most of its move.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ObstacleHit {
+    pub entity: Entity,
+    /// Grid cell of the tile the move ran into, if one could be found
+    pub cell: Option<IVec2>,
+}
+
 impl Collider {
     /// Get the world position of this collider given an entity's transform.
     pub fn world_position(&self, transform: &Transform) -> Vec2 {
@@ -33,15 +51,18 @@ impl Collider {
 }
 
 pub fn validate_movement(
+    mut commands: Commands,
     map: Option<Res<CollisionMap>>,
     time: Res<Time>,
-    mut query: Query<(&Transform, &mut Velocity, &Collider)>,
+    mut query: Query<(Entity, &Transform, &mut Velocity, &Collider), Without<Noclip>>,
+    mut blocked: Local<EntityHashSet>,
 ) {
     let Some(map) = map else { return };
 
-    for (transform, mut velocity, collider) in query.iter_mut() {
+    for (entity, transform, mut velocity, collider) in query.iter_mut() {
         // Skip if not moving
         if !velocity.is_moving() {
+            blocked.remove(&entity);
             continue;
         }
 
@@ -53,10 +74,20 @@ pub fn validate_movement(
         let desired_pos = current_pos + delta;
 
         // Use swept collision to find valid position
-        let valid_pos = map.sweep_circle(current_pos, desired_pos, collider.radius);
+        let valid_pos = map.sweep_circle_on(current_pos, desired_pos, collider.radius, collider.layer);
 
         // Calculate what velocity would get us to valid_pos
         let actual_delta = valid_pos - current_pos;
+
+        // Report walking into something once, not on every tick spent against it
+        let is_blocked =
+            actual_delta.length_squared() < (delta * BUMP_BLOCKED_FRACTION).length_squared();
+        if is_blocked && blocked.insert(entity) {
+            let cell = map.blocking_cell_on(desired_pos, collider.radius, collider.layer);
+            commands.trigger(ObstacleHit { entity, cell });
+        } else if !is_blocked {
+            blocked.remove(&entity);
+        }
         
         // Only update velocity if collision modified our path
         if (actual_delta - delta).length_squared() > 0.001 {
@@ -70,20 +101,22 @@ pub fn validate_movement(
 }
 
 /// Resolve collisions between entities (player and enemies)
-/// Prevents entities from moving into each other
+/// Prevents entities from moving into each other; `Noclip` entities neither
+/// get blocked nor block others
 pub fn resolve_entity_collisions(
-    mut query: Query<(Entity, &Transform, &mut Velocity, &Collider)>,
+    mut query: Query<(Entity, &Transform, &mut Velocity, &Collider, Has<Noclip>)>,
 ) {
     // Collect all entity positions first to avoid multiple mutable borrows
     let entities: Vec<_> = query
         .iter()
-        .map(|(e, t, _, c)| (e, c.world_position(t), c.radius))
+        .filter(|(_, _, _, _, noclip)| !noclip)
+        .map(|(e, t, _, c, _)| (e, c.world_position(t), c.radius))
         .collect();
 
     // Check each entity against all others
-    for (entity, transform, mut velocity, collider) in query.iter_mut() {
+    for (entity, transform, mut velocity, collider, noclip) in query.iter_mut() {
         // Skip if not moving
-        if !velocity.is_moving() {
+        if noclip || !velocity.is_moving() {
             continue;
         }
 
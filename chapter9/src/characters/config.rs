@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, Reflect)]
 pub enum AnimationType {
     #[default] 
     Walk,
@@ -11,7 +11,7 @@ pub enum AnimationType {
     Jump
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub struct AnimationDefinition {
     pub start_row: usize,
     pub frame_count: usize,
@@ -19,7 +19,8 @@ pub struct AnimationDefinition {
     pub directional: bool, // true = 4 rows (one per direction), false = 1 row
 }
 
-#[derive(Component, Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+#[derive(Component, Asset, Reflect, Debug, Clone, Serialize, Deserialize)]
+#[reflect(Component)]
 pub struct CharacterEntry {
     pub name: String,
     pub max_health: f32,
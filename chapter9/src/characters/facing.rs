@@ -2,7 +2,8 @@ use bevy::prelude::*;
 
 /// The direction a character is facing.
 /// Separate from movement - character can face one way while moving another.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
 pub enum Facing {
     Up,
     Left,
@@ -20,6 +21,16 @@ impl Facing {
         }
     }
     
+    /// Unit vector pointing the way the character faces.
+    pub fn to_vec2(self) -> Vec2 {
+        match self {
+            Facing::Up => Vec2::Y,
+            Facing::Left => Vec2::NEG_X,
+            Facing::Down => Vec2::NEG_Y,
+            Facing::Right => Vec2::X,
+        }
+    }
+
     /// Helper to map direction to row offset (0, 1, 2, 3)
     pub(crate) fn direction_index(self) -> usize {
         match self {
@@ -1,25 +1,31 @@
 use bevy::prelude::*;
+use crate::controls::{ControlBindings, InputAction, InputBuffer};
 use super::{
     state::CharacterState,
     physics::Velocity,
     facing::Facing,
     config::CharacterEntry,
     animation::{AnimationController, AnimationTimer},
+    stamina::Stamina,
 };
+use crate::boat::Riding;
+use crate::config::boat::BOAT_SPEED;
+use crate::status::StatusEffects;
 
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 pub struct Player;
 
-fn read_movement_input(input: &ButtonInput<KeyCode>) -> Vec2 {
-    const MOVEMENT_KEYS: [(KeyCode, Vec2); 4] = [
-        (KeyCode::ArrowLeft, Vec2::NEG_X),
-        (KeyCode::ArrowRight, Vec2::X),
-        (KeyCode::ArrowUp, Vec2::Y),
-        (KeyCode::ArrowDown, Vec2::NEG_Y),
+fn read_movement_input(input: &ButtonInput<KeyCode>, bindings: &ControlBindings) -> Vec2 {
+    const MOVEMENT_ACTIONS: [(InputAction, Vec2); 4] = [
+        (InputAction::MoveLeft, Vec2::NEG_X),
+        (InputAction::MoveRight, Vec2::X),
+        (InputAction::MoveUp, Vec2::Y),
+        (InputAction::MoveDown, Vec2::NEG_Y),
     ];
     
-    MOVEMENT_KEYS.iter()
-        .filter(|(key, _)| input.pressed(*key))
+    MOVEMENT_ACTIONS.iter()
+        .filter(|(action, _)| bindings.pressed(*action, input))
         .map(|(_, dir)| *dir)
         .sum()
 }
@@ -49,21 +55,28 @@ fn determine_new_state(
 
 pub fn handle_player_input(
     input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<ControlBindings>,
+    mut buffer: ResMut<InputBuffer>,
     mut query: Query<(
         &mut CharacterState,
         &mut Velocity,
         &mut Facing,
         &CharacterEntry,
+        &Stamina,
+        &StatusEffects,
+        Has<Riding>,
     ), With<Player>>,
 ) {
-    let Ok((mut state, mut velocity, mut facing, character)) = query.single_mut() else {
+    let Ok((mut state, mut velocity, mut facing, character, stamina, effects, riding)) = query.single_mut() else {
         return;
     };
     
     // Step 1: Read what keys are pressed
-    let direction = read_movement_input(&input);
-    let is_running = input.pressed(KeyCode::ShiftLeft) || input.pressed(KeyCode::ShiftRight);
-    let wants_jump = input.just_pressed(KeyCode::Space);
+    let direction = read_movement_input(&input, &bindings);
+    // No running or jumping out of a boat
+    let is_running = !riding && bindings.pressed(InputAction::Run, &input) && stamina.can_run();
+    // Buffered, so jumping again during the landing goes off as soon as it can
+    let wants_jump = !riding && buffer.is_buffered(InputAction::Jump);
     
     // Step 2: Update facing direction (which way the character looks)
     if direction != Vec2::ZERO {
@@ -76,13 +89,21 @@ pub fn handle_player_input(
     // Step 3: Use our state machine to determine the new state
     // This calls the determine_new_state function we wrote earlier
     let new_state = determine_new_state(*state, direction, is_running, wants_jump);
+    if new_state == CharacterState::Jumping && *state != CharacterState::Jumping {
+        buffer.consume(InputAction::Jump);
+    }
     if *state != new_state {
         *state = new_state;  // This triggers Changed<CharacterState>!
     }
     
     // Step 4: Calculate velocity based on state
     // Idle and Jumping = no movement, Walking/Running = movement
-    *velocity = super::physics::calculate_velocity(*state, direction, character);
+    let base = if riding && *state == CharacterState::Walking {
+        Velocity(direction.normalize_or_zero() * BOAT_SPEED)
+    } else {
+        super::physics::calculate_velocity(*state, direction, character)
+    };
+    *velocity = Velocity(base.0 * effects.modifiers().speed);
 }
 
 pub fn update_jump_state(
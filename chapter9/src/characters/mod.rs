@@ -6,14 +6,16 @@ pub mod facing;
 pub mod input; 
 pub mod physics;  
 pub mod collider;
-mod rendering;
+pub mod stamina;
 
 use bevy::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
 use config::CharactersList;
+use crate::camera::camera_follows_player;
 use crate::state::GameState;
 use spawn::PlayerSpawned; // Add this line
 use crate::collision::CollisionMapBuilt; // Add this line
+use crate::config::physics::FIXED_TICK_HZ;
 
 pub struct CharactersPlugin;
 
@@ -22,6 +24,7 @@ impl Plugin for CharactersPlugin {
         app.add_plugins(RonAssetPlugin::<CharactersList>::new(&["characters.ron"]))
             .init_resource::<spawn::CurrentCharacterIndex>()
             .init_resource::<PlayerSpawned>() // Add this line
+            .insert_resource(Time::<Fixed>::from_hz(FIXED_TICK_HZ))
             // Load character assets at startup (before collision map)
             .add_systems(Startup, spawn::load_character_assets) // Change function name
             // Spawn player at valid position AFTER collision map is built
@@ -34,19 +37,36 @@ impl Plugin for CharactersPlugin {
             )
             .add_systems(
                 Update,
+                spawn::switch_character.run_if(in_state(GameState::Playing)),
+            )
+            // Input, stamina, animation (which ends jumps) and movement all
+            // run at a fixed rate so they behave the same at any frame rate,
+            // and a replay lands where its recording did
+            .add_systems(
+                FixedUpdate,
                 (
-                    input::handle_player_input,
-                    spawn::switch_character,
+                    input::handle_player_input.run_if(camera_follows_player),
+                    stamina::update_stamina,
                     input::update_jump_state,
                     animation::on_state_change_update_animation,
+                    animation::animations_playback,
+                    physics::record_previous_positions,
                     collider::validate_movement,
                     collider::resolve_entity_collisions,
                     physics::apply_velocity,
-                    rendering::update_character_depth,
-                    animation::animations_playback,
                 )
                     .chain()
                     .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    physics::restore_simulated_positions
+                        .in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+                    physics::interpolate_rendered_positions
+                        .in_set(RunFixedMainLoopSystems::AfterFixedMainLoop),
+                )
+                    .run_if(in_state(GameState::Playing)),
             );
     }
 }
\ No newline at end of file
@@ -4,7 +4,9 @@ use super::{state::CharacterState, config::CharacterEntry};
 /// Linear velocity in world units per second.
 /// Systems that want to move an entity modify this.
 /// A physics system reads this to update Transform.
-#[derive(Component, Debug, Clone, Copy, Default, Deref, DerefMut)]
+#[derive(Component, Debug, Clone, Copy, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component)]
+#[require(InterpolatedPosition)]
 pub struct Velocity(pub Vec2);
 
 impl Velocity {
@@ -42,4 +44,57 @@ pub fn apply_velocity(
             transform.translation += velocity.0.extend(0.0) * time.delta_secs();
         }
     }
-}
\ No newline at end of file
+}
+
+/// Position of a moving entity at the last two fixed ticks.
+///
+/// Movement runs in `FixedUpdate` on the real position; between ticks the
+/// `Transform` shows a blend of the two, so motion looks smooth at any
+/// frame rate while the simulation stays frame-rate independent.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct InterpolatedPosition {
+    previous: Vec2,
+    current: Vec2,
+    /// What we last wrote to the Transform, to spot moves made outside the fixed loop
+    rendered: Vec2,
+}
+
+/// Puts the simulated position back into the Transform before the fixed loop.
+///
+/// If something else moved the entity since the last frame (spawning,
+/// loading a save, switching characters), that position is taken as-is
+/// without interpolating from the old one.
+pub fn restore_simulated_positions(
+    mut query: Query<(&mut Transform, &mut InterpolatedPosition)>,
+) {
+    for (mut transform, mut position) in query.iter_mut() {
+        let shown = transform.translation.truncate();
+        if shown != position.rendered {
+            position.previous = shown;
+            position.current = shown;
+        }
+        transform.translation = position.current.extend(transform.translation.z);
+    }
+}
+
+/// Remembers where each entity was before this tick moves it.
+pub fn record_previous_positions(mut query: Query<(&Transform, &mut InterpolatedPosition)>) {
+    for (transform, mut position) in query.iter_mut() {
+        position.previous = transform.translation.truncate();
+    }
+}
+
+/// Shows each entity part way between its last two ticks, by how far
+/// real time has run past the last tick.
+pub fn interpolate_rendered_positions(
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut InterpolatedPosition)>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+    for (mut transform, mut position) in query.iter_mut() {
+        position.current = transform.translation.truncate();
+        position.rendered = position.previous.lerp(position.current, alpha);
+        transform.translation = position.rendered.extend(transform.translation.z);
+    }
+}
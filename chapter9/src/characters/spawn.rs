@@ -6,12 +6,18 @@ use crate::characters::state::CharacterState;  // Line update alert
 use crate::characters::physics::Velocity;  // Line update alert
 use crate::characters::facing::Facing;  // Line update alert
 use crate::characters::collider::Collider; 
-use crate::config::player::{PLAYER_SCALE, PLAYER_Z_POSITION}; 
+use crate::characters::stamina::Stamina;
+use crate::config::player::{PLAYER_SCALE, PLAYER_Z_POSITION, STAMINA_MAX}; 
 use crate::combat::PlayerCombat;
 use crate::collision::CollisionMap;
 use crate::combat::Health;
+use crate::rendering::YSort;
+use crate::fog::VisionRadius;
+use crate::status::StatusEffects;
+use crate::state::WorldScoped;
 
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
 pub struct CurrentCharacterIndex {
     pub index: usize,
 }
@@ -21,7 +27,7 @@ pub struct CharactersListResource {
     pub handle: Handle<CharactersList>,
 }
 
-fn create_character_atlas_layout(
+pub fn create_character_atlas_layout(
     atlas_layouts: &mut ResMut<Assets<TextureAtlasLayout>>,
     character_entry: &CharacterEntry,
 ) -> Handle<TextureAtlasLayout> {
@@ -101,9 +107,20 @@ pub fn switch_character(
     );
 }
 
+/// Vertical offset from a character's sprite center to its feet, used for Y-sorting.
+pub fn character_feet_offset(character_entry: &CharacterEntry) -> f32 {
+    -(character_entry.tile_size as f32 * PLAYER_SCALE) / 2.0
+}
+
 #[derive(Resource, Default, PartialEq, Eq)]
 pub struct PlayerSpawned(pub bool);
 
+/// Where a new player appears on this map: the world centre, or the
+/// nearest walkable tile if the centre is blocked.
+pub fn spawn_point(collision_map: &CollisionMap) -> Vec2 {
+    get_valid_spawn_position(collision_map, Vec2::ZERO)
+}
+
 // Add this helper function after create_character_atlas_layout
 /// Get a valid spawn position, checking collision map and adjusting if needed
 fn get_valid_spawn_position(collision_map: &CollisionMap, desired_pos: Vec2) -> Vec2 {
@@ -187,8 +204,7 @@ pub fn spawn_player_at_valid_position(
     let character_entry = &characters_list.characters[character_index.index];
     
     // Calculate valid spawn position
-    let desired_pos = Vec2::new(0.0, 0.0);
-    let valid_pos = get_valid_spawn_position(&collision_map, desired_pos);
+    let valid_pos = spawn_point(&collision_map);
     
     // Create sprite
     let texture = asset_server.load(&character_entry.texture_path);
@@ -206,13 +222,20 @@ pub fn spawn_player_at_valid_position(
         Velocity::default(),
         Facing::default(),
         Collider::default(),
+        YSort::new(character_feet_offset(character_entry)),
         PlayerCombat::default(),
-        Health::new(character_entry.max_health),
+        (
+            Health::new(character_entry.max_health),
+            Stamina::new(STAMINA_MAX),
+            VisionRadius::default(),
+            StatusEffects::default(),
+        ),
         AnimationTimer(Timer::from_seconds(
             DEFAULT_ANIMATION_FRAME_TIME,
             TimerMode::Repeating,
         )),
         character_entry.clone(),
+        WorldScoped,
     ));
     
     // Mark player as spawned
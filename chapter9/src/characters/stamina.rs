@@ -0,0 +1,62 @@
+// src/characters/stamina.rs
+use bevy::prelude::*;
+
+use super::input::Player;
+use super::state::CharacterState;
+use crate::config::player::{STAMINA_DRAIN_PER_SEC, STAMINA_RECOVER_RATIO, STAMINA_REGEN_PER_SEC};
+use crate::settings::Difficulty;
+use crate::status::StatusEffects;
+
+/// Stamina spent by running and regained while not running.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Stamina {
+    pub current: f32,
+    pub max: f32,
+    /// Set when stamina runs out; running is blocked until it recovers
+    pub exhausted: bool,
+}
+
+impl Stamina {
+    pub fn new(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            exhausted: false,
+        }
+    }
+
+    /// Returns stamina as a ratio in [0, 1].
+    pub fn ratio(&self) -> f32 {
+        self.current / self.max
+    }
+
+    pub fn can_run(&self) -> bool {
+        !self.exhausted && self.current > 0.0
+    }
+}
+
+/// Drains stamina while the player runs and regenerates it otherwise,
+/// faster or slower depending on status effects. The difficulty scales
+/// the drain.
+pub fn update_stamina(
+    time: Res<Time>,
+    difficulty: Res<Difficulty>,
+    mut query: Query<(&mut Stamina, &CharacterState, &StatusEffects), With<Player>>,
+) {
+    let dt = time.delta_secs();
+    for (mut stamina, state, effects) in query.iter_mut() {
+        if *state == CharacterState::Running {
+            stamina.current = (stamina.current - STAMINA_DRAIN_PER_SEC * difficulty.stamina_drain() * dt).max(0.0);
+            if stamina.current <= 0.0 {
+                stamina.exhausted = true;
+            }
+        } else if stamina.current < stamina.max {
+            let regen = STAMINA_REGEN_PER_SEC * effects.modifiers().stamina_regen;
+            stamina.current = (stamina.current + regen * dt).min(stamina.max);
+            if stamina.exhausted && stamina.ratio() >= STAMINA_RECOVER_RATIO {
+                stamina.exhausted = false;
+            }
+        }
+    }
+}
@@ -2,7 +2,8 @@
 use bevy::prelude::*;
 
 /// Character states. Only one can be active at a time.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
 pub enum CharacterState {
     #[default]
     Idle,
@@ -0,0 +1,97 @@
+// src/clock/game_clock.rs
+use std::ops::Deref;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::config::clock::{MAX_TIME_SCALE, MIN_TIME_SCALE};
+use crate::state::GameState;
+
+/// Gameplay time: stops outside `GameState::Playing` and runs at `scale`
+/// times real time.
+///
+/// Gameplay systems (crops, regrowth, the day/night cycle, ...) read their
+/// delta from here instead of `Time`, so pausing or opening a menu freezes
+/// them and the debug time scale speeds them all up together. They run in
+/// `FixedUpdate`; movement and UI still use `Time`.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct GameClock {
+    delta: Duration,
+    scale: f32,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self {
+            delta: Duration::ZERO,
+            scale: 1.0,
+        }
+    }
+}
+
+impl GameClock {
+    /// Game time that passed this fixed tick inside the fixed loop, or this
+    /// frame outside it; zero while paused.
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    pub fn delta_secs(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Set how fast game time runs, clamped to `MIN_TIME_SCALE..=MAX_TIME_SCALE`.
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.clamp(MIN_TIME_SCALE, MAX_TIME_SCALE);
+    }
+}
+
+/// Advances the game clock from `Time`, which is the fixed step inside the
+/// fixed loop and the frame's delta outside it, unless the game isn't being
+/// played.
+pub fn tick_game_clock(time: Res<Time>, state: Res<State<GameState>>, mut clock: ResMut<GameClock>) {
+    clock.delta = if *state.get() == GameState::Playing {
+        time.delta().mul_f32(clock.scale)
+    } else {
+        Duration::ZERO
+    };
+}
+
+/// A `Timer` that only advances with the `GameClock`.
+///
+/// Derefs to `Timer` for reading; `tick` takes the clock rather than a
+/// duration so gameplay timers can't accidentally run on real time.
+#[derive(Debug, Clone, Reflect)]
+pub struct GameTimer(Timer);
+
+impl GameTimer {
+    pub fn from_seconds(seconds: f32, mode: TimerMode) -> Self {
+        Self(Timer::from_seconds(seconds, mode))
+    }
+
+    pub fn tick(&mut self, clock: &GameClock) -> &Self {
+        self.0.tick(clock.delta());
+        self
+    }
+
+    pub fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    pub fn set_elapsed(&mut self, elapsed: Duration) {
+        self.0.set_elapsed(elapsed);
+    }
+}
+
+impl Deref for GameTimer {
+    type Target = Timer;
+
+    fn deref(&self) -> &Timer {
+        &self.0
+    }
+}
@@ -0,0 +1,30 @@
+// src/clock/mod.rs
+mod game_clock;
+mod time_of_day;
+
+use bevy::prelude::*;
+use crate::state::GameState;
+
+pub use game_clock::{GameClock, GameTimer};
+pub use time_of_day::TimeOfDay;
+
+/// Plugin for the pausable game clock and the in-game day/night cycle.
+pub struct ClockPlugin;
+
+impl Plugin for ClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameClock>()
+            .init_resource::<TimeOfDay>()
+            // Like `Time`, the clock holds the fixed step inside the fixed
+            // loop and the frame's delta after it
+            .add_systems(FixedPreUpdate, game_clock::tick_game_clock)
+            .add_systems(
+                RunFixedMainLoop,
+                game_clock::tick_game_clock.in_set(RunFixedMainLoopSystems::AfterFixedMainLoop),
+            )
+            .add_systems(
+                FixedUpdate,
+                time_of_day::advance_time_of_day.run_if(in_state(GameState::Playing)),
+            );
+    }
+}
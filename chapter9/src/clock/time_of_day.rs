@@ -0,0 +1,39 @@
+// src/clock/time_of_day.rs
+use bevy::prelude::*;
+
+use super::GameClock;
+use crate::config::clock::{
+    DAY_LENGTH_SECS, NIGHT_END_HOUR, NIGHT_START_HOUR, START_HOUR, TWILIGHT_HOURS,
+};
+
+/// In-game time of day in hours, wrapping from 24 back to 0.
+#[derive(Resource, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct TimeOfDay {
+    pub hours: f32,
+}
+
+impl Default for TimeOfDay {
+    fn default() -> Self {
+        Self { hours: START_HOUR }
+    }
+}
+
+impl TimeOfDay {
+    pub fn is_night(&self) -> bool {
+        self.hours >= NIGHT_START_HOUR || self.hours < NIGHT_END_HOUR
+    }
+
+    /// How dark it is: 0 by day, 1 at night, fading over `TWILIGHT_HOURS`
+    /// at dusk and dawn.
+    pub fn darkness(&self) -> f32 {
+        let dusk = (self.hours - NIGHT_START_HOUR) / TWILIGHT_HOURS + 0.5;
+        let dawn = (NIGHT_END_HOUR - self.hours) / TWILIGHT_HOURS + 0.5;
+        dusk.max(dawn).clamp(0.0, 1.0)
+    }
+}
+
+/// Advances the clock so a full day takes `DAY_LENGTH_SECS` of game time.
+pub fn advance_time_of_day(game_clock: Res<GameClock>, mut clock: ResMut<TimeOfDay>) {
+    clock.hours = (clock.hours + game_clock.delta_secs() * 24.0 / DAY_LENGTH_SECS) % 24.0;
+}
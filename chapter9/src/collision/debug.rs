@@ -1,12 +1,44 @@
 use bevy::prelude::*;
-use super::CollisionMap;
+use super::{CollisionMap, TileType};
 use crate::characters::input::Player;
-use crate::characters::collider::Collider;
+use crate::characters::collider::{Collider, ObstacleHit};
 
 /// Resource to toggle debug visualization.
 #[derive(Resource, Default)]
 pub struct DebugCollisionEnabled(pub bool);
 
+/// Resource to toggle the pathfinding cost overlay.
+#[derive(Resource, Default)]
+pub struct DebugCostFieldEnabled(pub bool);
+
+/// Cell of the tile that stopped the player's last blocked move.
+#[derive(Resource, Default)]
+pub struct LastBlockedCell(pub Option<IVec2>);
+
+/// Overlay color for each kind of tile: faint for walkable ground,
+/// stronger for hazards and what blocks movement.
+fn tile_color(tile: TileType) -> Color {
+    match tile {
+        TileType::Empty => Color::srgba(0.6, 0.6, 0.6, 0.15),
+        TileType::Dirt | TileType::TilledSoil => Color::srgba(0.7, 0.45, 0.2, 0.25),
+        TileType::Grass | TileType::YellowGrass => Color::srgba(0.0, 1.0, 0.0, 0.25),
+        TileType::Shore => Color::srgba(1.0, 0.9, 0.4, 0.35),
+        TileType::Water => Color::srgba(0.1, 0.4, 1.0, 0.45),
+        TileType::Tree | TileType::Rock | TileType::Fence => Color::srgba(1.0, 0.0, 0.0, 0.4),
+        TileType::Thorns | TileType::Mud => Color::srgba(0.8, 0.0, 0.9, 0.4),
+    }
+}
+
+pub fn record_blocked_cell(
+    hit: On<ObstacleHit>,
+    players: Query<(), With<Player>>,
+    mut last: ResMut<LastBlockedCell>,
+) {
+    if players.contains(hit.entity) && hit.cell.is_some() {
+        last.0 = hit.cell;
+    }
+}
+
 pub fn toggle_debug_collision(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut debug_enabled: ResMut<DebugCollisionEnabled>,
@@ -21,6 +53,51 @@ pub fn toggle_debug_collision(
     }
 }
 
+pub fn toggle_debug_cost_field(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut enabled: ResMut<DebugCostFieldEnabled>,
+) {
+    if keyboard.just_pressed(KeyCode::F10) {
+        enabled.0 = !enabled.0;
+        if enabled.0 {
+            info!("Pathfinding cost overlay ENABLED (F10 to toggle)");
+        } else {
+            info!("Pathfinding cost overlay disabled");
+        }
+    }
+}
+
+/// Shades each walkable tile from green (cheapest) to red (dearest) by its
+/// pathfinding cost; blocked tiles are left out.
+pub fn debug_draw_cost_field(
+    map: Option<Res<CollisionMap>>,
+    enabled: Res<DebugCostFieldEnabled>,
+    mut gizmos: Gizmos,
+) {
+    if !enabled.0 {
+        return;
+    }
+
+    let Some(map) = map else { return };
+
+    // Highest cost of any walkable tile (shore)
+    const MAX_COST: f32 = 20.0;
+    let min_cost = TileType::CHEAPEST_MOVEMENT_COST as f32;
+    let tile_size = map.tile_size();
+
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            let Some(cost) = map.movement_cost(x, y) else { continue };
+            let t = ((cost as f32 - min_cost) / (MAX_COST - min_cost)).clamp(0.0, 1.0);
+            gizmos.rect_2d(
+                map.grid_to_world(x, y),
+                Vec2::splat(tile_size * 0.5),
+                Color::srgba(t, 1.0 - t, 0.0, 0.6),
+            );
+        }
+    }
+}
+
 pub fn debug_draw_collision(
     map: Option<Res<CollisionMap>>,
     debug_enabled: Res<DebugCollisionEnabled>,
@@ -35,25 +112,40 @@ pub fn debug_draw_collision(
     let tile_size = map.tile_size();
     let origin = map.origin();
 
-    // Draw each tile
+    // Draw each tile, tinted by its type
     for y in 0..map.height() {
         for x in 0..map.width() {
+            let Some(tile) = map.get_tile(x, y) else { continue };
             let world_pos = Vec2::new(
                 origin.x + (x as f32 + 0.5) * tile_size,
                 origin.y + (y as f32 + 0.5) * tile_size,
             );
 
-            let color = if map.is_walkable(x, y) {
-                Color::srgba(0.0, 1.0, 0.0, 0.25)  // Green, 25% opacity
-            } else {
-                Color::srgba(1.0, 0.0, 0.0, 0.4)   // Red, 40% opacity
-            };
-
             gizmos.rect_2d(
                 world_pos,
                 Vec2::splat(tile_size * 0.9),
-                color,
+                tile_color(tile),
             );
+
+            // Outline where walkable ground meets water: the shore edge
+            // conversion makes this the real limit of how far into water you can walk
+            if tile != TileType::Water {
+                continue;
+            }
+            let half = tile_size / 2.0;
+            for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                if !map.is_walkable(x + dx, y + dy) {
+                    continue;
+                }
+                let normal = Vec2::new(dx as f32, dy as f32);
+                let edge_center = world_pos + normal * half;
+                let along = normal.perp() * half;
+                gizmos.line_2d(
+                    edge_center - along,
+                    edge_center + along,
+                    Color::srgb(1.0, 0.9, 0.4),
+                );
+            }
         }
     }
 }
@@ -62,6 +154,7 @@ pub fn debug_player_position(
     player_query: Query<(&Transform, &Collider), With<Player>>,
     map: Option<Res<CollisionMap>>,
     debug_enabled: Res<DebugCollisionEnabled>,
+    last_blocked: Res<LastBlockedCell>,
     mut gizmos: Gizmos,
 ) {
     if !debug_enabled.0 {
@@ -83,6 +176,19 @@ pub fn debug_player_position(
     // Draw actual collider circle
     gizmos.circle_2d(collider_pos, collider.radius, Color::srgb(0.0, 1.0, 1.0));
 
+    // Trees and rocks test against a smaller circle so corners can be cut
+    let obstacle_radius = collider.radius + TileType::Tree.collision_adjustment() * map.tile_size();
+    if obstacle_radius > 0.0 && obstacle_radius != collider.radius {
+        gizmos.circle_2d(collider_pos, obstacle_radius, Color::srgba(0.0, 1.0, 1.0, 0.5));
+    }
+
+    // Outline the exact tile that stopped the last blocked move
+    if let Some(cell) = last_blocked.0 {
+        let cell_center = map.grid_to_world(cell.x, cell.y);
+        gizmos.rect_2d(cell_center, Vec2::splat(map.tile_size()), Color::srgb(1.0, 0.0, 1.0));
+        gizmos.line_2d(collider_pos, cell_center, Color::srgba(1.0, 0.0, 1.0, 0.5));
+    }
+
     // Draw current grid cell outline
     if map.in_bounds(grid.x, grid.y) {
         let cell_center = map.grid_to_world(grid.x, grid.y);
@@ -1,15 +1,30 @@
 use bevy::prelude::*;
-use super::TileType;
+use super::{CollisionLayer, TileType};
 use pathfinding::prelude::astar;
+use serde::{Deserialize, Serialize};
 
 /// Collision map resource that stores walkability information.
 /// Provides efficient spatial queries for movement validation.
-#[derive(Resource)]
+///
+/// The grid and its size are hidden from reflection: edits that bypass
+/// `set_tile` would leave `walkable` stale, and a changed size would index
+/// past the end of `tiles`.
+#[derive(Resource, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
 pub struct CollisionMap {
     /// Flat array of tile types (row-major order)
+    #[reflect(ignore)]
     tiles: Vec<TileType>,
+    /// One bit per tile, set when walkable (same indexing as `tiles`).
+    /// Kept in sync by `set_tile` so hot queries read a bit instead of
+    /// re-deriving walkability from the tile type.
+    #[serde(skip)]
+    #[reflect(ignore)]
+    walkable: Vec<u64>,
     /// Grid dimensions
+    #[reflect(ignore)]
     width: i32,
+    #[reflect(ignore)]
     height: i32,
     /// Size of each tile in world units
     tile_size: f32,
@@ -24,6 +39,8 @@ impl CollisionMap {
         let size = (width * height) as usize;
         Self {
             tiles: vec![TileType::Empty; size],
+            // Empty tiles are walkable
+            walkable: vec![u64::MAX; size.div_ceil(64)],
             width,
             height,
             tile_size,
@@ -32,12 +49,56 @@ impl CollisionMap {
         }
     }
 
+    /// Serialize the grid (tiles, dimensions, tile size, origin) as JSON.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Rebuild a map written by `to_json`.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        let mut map: Self = serde_json::from_str(json)?;
+        if map.width < 0 || map.height < 0 || map.tiles.len() != (map.width * map.height) as usize {
+            return Err(serde::de::Error::custom(format!(
+                "expected {}x{} tiles, found {}",
+                map.width,
+                map.height,
+                map.tiles.len()
+            )));
+        }
+        map.rebuild_walkable();
+        Ok(map)
+    }
+
+    /// Recompute the walkability bitset from `tiles`.
+    fn rebuild_walkable(&mut self) {
+        self.walkable = vec![0; self.tiles.len().div_ceil(64)];
+        for (idx, tile) in self.tiles.iter().enumerate() {
+            if tile.is_walkable() {
+                self.walkable[idx / 64] |= 1 << (idx % 64);
+            }
+        }
+    }
+
     /// Convert 2D grid coordinates to 1D array index.
     #[inline]
     fn xy_to_idx(&self, x: i32, y: i32) -> usize {
         (y * self.width + x) as usize
     }
 
+    #[inline]
+    fn walkable_bit(&self, idx: usize) -> bool {
+        self.walkable[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    /// Land reads the walkability bit; other layers check the tile type.
+    #[inline]
+    fn is_passable(&self, idx: usize, layer: CollisionLayer) -> bool {
+        match layer {
+            CollisionLayer::Land => self.walkable_bit(idx),
+            CollisionLayer::Water => self.tiles[idx].is_passable(layer),
+        }
+    }
+
     /// Check if grid coordinates are within bounds.
     #[inline]
     pub fn in_bounds(&self, x: i32, y: i32) -> bool {
@@ -71,12 +132,28 @@ impl CollisionMap {
         if self.in_bounds(x, y) {
             let idx = self.xy_to_idx(x, y);
             self.tiles[idx] = tile_type;
+
+            let mask = 1 << (idx % 64);
+            if tile_type.is_walkable() {
+                self.walkable[idx / 64] |= mask;
+            } else {
+                self.walkable[idx / 64] &= !mask;
+            }
         }
     }
 
     /// Check if a grid position is walkable.
     pub fn is_walkable(&self, x: i32, y: i32) -> bool {
-        self.get_tile(x, y).map_or(false, |t| t.is_walkable())
+        self.in_bounds(x, y) && self.walkable_bit(self.xy_to_idx(x, y))
+    }
+
+    /// Pathfinding cost of crossing a tile (see `TileType::movement_cost`),
+    /// or `None` if it is blocked or out of bounds.
+    pub fn movement_cost(&self, x: i32, y: i32) -> Option<u32> {
+        if !self.is_walkable(x, y) {
+            return None;
+        }
+        self.tiles[self.xy_to_idx(x, y)].movement_cost()
     }
 
     /// Check if a world position is walkable.
@@ -116,6 +193,11 @@ impl CollisionMap {
     }
 
     pub fn is_circle_clear(&self, center: Vec2, radius: f32) -> bool {
+        self.is_circle_clear_on(center, radius, CollisionLayer::Land)
+    }
+
+    /// Like `is_circle_clear`, for a collider on `layer`.
+    pub fn is_circle_clear_on(&self, center: Vec2, radius: f32, layer: CollisionLayer) -> bool {
         // Early bounds check
         if !self.is_within_bounds(center, radius) {
             return false;
@@ -123,9 +205,21 @@ impl CollisionMap {
 
         // Point collision if no radius
         if radius <= 0.0 {
-            return self.is_world_pos_walkable(center);
+            let cell = self.world_to_grid(center);
+            return self.in_bounds(cell.x, cell.y)
+                && self.is_passable(self.xy_to_idx(cell.x, cell.y), layer);
         }
 
+        self.blocking_cell_on(center, radius, layer).is_none()
+    }
+
+    /// First grid cell whose tile (or the map edge) overlaps the circle.
+    pub fn blocking_cell(&self, center: Vec2, radius: f32) -> Option<IVec2> {
+        self.blocking_cell_on(center, radius, CollisionLayer::Land)
+    }
+
+    /// Like `blocking_cell`, for a collider on `layer`.
+    pub fn blocking_cell_on(&self, center: Vec2, radius: f32, layer: CollisionLayer) -> Option<IVec2> {
         // Find grid cells that could overlap the circle
         let min_gx = ((center.x - radius - self.origin_x) / self.tile_size).floor() as i32;
         let max_gx = ((center.x + radius - self.origin_x) / self.tile_size).floor() as i32;
@@ -135,25 +229,32 @@ impl CollisionMap {
         for gy in min_gy..=max_gy {
             for gx in min_gx..=max_gx {
                 if !self.in_bounds(gx, gy) {
-                    return false;  // Out of bounds = blocked
+                    return Some(IVec2::new(gx, gy));  // Out of bounds = blocked
                 }
 
-                if let Some(tile) = self.get_tile(gx, gy) {
-                    if !tile.is_walkable() {
-                        // Apply tile-specific collision adjustment
-                        let effective_radius = radius + tile.collision_adjustment() * self.tile_size;
-                        
-                        if self.circle_intersects_tile(center, effective_radius, gx, gy) {
-                            return false;
-                        }
-                    }
+                let idx = self.xy_to_idx(gx, gy);
+                if self.is_passable(idx, layer) {
+                    continue;
+                }
+
+                // Only blocked tiles need their type, for the collision adjustment
+                let tile = self.tiles[idx];
+                let effective_radius = radius + tile.collision_adjustment() * self.tile_size;
+
+                if self.circle_intersects_tile(center, effective_radius, gx, gy) {
+                    return Some(IVec2::new(gx, gy));
                 }
             }
         }
-        true
+        None
     }
 
     pub fn sweep_circle(&self, start: Vec2, end: Vec2, radius: f32) -> Vec2 {
+        self.sweep_circle_on(start, end, radius, CollisionLayer::Land)
+    }
+
+    /// Like `sweep_circle`, for a collider on `layer`.
+    pub fn sweep_circle_on(&self, start: Vec2, end: Vec2, radius: f32, layer: CollisionLayer) -> Vec2 {
         let delta = end - start;
         
         // No movement needed
@@ -170,19 +271,19 @@ impl CollisionMap {
         for _ in 0..steps {
             let candidate = pos + step_vec;
 
-            if self.is_circle_clear(candidate, radius) {
+            if self.is_circle_clear_on(candidate, radius, layer) {
                 pos = candidate;
             } else {
                 // Try sliding along X axis only
                 let try_x = Vec2::new(candidate.x, pos.y);
-                if self.is_circle_clear(try_x, radius) {
+                if self.is_circle_clear_on(try_x, radius, layer) {
                     pos = try_x;
                     continue;
                 }
 
                 // Try sliding along Y axis only
                 let try_y = Vec2::new(pos.x, candidate.y);
-                if self.is_circle_clear(try_y, radius) {
+                if self.is_circle_clear_on(try_y, radius, layer) {
                     pos = try_y;
                     continue;
                 }
@@ -194,10 +295,63 @@ impl CollisionMap {
         pos
     }
 
-    #[cfg(debug_assertions)]
+    /// Walk the grid cells crossed by a segment (DDA traversal) and return
+    /// the first point where it enters a cell matching `blocks`.
+    /// Leaving the map counts as a hit.
+    pub fn raycast(&self, start: Vec2, end: Vec2, blocks: impl Fn(TileType) -> bool) -> Option<Vec2> {
+        let is_blocked = |cell: IVec2| self.get_tile(cell.x, cell.y).is_none_or(&blocks);
+
+        let mut cell = self.world_to_grid(start);
+        if is_blocked(cell) {
+            return Some(start);
+        }
+
+        let delta = end - start;
+        let length = delta.length();
+        if length < 0.001 {
+            return None;
+        }
+        let dir = delta / length;
+
+        // Per axis: step direction, ray distance to the first grid line,
+        // and ray distance between successive grid lines
+        let axis_setup = |dir: f32, start: f32, origin: f32, cell: i32| -> (i32, f32, f32) {
+            if dir > 0.0 {
+                let boundary = origin + (cell + 1) as f32 * self.tile_size;
+                (1, (boundary - start) / dir, self.tile_size / dir)
+            } else if dir < 0.0 {
+                let boundary = origin + cell as f32 * self.tile_size;
+                (-1, (boundary - start) / dir, -self.tile_size / dir)
+            } else {
+                (0, f32::INFINITY, f32::INFINITY)
+            }
+        };
+        let (step_x, mut t_max_x, t_delta_x) = axis_setup(dir.x, start.x, self.origin_x, cell.x);
+        let (step_y, mut t_max_y, t_delta_y) = axis_setup(dir.y, start.y, self.origin_y, cell.y);
+
+        loop {
+            // Cross whichever grid line comes first
+            let t = if t_max_x < t_max_y {
+                cell.x += step_x;
+                t_max_x += t_delta_x;
+                t_max_x - t_delta_x
+            } else {
+                cell.y += step_y;
+                t_max_y += t_delta_y;
+                t_max_y - t_delta_y
+            };
+
+            if t > length {
+                return None;
+            }
+            if is_blocked(cell) {
+                return Some(start + dir * t);
+            }
+        }
+    }
+
     pub fn width(&self) -> i32 { self.width }
     
-    #[cfg(debug_assertions)]
     pub fn height(&self) -> i32 { self.height }
     
     #[cfg(debug_assertions)]
@@ -264,23 +418,29 @@ impl CollisionMap {
             self.find_nearest_walkable(goal_grid)?
         };
         
+        // Step costs are the distance (10 cardinal, 14 diagonal) scaled by
+        // the cost of the tile stepped onto
         let result = astar(
             &start_grid,
             |pos| {
                 let pos = *pos;
                 self.get_neighbors(pos).into_iter().map(move |n| {
-                    let cost = if (n.x - pos.x).abs() + (n.y - pos.y).abs() == 2 {
+                    let distance = if (n.x - pos.x).abs() + (n.y - pos.y).abs() == 2 {
                         14u32 // Diagonal
                     } else {
                         10u32 // Cardinal
                     };
-                    (n, cost)
+                    let tile_cost = self
+                        .movement_cost(n.x, n.y)
+                        .expect("get_neighbors only yields walkable cells");
+                    (n, distance * tile_cost)
                 })
             },
             |pos| {
-                let dx = (pos.x - actual_goal.x).abs();
-                let dy = (pos.y - actual_goal.y).abs();
-                ((dx + dy) * 10) as u32
+                // Octile distance over the cheapest possible ground
+                let dx = (pos.x - actual_goal.x).unsigned_abs();
+                let dy = (pos.y - actual_goal.y).unsigned_abs();
+                (10 * dx.max(dy) + 4 * dx.min(dy)) * TileType::CHEAPEST_MOVEMENT_COST
             },
             |pos| *pos == actual_goal,
         );
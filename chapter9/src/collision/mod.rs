@@ -1,17 +1,21 @@
 mod tile_type;
 mod map;
 mod systems;
+mod spatial_hash;
 
 #[cfg(debug_assertions)]
 mod debug;
 
+use bevy::diagnostic::{Diagnostic, RegisterDiagnostic};
 use bevy::prelude::*;
+use crate::map::generate::MapReady;
 use crate::state::GameState;
 
 // Re-export commonly used types
-pub use tile_type::{TileType, TileMarker};
+pub use tile_type::{CollisionLayer, Footprint, Hazard, TileType, TileMarker};
 pub use map::CollisionMap;
-pub use systems::CollisionMapBuilt;
+pub use systems::{CollisionMapBuilt, BUILD_TIME};
+pub use spatial_hash::SpatialHash;
 
 #[cfg(debug_assertions)]
 pub use debug::DebugCollisionEnabled;
@@ -21,10 +25,20 @@ pub struct CollisionPlugin;
 impl Plugin for CollisionPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CollisionMapBuilt>()
+            .init_resource::<SpatialHash>()
+            .register_diagnostic(Diagnostic::new(BUILD_TIME).with_suffix("ms"))
+            // Rebuilt before gameplay systems run so queries see this frame's positions
+            .add_systems(
+                PreUpdate,
+                spatial_hash::update_spatial_hash.run_if(in_state(GameState::Playing)),
+            )
+            // Built once the generator reports the world is spawned, and again
+            // if more tile entities appear, rather than polled every frame
             .add_systems(
                 Update,
                 systems::build_collision_map
-                    .run_if(resource_equals(CollisionMapBuilt(false)))
+                    .run_if(resource_exists::<MapReady>)
+                    .run_if(resource_equals(CollisionMapBuilt(false)).or(systems::tiles_added))
                     .run_if(in_state(GameState::Playing)),
             );
 
@@ -32,12 +46,17 @@ impl Plugin for CollisionPlugin {
         #[cfg(debug_assertions)]
         {
             app.init_resource::<DebugCollisionEnabled>()
+                .init_resource::<debug::DebugCostFieldEnabled>()
+                .init_resource::<debug::LastBlockedCell>()
+                .add_observer(debug::record_blocked_cell)
                 .add_systems(
                     Update,
                     (
                         debug::toggle_debug_collision,
                         debug::debug_draw_collision,
                         debug::debug_player_position,
+                        debug::toggle_debug_cost_field,
+                        debug::debug_draw_cost_field,
                     )
                         .run_if(in_state(GameState::Playing)),
                 );
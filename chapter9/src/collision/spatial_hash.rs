@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::characters::collider::Collider;
+use crate::config::map::TILE_SIZE;
+
+/// Size of a spatial hash bucket in world units.
+/// Two tiles keeps buckets small while most queries touch only a few of them.
+const CELL_SIZE: f32 = TILE_SIZE * 2.0;
+
+/// Uniform-grid spatial hash of every entity with a `Collider`.
+/// Rebuilt once per frame so hit queries don't scan every entity.
+#[derive(Resource, Default)]
+pub struct SpatialHash {
+    cells: HashMap<IVec2, Vec<(Entity, Vec2)>>,
+}
+
+impl SpatialHash {
+    fn cell_of(pos: Vec2) -> IVec2 {
+        (pos / CELL_SIZE).floor().as_ivec2()
+    }
+
+    pub fn clear(&mut self) {
+        // Keep bucket allocations around for the next rebuild
+        for bucket in self.cells.values_mut() {
+            bucket.clear();
+        }
+    }
+
+    pub fn insert(&mut self, entity: Entity, pos: Vec2) {
+        self.cells
+            .entry(Self::cell_of(pos))
+            .or_default()
+            .push((entity, pos));
+    }
+
+    /// All entities whose position lies within `radius` of `center`.
+    pub fn query_radius(&self, center: Vec2, radius: f32) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        let min = Self::cell_of(center - Vec2::splat(radius));
+        let max = Self::cell_of(center + Vec2::splat(radius));
+        let radius_sq = radius * radius;
+
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .filter(move |(_, pos)| center.distance_squared(*pos) <= radius_sq)
+    }
+}
+
+/// Rebuild the spatial hash from current entity positions.
+pub fn update_spatial_hash(
+    mut hash: ResMut<SpatialHash>,
+    query: Query<(Entity, &GlobalTransform), With<Collider>>,
+) {
+    hash.clear();
+    for (entity, transform) in query.iter() {
+        hash.insert(entity, transform.translation().truncate());
+    }
+}
@@ -1,72 +1,95 @@
+use bevy::diagnostic::{DiagnosticPath, Diagnostics};
+use bevy::ecs::batching::BatchingStrategy;
 use bevy::prelude::*;
+use bevy::utils::Parallel;
 use std::collections::{HashMap, hash_map::Entry};
+use std::time::Instant;
 
-use super::{CollisionMap, TileMarker, TileType};
-use crate::config::map::{TILE_SIZE, TOTAL_GRID_X, TOTAL_GRID_Y};
+use super::{CollisionMap, Footprint, TileMarker, TileType};
+use crate::config::map::{COLLISION_BUILD_BATCH_SIZE, TILE_SIZE, TOTAL_GRID_X, TOTAL_GRID_Y};
+
+/// Time the last collision map build took, in milliseconds.
+pub const BUILD_TIME: DiagnosticPath = DiagnosticPath::const_new("collision/build_time");
 
 /// Resource to track if collision map has been built.
 #[derive(Resource, Default, PartialEq, Eq)]
 pub struct CollisionMapBuilt(pub bool);
 
+/// Topmost tile type seen so far at each grid cell, with its Z.
+type LayerTracker = HashMap<(i32, i32), (TileType, f32)>;
+
+fn keep_topmost(layers: &mut LayerTracker, cell: (i32, i32), tile_type: TileType, z: f32) {
+    match layers.entry(cell) {
+        Entry::Occupied(mut entry) => {
+            if z > entry.get().1 {
+                *entry.get_mut() = (tile_type, z);
+            }
+        }
+        Entry::Vacant(entry) => {
+            entry.insert((tile_type, z));
+        }
+    }
+}
+
+/// Run condition: tile entities were spawned since the last check
+/// (a freshly generated world, or tiles placed later).
+pub fn tiles_added(added: Query<(), Added<TileMarker>>) -> bool {
+    !added.is_empty()
+}
+
 pub fn build_collision_map(
     mut commands: Commands,
     mut built: ResMut<CollisionMapBuilt>,
-    tile_query: Query<(&TileMarker, &Transform)>,
+    tile_query: Query<(&TileMarker, &Transform, Option<&Footprint>)>,
+    mut diagnostics: Diagnostics,
 ) {
-    // Need at least one tile to proceed
-    let mut tile_iter = tile_query.iter();
-    let Some((first_marker, first_transform)) = tile_iter.next() else {
-        return; // WFC hasn't generated tiles yet
-    };
+    let started = Instant::now();
 
     // Calculate grid origin (centered map)
     let grid_origin_x = -TILE_SIZE * TOTAL_GRID_X as f32 / 2.0;
     let grid_origin_y = -TILE_SIZE * TOTAL_GRID_Y as f32 / 2.0;
 
-    // Track bounds and layer info
-    let (mut min_x, mut max_x) = (i32::MAX, i32::MIN);
-    let (mut min_y, mut max_y) = (i32::MAX, i32::MIN);
-    let mut layer_tracker: HashMap<(i32, i32), (TileType, f32)> = HashMap::new();
-    let mut tile_count: usize = 0;
-
-    // Process all tiles, keeping only the topmost at each position
-    let mut process_tile = |marker: &TileMarker, transform: &Transform| {
-        tile_count += 1;
+    // Each worker keeps the topmost layer per cell for its batch of tiles
+    let mut per_thread: Parallel<LayerTracker> = Parallel::default();
+    tile_query
+        .par_iter()
+        .batching_strategy(BatchingStrategy::new().min_batch_size(COLLISION_BUILD_BATCH_SIZE))
+        .for_each(|(marker, transform, footprint)| {
+            let grid_x = ((transform.translation.x - grid_origin_x) / TILE_SIZE).floor() as i32;
+            let grid_y = ((transform.translation.y - grid_origin_y) / TILE_SIZE).floor() as i32;
+            let z = transform.translation.z;
+            per_thread.scope(|layers| {
+                keep_topmost(layers, (grid_x, grid_y), marker.tile_type, z);
+                // Multi-tile props claim their other cells at the same depth
+                for (offset, tile_type) in footprint.into_iter().flat_map(|f| &f.0) {
+                    keep_topmost(layers, (grid_x + offset.x, grid_y + offset.y), *tile_type, z);
+                }
+            });
+        });
 
-        let world_x = transform.translation.x;
-        let world_y = transform.translation.y;
-        let world_z = transform.translation.z;
-        
-        let grid_x = ((world_x - grid_origin_x) / TILE_SIZE).floor() as i32;
-        let grid_y = ((world_y - grid_origin_y) / TILE_SIZE).floor() as i32;
+    let mut layer_tracker = LayerTracker::new();
+    for layers in per_thread.iter_mut() {
+        for (cell, (tile_type, z)) in layers.drain() {
+            keep_topmost(&mut layer_tracker, cell, tile_type, z);
+        }
+    }
+    if layer_tracker.is_empty() {
+        return; // WFC hasn't generated tiles yet
+    }
 
+    // Track bounds
+    let (mut min_x, mut max_x) = (i32::MAX, i32::MIN);
+    let (mut min_y, mut max_y) = (i32::MAX, i32::MIN);
+    for &(grid_x, grid_y) in layer_tracker.keys() {
         min_x = min_x.min(grid_x);
         max_x = max_x.max(grid_x);
         min_y = min_y.min(grid_y);
         max_y = max_y.max(grid_y);
-
-        // Keep only the topmost layer (highest Z)
-        match layer_tracker.entry((grid_x, grid_y)) {
-            Entry::Occupied(mut entry) => {
-                if world_z > entry.get().1 {
-                    *entry.get_mut() = (marker.tile_type, world_z);
-                }
-            }
-            Entry::Vacant(entry) => {
-                entry.insert((marker.tile_type, world_z));
-            }
-        }
-    };
-
-    // Process first tile and remaining
-    process_tile(first_marker, first_transform);
-    for (marker, transform) in tile_iter {
-        process_tile(marker, transform);
     }
 
     // Calculate actual dimensions
-    let actual_width = (max_x - min_x + 1) as i32;
-    let actual_height = (max_y - min_y + 1) as i32;
+    let actual_width = max_x - min_x + 1;
+    let actual_height = max_y - min_y + 1;
 
     // Create the collision map
     let mut map = CollisionMap::new(
@@ -87,6 +110,14 @@ pub fn build_collision_map(
 
     // Post-processing: Convert water edges to shore
     convert_water_edges_to_shore(&mut map);
+    info!(
+        "Built {}x{} collision map from {} cells",
+        actual_width,
+        actual_height,
+        layer_tracker.len()
+    );
+    let elapsed = started.elapsed();
+    diagnostics.add_measurement(&BUILD_TIME, || elapsed.as_secs_f64() * 1000.0);
     // Insert as resource and mark built
     commands.insert_resource(map);
     built.0 = true;
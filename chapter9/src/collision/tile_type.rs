@@ -1,8 +1,9 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Tile types for collision detection.
 /// Each type has different walkability and collision behavior.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect, Serialize, Deserialize)]
 pub enum TileType {
     // Walkable terrain
     #[default]
@@ -11,16 +12,61 @@ pub enum TileType {
     Grass,
     YellowGrass,
     Shore,  // Water edges (walkable)
+    TilledSoil, // Farmland made with the hoe (walkable)
+    Thorns, // Thorn bushes (walkable, see `hazard`)
+    Mud,    // Deep mud (walkable, see `hazard`)
     // Non-walkable obstacles
     Water,
     Tree,
     Rock,
+    Fence, // Placed by the player
 }
 
 impl TileType {
     /// Check if this tile type allows movement through it.
     pub fn is_walkable(&self) -> bool {
-        !matches!(self, TileType::Water | TileType::Tree | TileType::Rock)
+        !matches!(self, TileType::Water | TileType::Tree | TileType::Rock | TileType::Fence)
+    }
+
+    /// Check if a collider on `layer` can move through this tile type.
+    pub fn is_passable(&self, layer: CollisionLayer) -> bool {
+        match layer {
+            CollisionLayer::Land => self.is_walkable(),
+            CollisionLayer::Water => matches!(self, TileType::Water | TileType::Shore),
+        }
+    }
+
+    /// Cheapest `movement_cost` of any tile, which keeps the A* heuristic admissible.
+    pub const CHEAPEST_MOVEMENT_COST: u32 = 7;
+
+    /// Relative cost for pathfinding to cross this tile, where 10 is plain ground,
+    /// or `None` if it can't be walked.
+    /// Paths follow dirt roads, cut across yellow grass reluctantly, and keep off the shore.
+    pub fn movement_cost(&self) -> Option<u32> {
+        match self {
+            TileType::Dirt => Some(7),
+            TileType::Empty | TileType::Grass => Some(10),
+            TileType::TilledSoil => Some(12),
+            TileType::YellowGrass => Some(14),
+            TileType::Shore => Some(20),
+            TileType::Mud => Some(30),
+            TileType::Thorns => Some(40),
+            TileType::Water | TileType::Tree | TileType::Rock | TileType::Fence => None,
+        }
+    }
+
+    /// What standing on this tile does to an entity, if anything.
+    pub fn hazard(&self) -> Option<Hazard> {
+        match self {
+            TileType::Thorns => Some(Hazard::Thorns),
+            TileType::Mud => Some(Hazard::Mud),
+            _ => None,
+        }
+    }
+
+    /// Check if this tile type stops projectiles (they fly over water).
+    pub fn blocks_projectiles(&self) -> bool {
+        matches!(self, TileType::Tree | TileType::Rock | TileType::Fence)
     }
 
     /// Get the collision adjustment for this tile type.
@@ -33,7 +79,27 @@ impl TileType {
     }
 }
 
-#[derive(Component, Debug, Clone)]
+/// Harm a tile does to entities standing on it, once per exposure tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum Hazard {
+    /// Deals damage
+    Thorns,
+    /// Slows heavily
+    Mud,
+}
+
+/// Which tiles a collider can move through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum CollisionLayer {
+    /// Walkable terrain (the default for characters)
+    #[default]
+    Land,
+    /// Water and shore only, for boats
+    Water,
+}
+
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct TileMarker {
     pub tile_type: TileType,
 }
@@ -42,4 +108,10 @@ impl TileMarker {
     pub fn new(tile_type: TileType) -> Self {
         Self { tile_type }
     }
-}
\ No newline at end of file
+}
+
+/// Cells a multi-tile prop covers besides the one its `TileMarker` stands on,
+/// as grid offsets from that cell with the tile type each one gets.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct Footprint(pub Vec<(IVec2, TileType)>);
\ No newline at end of file
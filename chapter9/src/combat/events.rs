@@ -1,17 +1,18 @@
 // src/combat/events.rs
-use super::power_type::PowerType;
 use bevy::prelude::*;
 
-/// Event triggered when a projectile hits a target entity.
-#[derive(Event)]
-pub struct ProjectileHit {
-    pub target: Entity,
-    pub damage: f32,
-    pub power_type: PowerType,
-}
-
 /// Event triggered when an entity's health reaches zero.
 #[derive(Event)]
 pub struct EntityDeath {
     pub entity: Entity,
+}
+
+/// Event to hurt an entity with `Health`, for damage that doesn't come
+/// from a projectile (hazard tiles, ...).
+#[derive(Event)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: f32,
+    /// What dealt the damage, shown in the event log
+    pub source: &'static str,
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 
 /// Health component for any damageable entity (player, enemies).
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 pub struct Health {
     pub current: f32,
     pub max: f32,
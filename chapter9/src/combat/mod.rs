@@ -8,14 +8,16 @@ mod player_combat;
 mod power_type;
 pub mod systems;
 
+pub use events::DamageEvent;
 pub use health::Health; 
 pub use healthbar::HealthBarOwner;
 
 pub use player_combat::PlayerCombat;
 pub use power_type::{PowerType, PowerVisuals};
-pub use systems::{debug_switch_power, handle_power_input, spawn_projectile, ProjectileOwner}; 
+pub use systems::{debug_switch_power, handle_power_input, spawn_projectile}; 
 
 use bevy::prelude::*;
+use crate::characters::physics::apply_velocity;
 use crate::state::GameState; 
 
 pub struct CombatPlugin;
@@ -25,14 +27,19 @@ impl Plugin for CombatPlugin {
         app
             // Register observers for combat events
             .add_observer(observers::on_projectile_hit) 
+            .add_observer(observers::on_damage)
             .add_observer(observers::on_entity_death) 
+            // Fixed rate, so the power cooldown runs the same at any frame rate
+            .add_systems(
+                FixedUpdate,
+                handle_power_input
+                    .after(apply_velocity)
+                    .run_if(in_state(GameState::Playing)),
+            )
             .add_systems(
                 Update,
                 (
-                    handle_power_input,
                     debug_switch_power,
-                    systems::move_projectiles, 
-                    systems::check_projectile_hits,
                     healthbar::spawn_healthbars,
                     healthbar::update_healthbars,
                 )
@@ -1,14 +1,17 @@
 // src/combat/observers.rs
-use super::events::{EntityDeath, ProjectileHit};
+use super::events::{DamageEvent, EntityDeath};
 use super::health::Health;
 use bevy::prelude::*;
 use crate::characters::input::Player; 
+use crate::event_log::{LogCategory, LogEvent};
+use crate::projectile::ProjectileHit;
 use crate::state::GameState;
 
 /// Observer that handles projectile hits by applying damage to the target.
 pub fn on_projectile_hit(
     hit: On<ProjectileHit>,
     mut healths: Query<&mut Health>,
+    players: Query<(), With<Player>>,
     mut commands: Commands,
 ) {
     let Ok(mut health) = healths.get_mut(hit.target) else {
@@ -17,10 +20,39 @@ pub fn on_projectile_hit(
 
     health.take_damage(&mut commands, hit.target, hit.damage);
 
-    info!(
-        "{:?} hit for {} damage! HP: {:.0}/{:.0}",
-        hit.power_type, hit.damage, health.current, health.max
-    );
+    let text = if players.contains(hit.target) {
+        format!(
+            "Took {:.0} {:?} damage ({:.0}/{:.0} HP)",
+            hit.damage, hit.kind, health.current, health.max
+        )
+    } else {
+        format!("Hit an enemy for {:.0} {:?} damage", hit.damage, hit.kind)
+    };
+    commands.trigger(LogEvent::new(LogCategory::Combat, text));
+}
+
+/// Observer that applies damage from any other source.
+pub fn on_damage(
+    damage: On<DamageEvent>,
+    mut healths: Query<&mut Health>,
+    players: Query<(), With<Player>>,
+    mut commands: Commands,
+) {
+    let Ok(mut health) = healths.get_mut(damage.target) else {
+        return;
+    };
+
+    health.take_damage(&mut commands, damage.target, damage.amount);
+
+    if players.contains(damage.target) {
+        commands.trigger(LogEvent::new(
+            LogCategory::Combat,
+            format!(
+                "Took {:.0} damage from {} ({:.0}/{:.0} HP)",
+                damage.amount, damage.source, health.current, health.max
+            ),
+        ));
+    }
 }
 
 /// Observer that handles entity death by despawning the entity.
@@ -33,12 +65,13 @@ pub fn on_entity_death(
     let entity = death.entity;
     let is_player = players.get(entity).is_ok();
 
-    info!("Entity {:?} defeated!", death.entity);
     commands.entity(death.entity).despawn();
 
     // Add this line
     if is_player { 
-        info!("Player defeated! Game Over."); 
+        commands.trigger(LogEvent::new(LogCategory::Combat, "You were defeated"));
         next_state.set(GameState::GameOver);
+    } else {
+        commands.trigger(LogEvent::new(LogCategory::Combat, "Defeated an enemy"));
     }
 }
\ No newline at end of file
@@ -2,7 +2,8 @@ use super::power_type::PowerType;
 use bevy::prelude::*;
 
 /// Attach to any entity that can use powers (player, NPCs)
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 pub struct PlayerCombat {
     pub power_type: PowerType,
     pub cooldown: Timer,
@@ -2,7 +2,7 @@ use bevy::prelude::*;
 use crate::particles::components::{EmissionShape, ParticleConfig};
 
 /// The different magical powers available
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
 pub enum PowerType {
     #[default]
     Fire,
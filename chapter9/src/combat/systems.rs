@@ -3,9 +3,10 @@ use super::player_combat::PlayerCombat;
 use super::power_type::{PowerType, PowerVisuals};
 use crate::characters::facing::Facing;
 use crate::characters::input::Player;
-use crate::particles::components::ParticleEmitter;
+use crate::controls::{InputAction, InputBuffer};
+use crate::particles::components::{ParticleEmitter, ParticleOf};
+use crate::projectile::{Projectile, ProjectileKind, ProjectileOwner};
 use bevy::prelude::*;
-use crate::enemy::Enemy;
 
 /// Marker for projectile effects
 #[derive(Component)]
@@ -13,50 +14,33 @@ pub struct ProjectileEffect {
     pub power_type: PowerType,
 }
 
-/// Who fired a projectile determines which entities it can hit.
-#[derive(Component, Clone, Copy)]
-pub enum ProjectileOwner {
-    Player,
-    Enemy,
-}
-
-/// Invisible hitbox that travels and checks for collisions.
-#[derive(Component)]
-pub struct Projectile {
-    pub velocity: Vec3,
-    pub lifetime: f32,
-    pub power_type: PowerType,
-    pub owner: ProjectileOwner,
-    pub radius: f32,
-}
-
 pub fn handle_power_input(
     mut commands: Commands,
-    input: Res<ButtonInput<KeyCode>>,
+    mut buffer: ResMut<InputBuffer>,
     time: Res<Time>,
-    mut player_query: Query<(&GlobalTransform, &Facing, &mut PlayerCombat), With<Player>>,
+    mut player_query: Query<(&Transform, &Facing, &mut PlayerCombat), With<Player>>,
 ) {
-    let Ok((global_transform, facing, mut combat)) = player_query.single_mut() else {
+    let Ok((transform, facing, mut combat)) = player_query.single_mut() else {
         return;
     };
 
     combat.cooldown.tick(time.delta());
 
-    let ctrl_pressed =
-        input.just_pressed(KeyCode::ControlLeft) || input.just_pressed(KeyCode::ControlRight);
-
-    if !ctrl_pressed {
+    if !buffer.is_buffered(InputAction::CastPower) {
         return;
     }
 
-    // Only fire if cooldown has elapsed
+    // Only fire if cooldown has elapsed; a press just before stays buffered
     if combat.cooldown.elapsed_secs() < combat.cooldown.duration().as_secs_f32() {
         return;
     }
 
+    buffer.consume(InputAction::CastPower);
     combat.cooldown.reset();
 
-    let position: Vec3 = global_transform.translation();
+    // The player's Transform, not its GlobalTransform: inside the fixed loop
+    // that's where the tick left them rather than where they were last drawn
+    let position: Vec3 = transform.translation;
     let direction = facing_to_vec3(facing);
     let spawn_position = position + direction * 5.0;
 
@@ -70,6 +54,10 @@ pub fn handle_power_input(
 
 
 
+/// Spawns a projectile's hitbox and its particle visuals.
+///
+/// The visuals belong to the hitbox, so they vanish with it when it hits a
+/// target, stops on an obstacle or runs out of range.
 pub fn spawn_projectile(
     commands: &mut Commands,
     position: Vec3,
@@ -77,6 +65,22 @@ pub fn spawn_projectile(
     visuals: &PowerVisuals,
     owner: ProjectileOwner, 
 ) {
+    // Hitbox: invisible entity that moves and checks for hits
+    let direction = visuals.primary.direction.truncate().normalize_or_zero();
+    let speed = visuals.primary.speed;
+    let hitbox = commands
+        .spawn((
+            Projectile::new(
+                ProjectileKind::Power(power_type),
+                owner,
+                direction * speed,
+                power_type.damage(),
+                power_type.hitbox_radius(),
+            ),
+            Transform::from_translation(position),
+        ))
+        .id();
+
     // Primary particles
     let primary_emitter =
         ParticleEmitter::new(0.016, visuals.particles_per_spawn, visuals.primary.clone())
@@ -87,6 +91,7 @@ pub fn spawn_projectile(
         Transform::from_translation(position),
         GlobalTransform::from(Transform::from_translation(position)),
         ProjectileEffect { power_type },
+        ParticleOf(hitbox),
     ));
 
     // Core particles (if the power has a core)
@@ -100,22 +105,9 @@ pub fn spawn_projectile(
             Transform::from_translation(position),
             GlobalTransform::from(Transform::from_translation(position)),
             ProjectileEffect { power_type },
+            ParticleOf(hitbox),
         ));
     }
-
-    // Hitbox: invisible entity that moves and checks for hits
-    let direction = visuals.primary.direction.normalize_or_zero();
-    let speed = visuals.primary.speed;
-    commands.spawn((
-        Projectile {
-            velocity: direction * speed,
-            lifetime: 2.0,
-            power_type,
-            owner,
-            radius: power_type.hitbox_radius(),
-        },
-        Transform::from_translation(position),
-    ));
 }
 
 fn facing_to_vec3(facing: &Facing) -> Vec3 {
@@ -152,53 +144,3 @@ pub fn debug_switch_power(
         info!("Switched to {:?}", power);
     }
 }
-
-/// Moves projectile hitboxes forward and despawns them on timeout.
-pub fn move_projectiles(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut projectiles: Query<(Entity, &mut Projectile, &mut Transform)>,
-) {
-    let dt = time.delta_secs();
-    for (entity, mut proj, mut transform) in projectiles.iter_mut() {
-        proj.lifetime -= dt;
-        if proj.lifetime <= 0.0 {
-            commands.entity(entity).despawn();
-            continue;
-        }
-        transform.translation += proj.velocity * dt;
-    }
-}
-
-/// Checks each projectile hitbox against its valid targets; triggers hit events on collision.
-pub fn check_projectile_hits(
-    mut commands: Commands,
-    projectiles: Query<(Entity, &Projectile, &Transform)>,
-    players: Query<(Entity, &GlobalTransform), With<Player>>,
-    enemies: Query<(Entity, &GlobalTransform), With<Enemy>>,
-) {
-    for (proj_entity, proj, proj_transform) in &projectiles {
-        let proj_pos = proj_transform.translation;
-
-        let hit_target = match proj.owner {
-            ProjectileOwner::Player => enemies
-                .iter()
-                .find(|(_, t)| proj_pos.distance(t.translation()) <= proj.radius)
-                .map(|(e, _)| e),
-            ProjectileOwner::Enemy => players
-                .iter()
-                .find(|(_, t)| proj_pos.distance(t.translation()) <= proj.radius)
-                .map(|(e, _)| e),
-        };
-
-        if let Some(target) = hit_target {
-            // Trigger hit event instead of directly applying damage
-            commands.trigger(super::events::ProjectileHit {
-                target,
-                damage: proj.power_type.damage(),
-                power_type: proj.power_type,
-            });
-            commands.entity(proj_entity).despawn();
-        }
-    }
-}
\ No newline at end of file
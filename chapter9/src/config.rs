@@ -7,6 +7,28 @@ pub mod player {
     
     /// Visual scale of the player sprite
     pub const PLAYER_SCALE: f32 = 1.2; // Line update alert (was 0.8)
+
+    /// Maximum stamina
+    pub const STAMINA_MAX: f32 = 100.0;
+
+    /// Stamina spent per second of running
+    pub const STAMINA_DRAIN_PER_SEC: f32 = 25.0;
+
+    /// Stamina regained per second while not running
+    pub const STAMINA_REGEN_PER_SEC: f32 = 15.0;
+
+    /// After running dry, running unlocks again at this fraction of max stamina
+    pub const STAMINA_RECOVER_RATIO: f32 = 0.3;
+}
+
+pub mod physics {
+    /// Movement and collision ticks per second (FixedUpdate rate).
+    /// Rendering interpolates between ticks, so this doesn't need to match the display.
+    pub const FIXED_TICK_HZ: f64 = 60.0;
+
+    /// A move counts as bumping into something once terrain cuts it to
+    /// less than this fraction of its length (glancing slides don't count).
+    pub const BUMP_BLOCKED_FRACTION: f32 = 0.5;
 }
 
 pub mod pickup {
@@ -14,6 +36,305 @@ pub mod pickup {
     pub const DEFAULT_RADIUS: f32 = 40.0;
 }
 
+pub mod projectile {
+    /// Seconds a projectile flies before despawning
+    pub const DEFAULT_LIFETIME: f32 = 2.0;
+
+    /// Distance a projectile flies before despawning (in world units)
+    pub const DEFAULT_MAX_RANGE: f32 = 1200.0;
+}
+
+pub mod interaction {
+    /// Default reach for interacting with world entities (in world units)
+    pub const DEFAULT_RADIUS: f32 = 56.0;
+
+    /// Targets must be within this angle of the way the player faces
+    pub const CONE_HALF_ANGLE_DEG: f32 = 60.0;
+
+    /// Targets this close count as in front whatever their angle (underfoot)
+    pub const TOUCH_RADIUS: f32 = 12.0;
+
+    /// How much being off to the side counts against a target, relative to distance
+    pub const OFF_AXIS_WEIGHT: f32 = 0.5;
+}
+
+pub mod harvest {
+    /// Percentage of shore tiles that get a reeds node
+    pub const SHORE_REEDS_PERCENT: u32 = 8;
+}
+
+pub mod placement {
+    /// Z for the placement preview: above props and characters, below the fog
+    pub const GHOST_Z: f32 = 800.0;
+
+    /// Alpha of the placement preview
+    pub const GHOST_ALPHA: f32 = 0.5;
+}
+
+pub mod hotbar {
+    /// Number of hotbar slots
+    pub const SLOT_COUNT: usize = 8;
+}
+
+pub mod farming {
+    /// Seconds a crop spends in each growth stage
+    pub const STAGE_SECS: f32 = 30.0;
+
+    /// Crops within this many tiles of water grow faster
+    pub const WATER_RADIUS: i32 = 3;
+
+    /// Growth speed multiplier for crops near water
+    pub const WATER_GROWTH_MULTIPLIER: f32 = 2.0;
+
+    /// Wheat yielded by a mature crop (plus one seed back)
+    pub const HARVEST_YIELD: u32 = 2;
+
+    /// Seeds the player starts a new game with
+    pub const STARTING_SEEDS: u32 = 5;
+}
+
+pub mod fishing {
+    /// Seconds before a fish bites, picked at random in this range
+    pub const BITE_DELAY_MIN: f32 = 2.0;
+    pub const BITE_DELAY_MAX: f32 = 6.0;
+
+    /// Seconds the player has to reel in once a fish bites
+    pub const BITE_WINDOW: f32 = 0.8;
+
+    /// The line snaps if the player walks further than this from the bobber
+    pub const MAX_LINE_LENGTH: f32 = 160.0;
+
+    /// Side length of the bobber placeholder sprite
+    pub const BOBBER_SIZE: f32 = 10.0;
+}
+
+pub mod trade {
+    /// Percentage of an item's price a trader pays when buying it from the player
+    pub const SELL_PRICE_PERCENT: u32 = 50;
+
+    /// Where the trader stands, relative to the player's spawn point, when
+    /// the world has no ruins to wait at
+    pub const TRADER_OFFSET_X: f32 = -192.0;
+    pub const TRADER_OFFSET_Y: f32 = -128.0;
+
+    /// The shop closes once the player walks further than this from the trader
+    pub const SHOP_CLOSE_DISTANCE: f32 = 120.0;
+}
+
+pub mod boat {
+    /// Rowing speed while riding a boat (world units per second)
+    pub const BOAT_SPEED: f32 = 220.0;
+
+    /// How close the player must be to board a boat; longer than the usual
+    /// reach, since the boat sits out on the water
+    pub const BOARD_RADIUS: f32 = 96.0;
+
+    /// Boats sort just behind whoever sits in them
+    pub const BOAT_SORT_OFFSET: f32 = 1.0;
+}
+
+pub mod ambient {
+    /// Seconds between ambient emission passes
+    pub const AMBIENT_INTERVAL_SECS: f32 = 0.1;
+
+    /// Random tiles in view sampled per pass; each may emit one particle
+    pub const SAMPLES_PER_PASS: usize = 3;
+
+    /// No new ambient particles while this many are alive
+    pub const MAX_AMBIENT_PARTICLES: usize = 80;
+
+    /// Heat shimmer rises off dirt between these hours
+    pub const MIDDAY_START_HOUR: f32 = 11.0;
+    pub const MIDDAY_END_HOUR: f32 = 15.0;
+}
+
+pub mod status {
+    /// Seconds between checks of what the player is standing in; each check adds a stack
+    pub const EXPOSURE_TICK_SECS: f32 = 1.0;
+
+    /// Most stacks a single effect can build up
+    pub const MAX_STACKS: u32 = 3;
+
+    /// How long each effect lasts after its last stack was applied
+    pub const SLOWED_SECS: f32 = 1.5;
+    pub const WET_SECS: f32 = 20.0;
+    pub const WARM_SECS: f32 = 12.0;
+
+    /// Campfires warm the player within this distance
+    pub const WARMTH_RADIUS: f32 = 160.0;
+
+    /// Multipliers per stack
+    pub const SLOWED_SPEED: f32 = 0.8;
+    pub const WET_SPEED: f32 = 0.93;
+    pub const WET_STAMINA_REGEN: f32 = 0.7;
+    pub const WARM_STAMINA_REGEN: f32 = 1.25;
+    pub const WARM_VISION: f32 = 1.1;
+    pub const MIRED_SPEED: f32 = 0.55;
+
+    /// How long being stuck in mud lasts after stepping out
+    pub const MIRED_SECS: f32 = 1.0;
+
+    /// Damage thorns deal per exposure tick
+    pub const THORN_DAMAGE: f32 = 4.0;
+}
+
+pub mod clock {
+    /// Real seconds for one full in-game day
+    pub const DAY_LENGTH_SECS: f32 = 720.0;
+
+    /// Hour a new game starts at
+    pub const START_HOUR: f32 = 8.0;
+
+    /// Night runs from NIGHT_START_HOUR until NIGHT_END_HOUR the next morning
+    pub const NIGHT_START_HOUR: f32 = 20.0;
+    pub const NIGHT_END_HOUR: f32 = 6.0;
+
+    /// Hours dusk and dawn take to fade, centred on the night start and end
+    pub const TWILIGHT_HOURS: f32 = 1.5;
+
+    /// Range the debug time scale can be set to
+    pub const MIN_TIME_SCALE: f32 = 0.125;
+    pub const MAX_TIME_SCALE: f32 = 8.0;
+}
+
+pub mod regions {
+    /// Stretches of one biome smaller than this many cells get no name
+    pub const MIN_REGION_CELLS: usize = 40;
+
+    /// Mixed into the map seed for region names, so they don't follow the
+    /// same random sequence as generation
+    pub const NAME_SALT: u64 = 0x5EED_4E41_4D45_5300;
+}
+
+pub mod biome {
+    /// Seconds the player must stay in a new biome before it counts
+    pub const SETTLE_SECS: f32 = 1.5;
+}
+
+pub mod audio {
+    /// Seconds to crossfade from one music track to the next
+    pub const MUSIC_CROSSFADE_SECS: f32 = 2.5;
+
+    /// Side length (in tiles) of the regions scanned for ambient emitters
+    pub const AMBIENT_REGION_TILES: i32 = 8;
+
+    /// Minimum tiles of a kind in a region before it gets an emitter
+    pub const WATER_MIN_TILES: usize = 16;
+    pub const FOREST_MIN_TILES: usize = 10;
+
+    /// Hearing radius of each emitter (in world units)
+    pub const WATER_RADIUS: f32 = 480.0;
+    pub const FOREST_RADIUS: f32 = 360.0;
+    pub const CAMPFIRE_RADIUS: f32 = 256.0;
+
+    /// Distance between the listener's ears, controls how strongly sounds pan
+    pub const LISTENER_EAR_GAP: f32 = 64.0;
+
+    /// Sound effects play at a random speed within ±this of normal
+    pub const SFX_PITCH_VARIATION: f32 = 0.08;
+}
+
+pub mod stats {
+    /// Player moves longer than this in one frame are teleports, not walking
+    pub const MAX_STEP_TILES: f32 = 1.0;
+}
+
+pub mod event_log {
+    /// Oldest entries are dropped once the log holds this many
+    pub const MAX_LOG_ENTRIES: usize = 200;
+}
+
+pub mod ui {
+    /// Size of item icons in the inventory panel, in pixels
+    pub const INVENTORY_ICON_SIZE: f32 = 28.0;
+
+    /// How long an inventory row stays highlighted after a pickup
+    pub const PICKUP_HIGHLIGHT_SECS: f32 = 0.8;
+
+    /// Default time a toast stays on screen, in seconds
+    pub const TOAST_DURATION_SECS: f32 = 3.0;
+
+    /// Toasts fade out over their last this-many seconds
+    pub const TOAST_FADE_SECS: f32 = 0.6;
+
+    /// Oldest toasts are dropped once this many are stacked
+    pub const MAX_TOASTS: usize = 5;
+
+    /// How long a region's title stays on screen, fades included
+    pub const REGION_CAPTION_SECS: f32 = 4.0;
+
+    /// Region titles fade in and out over this many seconds
+    pub const REGION_CAPTION_FADE_SECS: f32 = 1.0;
+
+    /// Number of recent frames drawn in the diagnostics frame-time graph
+    pub const FRAME_GRAPH_BARS: usize = 90;
+
+    /// Frame time (ms) that fills the graph's full height
+    pub const FRAME_GRAPH_MAX_MS: f64 = 50.0;
+
+    /// Height of the frame-time graph, in pixels
+    pub const FRAME_GRAPH_HEIGHT: f32 = 48.0;
+
+    /// UI scale factors the accessibility option cycles through
+    pub const UI_SCALE_STEPS: [f32; 5] = [0.75, 1.0, 1.25, 1.5, 2.0];
+}
+
+pub mod controls {
+    /// How long a press of a buffered action waits for the action to become possible
+    pub const INPUT_BUFFER_SECS: f32 = 0.15;
+}
+
+pub mod settings {
+    /// Key bindings file location, relative to the working directory
+    pub const CONTROLS_PATH: &str = "controls.ron";
+
+    /// Settings file location, relative to the working directory
+    pub const SETTINGS_PATH: &str = "settings.ron";
+
+    /// Seconds without changes before settings are written to disk
+    pub const SAVE_DELAY_SECS: f32 = 0.5;
+}
+
+pub mod mods {
+    /// Directory scanned for content packs, one subdirectory per pack.
+    pub const MODS_DIR: &str = "mods";
+    /// Manifest file every pack directory must contain.
+    pub const PACK_MANIFEST: &str = "pack.ron";
+    /// Asset source pack sprites load through, as `mods://<pack>/<file>`.
+    pub const MODS_ASSET_SOURCE: &str = "mods";
+}
+
+pub mod save {
+    /// Save file location, relative to the working directory
+    pub const SAVE_PATH: &str = "saves/savegame.ron";
+
+    /// Where the world export (F4 or `--export-world`) is written by default
+    pub const EXPORT_PATH: &str = "world_export.json";
+}
+
+pub mod difficulty {
+    // Scale factors per `Difficulty`, indexed casual, normal, hard.
+    // Normal is 1 (or the count used before presets existed).
+
+    /// Weight of water tiles in generation
+    pub const WATER_DENSITY: [f32; 3] = [0.7, 1.0, 1.4];
+
+    /// Weight of trees, rocks and stumps in generation
+    pub const OBSTACLE_DENSITY: [f32; 3] = [0.75, 1.0, 1.35];
+
+    /// Weight of pickable plants and berry bushes in generation
+    pub const PICKUP_ABUNDANCE: [f32; 3] = [1.5, 1.0, 0.6];
+
+    /// Stamina spent per second of running
+    pub const STAMINA_DRAIN: [f32; 3] = [0.6, 1.0, 1.4];
+
+    /// Vision radius at full night, as a fraction of the daytime radius
+    pub const NIGHT_VISION: [f32; 3] = [1.0, 1.0, 0.65];
+
+    /// Enemies spawned into a new world
+    pub const ENEMY_BUDGET: [usize; 3] = [1, 2, 4];
+}
+
 pub mod enemy {
     /// Z-position for enemy rendering (same as player for consistent layering)
     pub const ENEMY_Z_POSITION: f32 = 20.0;
@@ -23,6 +344,47 @@ pub mod enemy {
 } 
 
 /// Map/terrain configuration
+pub mod pet {
+    /// Character from `characters.ron` drawn for the pet
+    pub const PET_CHARACTER: &str = "starlit_oracle";
+
+    /// Visual scale of the pet sprite (the player is 1.2)
+    pub const PET_SCALE: f32 = 0.6;
+
+    /// Collision radius of the pet, in world units
+    pub const PET_COLLIDER_RADIUS: f32 = 12.0;
+
+    /// How far behind the player the pet tries to stay
+    pub const FOLLOW_DISTANCE: f32 = 56.0;
+
+    /// Close enough to its spot behind the player to stop walking
+    pub const ARRIVE_RADIUS: f32 = 20.0;
+
+    /// The pet runs to catch up when further than this from the player
+    pub const RUN_DISTANCE: f32 = 180.0;
+
+    /// Left further behind than this, the pet reappears next to the player
+    pub const TELEPORT_DISTANCE: f32 = 520.0;
+
+    /// Seconds between path recalculations while following
+    pub const REPATH_SECS: f32 = 0.5;
+
+    /// While idle the pet looks around this often, in seconds
+    pub const IDLE_LOOK_SECS: f32 = 2.5;
+
+    /// Height of the idle breathing bob, in sprite pixels
+    pub const IDLE_BOB_PX: f32 = 1.5;
+
+    /// Breathing bob cycles per second, in radians
+    pub const IDLE_BOB_SPEED: f32 = 3.0;
+
+    /// Items within this distance of the pet are collected for the player
+    pub const COLLECT_RADIUS: f32 = 40.0;
+
+    /// Seconds between checks for items to collect
+    pub const COLLECT_INTERVAL_SECS: f32 = 0.25;
+}
+
 pub mod map {
     /// Size of a single tile in world units (64px base * 1.0 scale = 64)
     /// NOTE: This must match TILE_SIZE in generate.rs!
@@ -43,6 +405,193 @@ pub mod map {
     
     /// Z-height of each layer (used for Y-based depth sorting)
     pub const NODE_SIZE_Z: f32 = 1.0; // Add this line
+
+    /// Layer index of the props layer (trees, rocks, plants).
+    /// Characters and other Y-sorted entities share this layer's Z range.
+    pub const PROPS_LAYER: u32 = 4;
+
+    /// Tiles per worker batch when building the collision map.
+    /// Small maps fit in one batch and are processed on a single thread.
+    pub const COLLISION_BUILD_BATCH_SIZE: usize = 4096;
+
+    /// Mixed into the map seed for gameplay randomness, so fishing and the
+    /// pet don't follow the same random sequence as generation
+    pub const GAME_RNG_SALT: u64 = 0x5EED_6741_4D45_0000;
+}
+
+pub mod post_process {
+    /// Width of the river in tiles
+    pub const RIVER_WIDTH: u32 = 2;
+
+    /// Percent chance per column that the river drifts up or down a tile
+    pub const RIVER_MEANDER_PERCENT: u32 = 35;
+
+    /// Columns between dirt fords across the river, so it never walls the map off
+    pub const RIVER_FORD_SPACING: u32 = 24;
+
+    /// Rows around the map centre the river keeps clear of, so spawn stays dry
+    pub const RIVER_SPAWN_MARGIN: u32 = 12;
+
+    /// Number of ruined structures to place
+    pub const STRUCTURE_COUNT: u32 = 6;
+
+    /// Side length of a structure in tiles, walls included
+    pub const STRUCTURE_SIZE: u32 = 5;
+
+    /// Random spots tried per structure before giving up on it
+    pub const STRUCTURE_ATTEMPTS: u32 = 200;
+
+    /// Number of thorn and mud patches to scatter
+    pub const HAZARD_PATCH_COUNT: u32 = 14;
+
+    /// Largest distance from a patch's centre its tiles reach
+    pub const HAZARD_PATCH_RADIUS: i32 = 2;
+
+    /// Tiles around the map centre kept free of hazards, so spawn is safe
+    pub const HAZARD_SPAWN_MARGIN: i32 = 10;
+}
+
+pub mod outline {
+    use bevy::color::Color;
+
+    /// Outline drawn by `Highlighted::default()`
+    pub const DEFAULT_COLOR: Color = Color::srgb(1.0, 1.0, 0.85);
+    pub const DEFAULT_THICKNESS: f32 = 1.0;
+
+    /// How far in front of its sprite an outline is drawn
+    pub const OUTLINE_Z_OFFSET: f32 = 0.001;
+
+    /// Outline on the entity the player would interact with
+    pub const TARGET_COLOR: Color = Color::srgb(1.0, 0.95, 0.6);
+
+    /// Outline on entities on the tile being inspected (debug)
+    pub const INSPECT_COLOR: Color = Color::srgb(0.2, 0.9, 1.0);
+    pub const INSPECT_THICKNESS: f32 = 2.0;
+}
+
+pub mod fog {
+    use bevy::color::Color;
+
+    /// Distance around the player that stays fully visible
+    pub const VISION_RADIUS: f32 = 420.0;
+
+    /// Width of the fade from clear to full fog
+    pub const FOG_SOFTNESS: f32 = 160.0;
+
+    /// Fog tint; alpha is how dark unseen areas get
+    pub const FOG_COLOR: Color = Color::srgba(0.02, 0.02, 0.05, 0.75);
+
+    /// Colour-blind friendly tints, darkened from the Okabe-Ito blue and purple
+    pub const FOG_COLOR_BLUE: Color = Color::srgba(0.0, 0.11, 0.27, 0.8);
+    pub const FOG_COLOR_PURPLE: Color = Color::srgba(0.24, 0.08, 0.18, 0.8);
+
+    /// Outline drawn along the vision edge when the hard fog edge is on
+    pub const HARD_EDGE_COLOR: Color = Color::srgb(0.94, 0.89, 0.26);
+    pub const HARD_EDGE_WIDTH: f32 = 4.0;
+
+    /// Extra fog beyond the map edges so the camera never sees past it
+    pub const FOG_MARGIN: f32 = 1024.0;
+
+    /// Above the world and characters, below the camera
+    pub const FOG_Z: f32 = 900.0;
+
+    /// Player movement (world units) before the fog material is rewritten
+    pub const FOG_UPDATE_THRESHOLD: f32 = 1.0;
+}
+
+pub mod lighting {
+    use bevy::color::Color;
+
+    /// Most lights drawn (and revealed through the fog) at once; the
+    /// nearest to the player win. Must match `MAX_LIGHTS` in the shaders.
+    pub const MAX_LIGHTS: usize = 16;
+
+    /// Tint laid over the world at full night; alpha is how dark it gets
+    pub const NIGHT_COLOR: Color = Color::srgba(0.02, 0.03, 0.12, 0.7);
+
+    /// Above the world and characters, below the fog
+    pub const LIGHTING_Z: f32 = 850.0;
+
+    /// Campfire light: radius in world units and warm colour
+    pub const CAMPFIRE_RADIUS: f32 = 280.0;
+    pub const CAMPFIRE_COLOR: Color = Color::srgb(1.0, 0.62, 0.28);
+
+    /// Torch light: smaller and yellower than a campfire
+    pub const TORCH_RADIUS: f32 = 190.0;
+    pub const TORCH_COLOR: Color = Color::srgb(1.0, 0.8, 0.45);
+
+    /// How much a light's brightness wavers (0 = steady)
+    pub const FLICKER_AMOUNT: f32 = 0.08;
+
+    /// Flicker cycles per second
+    pub const FLICKER_SPEED: f32 = 7.0;
+}
+
+pub mod wind {
+    /// Wind strength between gusts (0 = still air, 1 = a normal gust)
+    pub const CALM_STRENGTH: f32 = 0.25;
+
+    /// Strongest gust in fair weather
+    pub const GUST_STRENGTH: f32 = 1.0;
+
+    /// Gusts are this much stronger while it rains
+    pub const RAIN_MULTIPLIER: f32 = 1.8;
+
+    /// Seconds between picking a new gust strength
+    pub const GUST_INTERVAL_SECS: f32 = 4.0;
+
+    /// How quickly the wind eases toward a new gust (per second)
+    pub const GUST_EASE: f32 = 0.8;
+
+    /// Sway cycles per second, in radians
+    pub const SWAY_SPEED: f32 = 2.2;
+
+    /// Phase shift per world unit up the map, so gusts roll across rows
+    /// instead of every plant swaying in lockstep
+    pub const SWAY_WAVE: f32 = 0.015;
+
+    /// Furthest a tree canopy sways at full strength, in pixels
+    pub const CANOPY_SWAY_PX: f32 = 1.5;
+
+    /// Furthest small plants and reeds sway at full strength, in pixels
+    pub const PLANT_SWAY_PX: f32 = 0.75;
+}
+
+pub mod culling {
+    /// Seconds between culling passes
+    pub const CULL_INTERVAL_SECS: f32 = 0.2;
+
+    /// Distance past the camera view that still counts as on screen
+    /// (covers sprite size and camera movement between passes)
+    pub const VISIBLE_MARGIN: f32 = 192.0;
+
+    /// Distance past the camera view beyond which AI stops running
+    pub const SLEEP_MARGIN: f32 = 1600.0;
+}
+
+#[cfg(feature = "net")]
+pub mod net {
+    /// UDP port the host listens on when `--host` is given without one
+    pub const DEFAULT_PORT: u16 = 7777;
+
+    /// Seconds between player position updates (only sent after moving)
+    pub const SEND_INTERVAL_SECS: f32 = 0.05;
+
+    /// Smallest player movement, in world units, worth sending
+    pub const POSITION_EPSILON: f32 = 0.5;
+
+    /// Seconds between join requests while a client waits for the host
+    pub const HELLO_INTERVAL_SECS: f32 = 1.0;
+
+    /// How quickly the remote player's sprite catches up to received positions
+    pub const REMOTE_LERP_SPEED: f32 = 12.0;
+
+    /// Seconds between resends of world edits the peer hasn't acked
+    pub const RESEND_INTERVAL_SECS: f32 = 0.25;
+
+    /// Largest UDP payload; `Welcome` carries the host's whole world diff,
+    /// everything else is a small JSON object
+    pub const MAX_DATAGRAM_SIZE: usize = 65_507;
 }
 
 pub mod camera {
@@ -51,4 +600,31 @@ pub mod camera {
     
     /// Z position for the camera (must be high to see all layers)
     pub const CAMERA_Z: f32 = 1000.0;
-}
\ No newline at end of file
+
+    /// Debug free-fly pan speed in world units per second at 1x zoom
+    pub const FREE_FLY_SPEED: f32 = 900.0;
+
+    /// Zoom change per mouse wheel line in free-fly (scale is multiplied by 1 ± this)
+    pub const FREE_FLY_ZOOM_STEP: f32 = 0.1;
+
+    /// Free-fly zoom limits, as orthographic projection scale
+    pub const FREE_FLY_MIN_SCALE: f32 = 0.25;
+    pub const FREE_FLY_MAX_SCALE: f32 = 12.0;
+}
+pub mod tile_history {
+    /// Most terrain edits the debug undo history keeps; older ones are dropped
+    pub const HISTORY_LIMIT: usize = 64;
+}
+
+pub mod headless {
+    /// Ticks `--headless` simulates once the player has spawned
+    pub const DEFAULT_TICKS: u32 = 600;
+
+    /// Ticks to wait for generation, the collision map and the player before giving up
+    pub const READY_TIMEOUT_TICKS: u32 = 10_000;
+}
+
+pub mod replay {
+    /// Where `--record` writes the replay when no path is given
+    pub const RECORD_PATH: &str = "replays/latest.ron";
+}
@@ -0,0 +1,97 @@
+// src/controls/action.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Everything the player can do with a rebindable key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum InputAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Run,
+    Jump,
+    Interact,
+    UseTool,
+    CycleHotbar,
+    CastPower,
+    Pause,
+    Mute,
+    QuickSave,
+    QuickLoad,
+    EventLog,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 15] = [
+        InputAction::MoveUp,
+        InputAction::MoveDown,
+        InputAction::MoveLeft,
+        InputAction::MoveRight,
+        InputAction::Run,
+        InputAction::Jump,
+        InputAction::Interact,
+        InputAction::UseTool,
+        InputAction::CycleHotbar,
+        InputAction::CastPower,
+        InputAction::Pause,
+        InputAction::Mute,
+        InputAction::QuickSave,
+        InputAction::QuickLoad,
+        InputAction::EventLog,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InputAction::MoveUp => "Move up",
+            InputAction::MoveDown => "Move down",
+            InputAction::MoveLeft => "Move left",
+            InputAction::MoveRight => "Move right",
+            InputAction::Run => "Run",
+            InputAction::Jump => "Jump",
+            InputAction::Interact => "Interact",
+            InputAction::UseTool => "Use tool",
+            InputAction::CycleHotbar => "Cycle hotbar",
+            InputAction::CastPower => "Cast power",
+            InputAction::Pause => "Pause",
+            InputAction::Mute => "Mute",
+            InputAction::QuickSave => "Quick save",
+            InputAction::QuickLoad => "Quick load",
+            InputAction::EventLog => "Event log",
+        }
+    }
+
+    /// Keys bound on first launch.
+    pub fn default_keys(&self) -> Vec<KeyCode> {
+        match self {
+            InputAction::MoveUp => vec![KeyCode::ArrowUp],
+            InputAction::MoveDown => vec![KeyCode::ArrowDown],
+            InputAction::MoveLeft => vec![KeyCode::ArrowLeft],
+            InputAction::MoveRight => vec![KeyCode::ArrowRight],
+            InputAction::Run => vec![KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            InputAction::Jump => vec![KeyCode::Space],
+            InputAction::Interact => vec![KeyCode::KeyE],
+            InputAction::UseTool => vec![KeyCode::KeyF],
+            InputAction::CycleHotbar => vec![KeyCode::KeyQ],
+            InputAction::CastPower => vec![KeyCode::ControlLeft, KeyCode::ControlRight],
+            InputAction::Pause => vec![KeyCode::Escape],
+            InputAction::Mute => vec![KeyCode::KeyM],
+            InputAction::QuickSave => vec![KeyCode::F5],
+            InputAction::QuickLoad => vec![KeyCode::F9],
+            InputAction::EventLog => vec![KeyCode::KeyL],
+        }
+    }
+}
+
+/// Short display name for a key, e.g. `KeyF` -> "F", `ArrowUp` -> "Up".
+pub fn key_name(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    for prefix in ["Key", "Digit", "Arrow"] {
+        if let Some(rest) = name.strip_prefix(prefix)
+            && !rest.is_empty()
+        {
+            return rest.to_string();
+        }
+    }
+    name
+}
@@ -0,0 +1,111 @@
+// src/controls/bindings.rs
+use std::collections::BTreeMap;
+use std::fs;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::action::{key_name, InputAction};
+use crate::config::settings::CONTROLS_PATH;
+
+/// Which keys trigger each `InputAction`, stored in `CONTROLS_PATH`.
+///
+/// Gameplay code asks this resource instead of checking `KeyCode`s directly,
+/// so rebinding applies everywhere at once.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ControlBindings {
+    keys: BTreeMap<InputAction, Vec<KeyCode>>,
+}
+
+impl Default for ControlBindings {
+    fn default() -> Self {
+        Self {
+            keys: InputAction::ALL
+                .iter()
+                .map(|action| (*action, action.default_keys()))
+                .collect(),
+        }
+    }
+}
+
+impl ControlBindings {
+    pub fn keys(&self, action: InputAction) -> &[KeyCode] {
+        self.keys.get(&action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    pub fn pressed(&self, action: InputAction, input: &ButtonInput<KeyCode>) -> bool {
+        input.any_pressed(self.keys(action).iter().copied())
+    }
+
+    pub fn just_pressed(&self, action: InputAction, input: &ButtonInput<KeyCode>) -> bool {
+        input.any_just_pressed(self.keys(action).iter().copied())
+    }
+
+    /// Keys of an action joined for display, e.g. "ShiftLeft / ShiftRight".
+    pub fn describe(&self, action: InputAction) -> String {
+        let names: Vec<String> = self.keys(action).iter().map(|key| key_name(*key)).collect();
+        if names.is_empty() {
+            "unbound".to_string()
+        } else {
+            names.join(" / ")
+        }
+    }
+
+    /// The action (other than `except`) that already uses `key`, if any.
+    pub fn action_for(&self, key: KeyCode, except: InputAction) -> Option<InputAction> {
+        self.keys
+            .iter()
+            .find(|(action, keys)| **action != except && keys.contains(&key))
+            .map(|(action, _)| *action)
+    }
+
+    /// Binds `key` as the only key of `action`.
+    ///
+    /// If another action was using the key, it takes over `action`'s old keys
+    /// instead, so nothing ends up unbound. Returns that action.
+    pub fn rebind(&mut self, action: InputAction, key: KeyCode) -> Option<InputAction> {
+        let conflict = self.action_for(key, action);
+        let previous = self.keys.insert(action, vec![key]).unwrap_or_default();
+
+        if let Some(other) = conflict {
+            let other_keys = self.keys.entry(other).or_default();
+            other_keys.retain(|bound| *bound != key);
+            if other_keys.is_empty() {
+                *other_keys = previous;
+            }
+        }
+        conflict
+    }
+
+    /// Read bindings from disk, falling back to defaults if missing or invalid.
+    pub fn load() -> Self {
+        let Ok(text) = fs::read_to_string(CONTROLS_PATH) else {
+            return Self::default();
+        };
+        let mut bindings: Self = ron::from_str(&text).unwrap_or_else(|err| {
+            warn!("Ignoring invalid {}: {}", CONTROLS_PATH, err);
+            Self::default()
+        });
+        // Actions added since the file was written get their defaults
+        for action in InputAction::ALL {
+            bindings.keys.entry(action).or_insert_with(|| action.default_keys());
+        }
+        bindings
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| err.to_string())?;
+        fs::write(CONTROLS_PATH, text).map_err(|err| err.to_string())
+    }
+}
+
+/// Run condition: true on the frame any key bound to `action` is pressed.
+pub fn action_just_pressed(
+    action: InputAction,
+) -> impl Fn(Res<ControlBindings>, Res<ButtonInput<KeyCode>>) -> bool + Clone {
+    move |bindings: Res<ControlBindings>, input: Res<ButtonInput<KeyCode>>| {
+        bindings.just_pressed(action, &input)
+    }
+}
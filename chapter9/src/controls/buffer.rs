@@ -0,0 +1,66 @@
+// src/controls/buffer.rs
+use std::collections::HashMap;
+
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+
+use super::{ControlBindings, InputAction};
+use crate::config::controls::INPUT_BUFFER_SECS;
+
+/// Actions whose presses are kept for a moment if they can't happen yet.
+const BUFFERED_ACTIONS: [InputAction; 3] =
+    [InputAction::Jump, InputAction::Interact, InputAction::CastPower];
+
+/// Recent presses of buffered actions that haven't been acted on.
+///
+/// A press stays queued for `INPUT_BUFFER_SECS`, so pressing attack just
+/// before the cooldown ends, or interact just before something comes into
+/// reach, still goes through once it can. Systems read `consume` instead of
+/// `ControlBindings::just_pressed` for these actions.
+#[derive(Resource, Debug, Default)]
+pub struct InputBuffer {
+    /// Fixed-step time at each queued press. The actions are consumed on
+    /// fixed ticks, so they expire by ticks run rather than by frames.
+    presses: HashMap<InputAction, f32>,
+}
+
+impl InputBuffer {
+    /// Whether `action` was pressed recently and not consumed yet.
+    pub fn is_buffered(&self, action: InputAction) -> bool {
+        self.presses.contains_key(&action)
+    }
+
+    /// Takes the queued press of `action`, returning whether there was one.
+    /// Only call this once the action actually happens.
+    pub fn consume(&mut self, action: InputAction) -> bool {
+        self.presses.remove(&action).is_some()
+    }
+}
+
+/// Plugin that fills the `InputBuffer` from the keyboard each frame.
+pub struct InputBufferPlugin;
+
+impl Plugin for InputBufferPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputBuffer>()
+            .add_systems(PreUpdate, buffer_presses.after(InputSystems));
+    }
+}
+
+/// Queues new presses of buffered actions and drops the ones that expired.
+pub fn buffer_presses(
+    time: Res<Time<Fixed>>,
+    input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<ControlBindings>,
+    mut buffer: ResMut<InputBuffer>,
+) {
+    let now = time.elapsed_secs();
+    buffer
+        .presses
+        .retain(|_, pressed_at| now - *pressed_at <= INPUT_BUFFER_SECS);
+    for action in BUFFERED_ACTIONS {
+        if bindings.just_pressed(action, &input) {
+            buffer.presses.insert(action, now);
+        }
+    }
+}
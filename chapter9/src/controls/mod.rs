@@ -0,0 +1,30 @@
+// src/controls/mod.rs
+mod action;
+mod bindings;
+mod buffer;
+mod rumble;
+
+use bevy::prelude::*;
+
+pub use action::{key_name, InputAction};
+pub use bindings::{action_just_pressed, ControlBindings};
+pub use buffer::{buffer_presses, InputBuffer, InputBufferPlugin};
+pub use rumble::{PlayRumble, RumbleId};
+
+/// Plugin owning the rebindable key map, the input buffer and gamepad rumble.
+///
+/// Bumping into terrain, picking items up, taking damage and harvesting
+/// pulse every connected gamepad, scaled by the rumble settings.
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ControlBindings::load())
+            .add_plugins(InputBufferPlugin)
+            .add_observer(rumble::play_rumble)
+            .add_observer(rumble::rumble_on_bump)
+            .add_observer(rumble::rumble_on_pickup)
+            .add_observer(rumble::rumble_on_damage)
+            .add_observer(rumble::rumble_on_hazard);
+    }
+}
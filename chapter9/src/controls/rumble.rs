@@ -0,0 +1,94 @@
+// src/controls/rumble.rs
+use std::time::Duration;
+
+use bevy::input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::*;
+
+use crate::characters::collider::ObstacleHit;
+use crate::characters::input::Player;
+use crate::combat::DamageEvent;
+use crate::inventory::ItemPickedUp;
+use crate::projectile::ProjectileHit;
+use crate::settings::GameSettings;
+
+/// Logical rumble pulses, named by what caused them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RumbleId {
+    Bump,
+    Pickup,
+    Damage,
+    Harvest,
+}
+
+impl RumbleId {
+    /// Strong (low-frequency) and weak (high-frequency) motor strengths at full intensity
+    fn motors(&self) -> (f32, f32) {
+        match self {
+            RumbleId::Bump => (0.35, 0.0),
+            RumbleId::Pickup => (0.0, 0.4),
+            RumbleId::Damage => (0.9, 0.6),
+            RumbleId::Harvest => (0.5, 0.3),
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        Duration::from_millis(match self {
+            RumbleId::Bump => 80,
+            RumbleId::Pickup => 60,
+            RumbleId::Damage => 250,
+            RumbleId::Harvest => 140,
+        })
+    }
+}
+
+/// Request a rumble pulse on every connected gamepad.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PlayRumble(pub RumbleId);
+
+/// Observer that sends a rumble pulse, scaled by the player's settings.
+pub fn play_rumble(
+    request: On<PlayRumble>,
+    settings: Res<GameSettings>,
+    gamepads: Query<Entity, With<Gamepad>>,
+    mut rumble: MessageWriter<GamepadRumbleRequest>,
+) {
+    let gain = settings.rumble_gain();
+    if gain <= 0.0 {
+        return;
+    }
+
+    let (strong, weak) = request.0.motors();
+    let intensity = GamepadRumbleIntensity {
+        strong_motor: strong * gain,
+        weak_motor: weak * gain,
+    };
+    for gamepad in gamepads.iter() {
+        rumble.write(GamepadRumbleRequest::Add {
+            duration: request.0.duration(),
+            intensity,
+            gamepad,
+        });
+    }
+}
+
+pub fn rumble_on_bump(hit: On<ObstacleHit>, mut commands: Commands, players: Query<(), With<Player>>) {
+    if players.contains(hit.entity) {
+        commands.trigger(PlayRumble(RumbleId::Bump));
+    }
+}
+
+pub fn rumble_on_pickup(_picked: On<ItemPickedUp>, mut commands: Commands) {
+    commands.trigger(PlayRumble(RumbleId::Pickup));
+}
+
+pub fn rumble_on_damage(hit: On<ProjectileHit>, mut commands: Commands, players: Query<(), With<Player>>) {
+    if players.contains(hit.target) {
+        commands.trigger(PlayRumble(RumbleId::Damage));
+    }
+}
+
+pub fn rumble_on_hazard(damage: On<DamageEvent>, mut commands: Commands, players: Query<(), With<Player>>) {
+    if players.contains(damage.target) {
+        commands.trigger(PlayRumble(RumbleId::Damage));
+    }
+}
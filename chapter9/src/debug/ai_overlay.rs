@@ -0,0 +1,146 @@
+// src/debug/ai_overlay.rs
+use bevy::ecs::entity::EntityHashSet;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+use crate::enemy::{AIBehavior, AiState, Enemy, EnemyPath};
+use crate::state::GameState;
+
+/// Above the fog so labels stay readable in unexplored areas.
+const LABEL_Z: f32 = 950.0;
+const LABEL_OFFSET: Vec2 = Vec2::new(0.0, 56.0);
+
+/// Enemy AI visualization, in debug builds only.
+///
+/// F8 toggles the overlay: each enemy gets its detection and attack radii,
+/// the remaining waypoints of its A* path, and a label with its AI state,
+/// all colored by that state.
+pub struct AiOverlayPlugin;
+
+impl Plugin for AiOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AiOverlayEnabled>().add_systems(
+            Update,
+            (
+                toggle_ai_overlay.run_if(input_just_pressed(KeyCode::F8)),
+                (sync_ai_labels, draw_ai_gizmos).run_if(resource_equals(AiOverlayEnabled(true))),
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+#[derive(Resource, Default, PartialEq, Eq)]
+pub struct AiOverlayEnabled(pub bool);
+
+/// World-space text following an enemy, showing its `AiState`.
+#[derive(Component)]
+pub struct AiStateLabel {
+    enemy: Entity,
+}
+
+fn state_color(state: AiState) -> Color {
+    match state {
+        AiState::Asleep => Color::srgb(0.5, 0.5, 0.5),
+        AiState::Idle => Color::srgb(0.3, 0.6, 1.0),
+        AiState::Attacking => Color::srgb(1.0, 0.25, 0.2),
+        AiState::Chasing => Color::srgb(1.0, 0.75, 0.1),
+        AiState::Direct => Color::srgb(1.0, 0.3, 1.0),
+    }
+}
+
+pub fn toggle_ai_overlay(
+    mut commands: Commands,
+    mut enabled: ResMut<AiOverlayEnabled>,
+    labels: Query<Entity, With<AiStateLabel>>,
+) {
+    enabled.0 = !enabled.0;
+    if !enabled.0 {
+        for label in labels.iter() {
+            commands.entity(label).despawn();
+        }
+    }
+    info!(
+        "AI overlay {} (F8 to toggle)",
+        if enabled.0 { "enabled" } else { "disabled" }
+    );
+}
+
+/// Keeps one label per enemy, moving it along and rewriting it as the state changes.
+pub fn sync_ai_labels(
+    mut commands: Commands,
+    enemies: Query<(Entity, &Transform, &AiState, &EnemyPath), With<Enemy>>,
+    mut labels: Query<
+        (Entity, &AiStateLabel, &mut Transform, &mut Text2d, &mut TextColor),
+        Without<Enemy>,
+    >,
+) {
+    let mut labelled = EntityHashSet::default();
+    for (label, owner, mut transform, mut text, mut color) in labels.iter_mut() {
+        let Ok((_, enemy_transform, state, path)) = enemies.get(owner.enemy) else {
+            commands.entity(label).despawn();
+            continue;
+        };
+        labelled.insert(owner.enemy);
+        transform.translation =
+            (enemy_transform.translation.truncate() + LABEL_OFFSET).extend(LABEL_Z);
+        let description = describe_state(*state, path);
+        if text.0 != description {
+            text.0 = description;
+        }
+        color.set_if_neq(TextColor(state_color(*state)));
+    }
+
+    for (enemy, transform, state, path) in enemies.iter() {
+        if labelled.contains(&enemy) {
+            continue;
+        }
+        commands.spawn((
+            AiStateLabel { enemy },
+            Text2d::new(describe_state(*state, path)),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(state_color(*state)),
+            Transform::from_translation((transform.translation.truncate() + LABEL_OFFSET).extend(LABEL_Z)),
+        ));
+    }
+}
+
+fn describe_state(state: AiState, path: &EnemyPath) -> String {
+    let remaining = path.waypoints.len().saturating_sub(path.current_index);
+    match state {
+        AiState::Chasing => format!("{} ({} waypoints)", state.label(), remaining),
+        _ => state.label().to_string(),
+    }
+}
+
+/// Perception radii and the rest of each enemy's current path.
+pub fn draw_ai_gizmos(
+    enemies: Query<(&Transform, &AIBehavior, &AiState, &EnemyPath), With<Enemy>>,
+    mut gizmos: Gizmos,
+) {
+    for (transform, behavior, state, path) in enemies.iter() {
+        let position = transform.translation.truncate();
+        let color = state_color(*state);
+        gizmos.circle_2d(position, behavior.detection_range, color.with_alpha(0.25));
+        gizmos.circle_2d(position, behavior.attack_range, color.with_alpha(0.6));
+
+        if !path.has_path() {
+            continue;
+        }
+        let remaining = &path.waypoints[path.current_index..];
+        gizmos.linestrip_2d(
+            std::iter::once(position).chain(remaining.iter().copied()),
+            color,
+        );
+        for waypoint in remaining {
+            gizmos.circle_2d(*waypoint, 4.0, color);
+        }
+        if let Some(target) = path.current_waypoint() {
+            gizmos.circle_2d(target, EnemyPath::WAYPOINT_THRESHOLD, Color::WHITE);
+        }
+    }
+}
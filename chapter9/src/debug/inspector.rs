@@ -0,0 +1,36 @@
+// src/debug/inspector.rs
+use bevy::input::common_conditions::input_toggle_active;
+use bevy::prelude::*;
+use bevy_inspector_egui::bevy_egui::EguiPlugin;
+use bevy_inspector_egui::quick::{
+    AssetInspectorPlugin, ResourceInspectorPlugin, WorldInspectorPlugin,
+};
+
+use crate::clock::TimeOfDay;
+use crate::fog::CircularFogMaterial;
+use crate::settings::GameSettings;
+
+/// Live-editing windows for tuning resources, player components and the
+/// fog material. F1 toggles them; they start hidden.
+pub struct InspectorPlugin;
+
+impl Plugin for InspectorPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin::default());
+        }
+
+        // Lets the inspector find fog materials through `Assets`
+        app.register_asset_reflect::<CircularFogMaterial>();
+
+        app.add_plugins((
+            WorldInspectorPlugin::new().run_if(input_toggle_active(false, KeyCode::F1)),
+            ResourceInspectorPlugin::<GameSettings>::default()
+                .run_if(input_toggle_active(false, KeyCode::F1)),
+            ResourceInspectorPlugin::<TimeOfDay>::default()
+                .run_if(input_toggle_active(false, KeyCode::F1)),
+            AssetInspectorPlugin::<CircularFogMaterial>::default()
+                .run_if(input_toggle_active(false, KeyCode::F1)),
+        ));
+    }
+}
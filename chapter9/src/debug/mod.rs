@@ -0,0 +1,31 @@
+// src/debug/mod.rs
+//! Developer tooling, each tool compiled in only behind its cargo feature
+//! or in debug builds.
+
+#[cfg(feature = "inspector")]
+mod inspector;
+
+#[cfg(feature = "inspector")]
+pub use inspector::InspectorPlugin;
+
+#[cfg(debug_assertions)]
+mod ai_overlay;
+#[cfg(debug_assertions)]
+mod movement_cheats;
+#[cfg(debug_assertions)]
+mod tile_history;
+#[cfg(debug_assertions)]
+mod tile_inspector;
+#[cfg(debug_assertions)]
+mod time_scale;
+
+#[cfg(debug_assertions)]
+pub use ai_overlay::AiOverlayPlugin;
+#[cfg(debug_assertions)]
+pub use movement_cheats::MovementCheatsPlugin;
+#[cfg(debug_assertions)]
+pub use tile_history::TileHistoryPlugin;
+#[cfg(debug_assertions)]
+pub use tile_inspector::TileInspectorPlugin;
+#[cfg(debug_assertions)]
+pub use time_scale::TimeScalePlugin;
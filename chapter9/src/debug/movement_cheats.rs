@@ -0,0 +1,76 @@
+// src/debug/movement_cheats.rs
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+use crate::characters::collider::{Collider, Noclip};
+use crate::characters::input::Player;
+use crate::state::GameState;
+use crate::ui::ShowToast;
+use crate::util::picking::CursorPicker;
+
+/// Noclip and teleport for reaching awkward spots quickly, in debug builds only.
+///
+/// F7 toggles noclip on the player. Ctrl+click teleports the player's feet
+/// to the cursor, moving to the nearest walkable tile if the spot is blocked;
+/// Ctrl+Shift+click (or any teleport while noclipping) skips that check.
+pub struct MovementCheatsPlugin;
+
+impl Plugin for MovementCheatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                toggle_noclip.run_if(input_just_pressed(KeyCode::F7)),
+                teleport_to_cursor.run_if(input_just_pressed(MouseButton::Left)),
+            )
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+pub fn toggle_noclip(mut commands: Commands, players: Query<(Entity, Has<Noclip>), With<Player>>) {
+    let Ok((player, noclip)) = players.single() else {
+        return;
+    };
+    if noclip {
+        commands.entity(player).remove::<Noclip>();
+    } else {
+        commands.entity(player).insert(Noclip);
+    }
+    let message = if noclip { "Noclip off" } else { "Noclip on (F7 to toggle)" };
+    info!("{}", message);
+    commands.trigger(ShowToast::new(message));
+}
+
+pub fn teleport_to_cursor(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    picker: CursorPicker,
+    mut players: Query<(&mut Transform, &Collider, Has<Noclip>), With<Player>>,
+) {
+    if !keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+    let force = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let (Some(map), Some(target), Ok((mut transform, collider, noclip))) =
+        (picker.map(), picker.world_position(), players.single_mut())
+    else {
+        return;
+    };
+
+    let feet = if force || noclip || map.is_circle_clear_on(target, collider.radius, collider.layer) {
+        target
+    } else {
+        let Some(cell) = map.find_nearest_walkable(map.world_to_grid(target)) else {
+            commands.trigger(ShowToast::new("No walkable tile near there"));
+            return;
+        };
+        map.grid_to_world(cell.x, cell.y)
+    };
+
+    // The collider sits at the feet, so shift the transform by its offset
+    let position = feet - collider.offset;
+    transform.translation.x = position.x;
+    transform.translation.y = position.y;
+    info!("Teleported player to {:?} (grid {:?})", feet, map.world_to_grid(feet));
+}
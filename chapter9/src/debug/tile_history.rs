@@ -0,0 +1,175 @@
+// src/debug/tile_history.rs
+use std::collections::{HashSet, VecDeque};
+
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+use crate::collision::{CollisionMap, TileMarker, TileType};
+use crate::config::tile_history::HISTORY_LIMIT;
+use crate::farming::set_ground_tile;
+use crate::map::TileChanged;
+use crate::placement::{remove_placed, spawn_placed, ObjectPlaced, PlaceableKind, PlacedObjects};
+use crate::rendering::YSort;
+use crate::state::GameState;
+use crate::ui::ShowToast;
+
+/// Undo and redo for terrain edits, in debug builds only.
+///
+/// Every `TileChanged` (tilling, putting down fences, ...) is recorded, up to
+/// `HISTORY_LIMIT` of them. F11 reverts the latest edit and F12 redoes the
+/// last one undone; a new edit clears the redo list. The history is dropped
+/// when a new world loads.
+pub struct TileHistoryPlugin;
+
+impl Plugin for TileHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TileHistory>()
+            .add_observer(record_tile_change)
+            .add_systems(OnEnter(GameState::Loading), clear_tile_history)
+            .add_systems(
+                Update,
+                (
+                    undo_tile_change.run_if(input_just_pressed(KeyCode::F11)),
+                    redo_tile_change.run_if(input_just_pressed(KeyCode::F12)),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct TileHistory {
+    undo: VecDeque<TileChanged>,
+    redo: Vec<TileChanged>,
+    /// Cells whose next `TileChanged` comes from an undo or redo, so it
+    /// isn't recorded as a new edit
+    replaying: HashSet<IVec2>,
+}
+
+pub fn record_tile_change(changed: On<TileChanged>, mut history: ResMut<TileHistory>) {
+    if history.replaying.remove(&changed.cell) {
+        return;
+    }
+    if history.undo.len() == HISTORY_LIMIT {
+        history.undo.pop_front();
+    }
+    history.undo.push_back(*changed);
+    history.redo.clear();
+}
+
+pub fn clear_tile_history(mut history: ResMut<TileHistory>) {
+    *history = TileHistory::default();
+}
+
+/// Puts `cell` back from `current` to `target`. Fences are placed objects,
+/// so they are despawned or respawned; other edits repaint the ground.
+fn apply_tile(
+    commands: &mut Commands,
+    map: &mut CollisionMap,
+    placed: &mut PlacedObjects,
+    ground_tiles: &mut Query<(&mut TileMarker, &mut Sprite, &Transform), Without<YSort>>,
+    cell: IVec2,
+    current: TileType,
+    target: TileType,
+) -> bool {
+    match (current, target) {
+        (TileType::Fence, ground) => remove_placed(commands, map, placed, cell, ground),
+        (_, TileType::Fence) => {
+            let kind = PlaceableKind::Fence;
+            let spawned = spawn_placed(commands, map, placed, kind, cell).is_some();
+            if spawned {
+                commands.trigger(ObjectPlaced { kind, cell });
+            }
+            spawned
+        }
+        (_, ground) => set_ground_tile(map, cell, ground, ground_tiles).is_some(),
+    }
+}
+
+/// Which way through the history a replay goes.
+#[derive(Clone, Copy)]
+enum Replay {
+    Undo,
+    Redo,
+}
+
+fn replay(
+    direction: Replay,
+    commands: &mut Commands,
+    history: &mut TileHistory,
+    map: &mut CollisionMap,
+    placed: &mut PlacedObjects,
+    ground_tiles: &mut Query<(&mut TileMarker, &mut Sprite, &Transform), Without<YSort>>,
+) {
+    let change = match direction {
+        Replay::Undo => history.undo.pop_back(),
+        Replay::Redo => history.redo.pop(),
+    };
+    let Some(change) = change else {
+        commands.trigger(ShowToast::new(match direction {
+            Replay::Undo => "Nothing to undo",
+            Replay::Redo => "Nothing to redo",
+        }));
+        return;
+    };
+    let (from, to) = match direction {
+        Replay::Undo => (change.to, change.from),
+        Replay::Redo => (change.from, change.to),
+    };
+
+    // Something else may have changed the cell since; don't stomp on it
+    if map.get_tile(change.cell.x, change.cell.y) != Some(from)
+        || !apply_tile(commands, map, placed, ground_tiles, change.cell, from, to)
+    {
+        commands.trigger(ShowToast::new(format!(
+            "Can't replay the edit at {:?}, the tile changed since",
+            change.cell
+        )));
+        return;
+    }
+
+    // Tell the chunk meshes (and any peer) about it without recording it again
+    history.replaying.insert(change.cell);
+    commands.trigger(TileChanged {
+        cell: change.cell,
+        from,
+        to,
+    });
+    match direction {
+        Replay::Undo => history.redo.push(change),
+        Replay::Redo => history.undo.push_back(change),
+    }
+    let verb = match direction {
+        Replay::Undo => "Undid",
+        Replay::Redo => "Redid",
+    };
+    let message = format!("{} {:?} -> {:?} at {:?}", verb, from, to, change.cell);
+    info!("{}", message);
+    commands.trigger(ShowToast::new(message));
+}
+
+pub fn undo_tile_change(
+    mut commands: Commands,
+    mut history: ResMut<TileHistory>,
+    collision_map: Option<ResMut<CollisionMap>>,
+    mut placed: ResMut<PlacedObjects>,
+    mut ground_tiles: Query<(&mut TileMarker, &mut Sprite, &Transform), Without<YSort>>,
+) {
+    let Some(mut map) = collision_map else {
+        return;
+    };
+    replay(Replay::Undo, &mut commands, &mut history, &mut map, &mut placed, &mut ground_tiles);
+}
+
+pub fn redo_tile_change(
+    mut commands: Commands,
+    mut history: ResMut<TileHistory>,
+    collision_map: Option<ResMut<CollisionMap>>,
+    mut placed: ResMut<PlacedObjects>,
+    mut ground_tiles: Query<(&mut TileMarker, &mut Sprite, &Transform), Without<YSort>>,
+) {
+    let Some(mut map) = collision_map else {
+        return;
+    };
+    replay(Replay::Redo, &mut commands, &mut history, &mut map, &mut placed, &mut ground_tiles);
+}
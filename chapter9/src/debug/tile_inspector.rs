@@ -0,0 +1,242 @@
+// src/debug/tile_inspector.rs
+use std::fmt::Write;
+
+use bevy::ecs::query::QueryData;
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+use bevy_procedural_tilemaps::proc_gen::generator::model::ModelInstance;
+
+use crate::collision::{CollisionMap, TileMarker};
+use crate::config::outline::{INSPECT_COLOR, INSPECT_THICKNESS};
+use crate::harvest::ResourceNode;
+use crate::inventory::Pickable;
+use crate::map::tilemap::TILEMAP;
+use crate::map::world_id::WorldEntityId;
+use crate::outline::Highlighted;
+use crate::placement::Placed;
+use crate::rendering::{BakedTile, YSort};
+use crate::state::GameState;
+use crate::util::picking::CursorPicker;
+
+/// Click-to-inspect for tiles, in debug builds only.
+///
+/// The I key toggles inspect mode; while it's on, left-clicking a tile
+/// outlines it and the sprites on it, and lists its collision data and
+/// every entity placed on it.
+pub struct TileInspectorPlugin;
+
+impl Plugin for TileInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TileInspector>()
+            .add_systems(Startup, spawn_inspector_panel)
+            .add_systems(
+                Update,
+                (
+                    toggle_tile_inspector.run_if(input_just_pressed(KeyCode::KeyI)),
+                    inspect_clicked_tile
+                        .run_if(inspector_enabled)
+                        .run_if(input_just_pressed(MouseButton::Left)),
+                    outline_inspected_tile.run_if(inspector_enabled),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Whether inspect mode is on, the cell last clicked and the entities on it
+/// that are outlined.
+#[derive(Resource, Default)]
+pub struct TileInspector {
+    enabled: bool,
+    cell: Option<IVec2>,
+    highlighted: Vec<Entity>,
+}
+
+impl TileInspector {
+    fn clear_highlights(&mut self, commands: &mut Commands) {
+        for entity in self.highlighted.drain(..) {
+            if let Ok(mut entity) = commands.get_entity(entity) {
+                entity.try_remove::<Highlighted>();
+            }
+        }
+    }
+}
+
+fn inspector_enabled(inspector: Res<TileInspector>) -> bool {
+    inspector.enabled
+}
+
+/// Panel listing the inspected tile; hidden while inspect mode is off.
+#[derive(Component)]
+pub struct TileInspectorPanel;
+
+#[derive(Component)]
+pub struct TileInspectorText;
+
+/// Everything worth showing about a world entity on the inspected cell.
+#[derive(QueryData)]
+pub struct InspectedEntity {
+    entity: Entity,
+    transform: &'static Transform,
+    sprite: Option<&'static Sprite>,
+    id: &'static WorldEntityId,
+    model: Option<&'static ModelInstance>,
+    marker: Option<&'static TileMarker>,
+    pickable: Option<&'static Pickable>,
+    resource_node: Option<&'static ResourceNode>,
+    placed: Option<&'static Placed>,
+    baked: Has<BakedTile>,
+    y_sorted: Has<YSort>,
+}
+
+fn spawn_inspector_panel(mut commands: Commands) {
+    commands
+        .spawn((
+            TileInspectorPanel,
+            Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                top: Val::Px(120.0),
+                left: Val::Px(16.0),
+                max_width: Val::Px(520.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.85)),
+            GlobalZIndex(20),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                TileInspectorText,
+                Text::new("Click a tile to inspect it"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+pub fn toggle_tile_inspector(
+    mut commands: Commands,
+    mut inspector: ResMut<TileInspector>,
+    mut panels: Query<&mut Node, With<TileInspectorPanel>>,
+) {
+    inspector.enabled = !inspector.enabled;
+    if !inspector.enabled {
+        inspector.clear_highlights(&mut commands);
+    }
+    for mut node in panels.iter_mut() {
+        node.display = if inspector.enabled { Display::Flex } else { Display::None };
+    }
+    info!(
+        "Tile inspector {} (I to toggle)",
+        if inspector.enabled { "enabled" } else { "disabled" }
+    );
+}
+
+/// Converts the click to a grid cell and describes what's on it.
+pub fn inspect_clicked_tile(
+    mut commands: Commands,
+    picker: CursorPicker,
+    entities: Query<InspectedEntity>,
+    mut inspector: ResMut<TileInspector>,
+    mut texts: Query<&mut Text, With<TileInspectorText>>,
+) {
+    let (Some(map), Some(cell)) = (picker.map(), picker.tile()) else {
+        return;
+    };
+
+    inspector.cell = Some(cell);
+
+    // Baked tiles are drawn by their chunk mesh, so only outline live sprites
+    inspector.clear_highlights(&mut commands);
+    for entity in entities.iter().filter(|entity| {
+        entity.sprite.is_some()
+            && !entity.baked
+            && map.world_to_grid(entity.transform.translation.truncate()) == cell
+    }) {
+        commands
+            .entity(entity.entity)
+            .insert(Highlighted::new(INSPECT_COLOR).with_thickness(INSPECT_THICKNESS));
+        inspector.highlighted.push(entity.entity);
+    }
+    let description = describe_cell(map, cell, &entities);
+    for mut text in texts.iter_mut() {
+        **text = description.clone();
+    }
+}
+
+fn describe_cell(map: &CollisionMap, cell: IVec2, entities: &Query<InspectedEntity>) -> String {
+    let mut out = format!("Tile ({}, {})", cell.x, cell.y);
+    let Some(tile) = map.get_tile(cell.x, cell.y) else {
+        out.push_str("\nOutside the map");
+        return out;
+    };
+    let _ = write!(
+        out,
+        "  index {}\nCollision: {:?}, {}",
+        cell.y * map.width() + cell.x,
+        tile,
+        if map.is_walkable(cell.x, cell.y) { "walkable" } else { "blocked" }
+    );
+
+    let mut on_cell: Vec<_> = entities
+        .iter()
+        .filter(|entity| map.world_to_grid(entity.transform.translation.truncate()) == cell)
+        .collect();
+    on_cell.sort_by(|a, b| a.transform.translation.z.total_cmp(&b.transform.translation.z));
+
+    for entity in on_cell {
+        let sprite = match entity.sprite.and_then(|sprite| sprite.texture_atlas.as_ref()) {
+            Some(atlas) => TILEMAP.sprite_name(atlas.index).unwrap_or("?"),
+            None => "(image)",
+        };
+        let _ = write!(out, "\n\nz {:.3}  {}  id {:#x}", entity.transform.translation.z, sprite, entity.id.0);
+        if let Some(model) = entity.model {
+            let _ = write!(out, "\n  model {} rotation {}", model.model_index, model.rotation);
+        }
+        if let Some(marker) = entity.marker {
+            let _ = write!(out, "\n  tile type {:?}", marker.tile_type);
+        }
+        if let Some(pickable) = entity.pickable {
+            let _ = write!(out, "\n  pickable {} (radius {:.0})", pickable.kind, pickable.radius);
+        }
+        if let Some(node) = entity.resource_node {
+            let _ = write!(
+                out,
+                "\n  resource node {:?}{}",
+                node.kind,
+                if node.depleted { ", depleted" } else { "" }
+            );
+        }
+        if let Some(placed) = entity.placed {
+            let _ = write!(out, "\n  placed {:?} on {:?}", placed.kind, placed.cell);
+        }
+        match (entity.baked, entity.y_sorted) {
+            (true, _) => out.push_str("\n  baked into chunk mesh"),
+            (false, true) => out.push_str("\n  y-sorted"),
+            (false, false) => {}
+        }
+    }
+    out
+}
+
+pub fn outline_inspected_tile(
+    inspector: Res<TileInspector>,
+    map: Option<Res<CollisionMap>>,
+    mut gizmos: Gizmos,
+) {
+    let (Some(cell), Some(map)) = (inspector.cell, map) else {
+        return;
+    };
+    if map.in_bounds(cell.x, cell.y) {
+        gizmos.rect_2d(
+            map.grid_to_world(cell.x, cell.y),
+            Vec2::splat(map.tile_size()),
+            Color::srgb(0.2, 0.9, 1.0),
+        );
+    }
+}
@@ -0,0 +1,40 @@
+// src/debug/time_scale.rs
+use bevy::prelude::*;
+
+use crate::clock::GameClock;
+use crate::state::GameState;
+use crate::ui::ShowToast;
+
+/// Speeds up or slows down game time, in debug builds only.
+///
+/// `]` doubles the `GameClock` scale, `[` halves it and `\` puts it back to
+/// normal. Crops, regrowth, status effects and the day/night cycle all
+/// follow; movement and UI run at real speed.
+pub struct TimeScalePlugin;
+
+impl Plugin for TimeScalePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, adjust_time_scale.run_if(in_state(GameState::Playing)));
+    }
+}
+
+pub fn adjust_time_scale(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut clock: ResMut<GameClock>,
+) {
+    let scale = if keys.just_pressed(KeyCode::BracketRight) {
+        clock.scale() * 2.0
+    } else if keys.just_pressed(KeyCode::BracketLeft) {
+        clock.scale() * 0.5
+    } else if keys.just_pressed(KeyCode::Backslash) {
+        1.0
+    } else {
+        return;
+    };
+    clock.set_scale(scale);
+
+    let message = format!("Time scale x{}", clock.scale());
+    info!("{}", message);
+    commands.trigger(ShowToast::new(message));
+}
@@ -1,5 +1,5 @@
 // src/enemy/ai.rs
-use super::components::{AIBehavior, Enemy, EnemyPath};
+use super::components::{AIBehavior, AiState, Enemy, EnemyPath};
 use crate::characters::{
     config::CharacterEntry,
     facing::Facing,
@@ -8,6 +8,8 @@ use crate::characters::{
     state::CharacterState,
 };
 use crate::collision::CollisionMap;
+use crate::combat::Health;
+use crate::rendering::CullBand;
 use bevy::prelude::*;
 
 /// AI system that makes enemies follow the player using A* pathfinding
@@ -23,6 +25,8 @@ pub fn enemy_follow_player(
             &CharacterEntry,
             &AIBehavior,
             &mut EnemyPath,
+            &mut AiState,
+            &CullBand,
         ),
         With<Enemy>,
     >,
@@ -39,15 +43,26 @@ pub fn enemy_follow_player(
     let player_pos = player_transform.translation.truncate();
     let delta = time.delta_secs();
 
-    for (enemy_transform, mut state, mut velocity, mut facing, character, ai, mut path) in
+    for (enemy_transform, mut state, mut velocity, mut facing, character, ai, mut path, mut ai_state, band) in
         enemy_query.iter_mut()
     {
+        // Far from the camera: stand still and skip pathfinding entirely
+        if band.is_asleep() {
+            ai_state.set_if_neq(AiState::Asleep);
+            if *state != CharacterState::Idle {
+                *state = CharacterState::Idle;
+            }
+            *velocity = Velocity::ZERO;
+            continue;
+        }
+
         let enemy_pos = enemy_transform.translation.truncate();
         let to_player = player_pos - enemy_pos;
         let distance = to_player.length();
 
         // Outside detection range - go idle
         if distance > ai.detection_range {
+            ai_state.set_if_neq(AiState::Idle);
             if *state != CharacterState::Idle {
                 *state = CharacterState::Idle;
             }
@@ -65,6 +80,7 @@ pub fn enemy_follow_player(
         };
         
         if distance <= attack_threshold {
+            ai_state.set_if_neq(AiState::Attacking);
             if *state != CharacterState::Idle {
                 *state = CharacterState::Idle;
             }
@@ -101,6 +117,7 @@ pub fn enemy_follow_player(
 
         // Follow current waypoint
         if let Some(waypoint) = path.current_waypoint() {
+            ai_state.set_if_neq(AiState::Chasing);
             let to_waypoint = waypoint - enemy_pos;
             let waypoint_distance = to_waypoint.length();
             
@@ -132,6 +149,7 @@ pub fn enemy_follow_player(
             }
         } else {
             // No path available - fallback to direct movement
+            ai_state.set_if_neq(AiState::Direct);
             let direction = to_player.normalize_or_zero();
             
             if *state != CharacterState::Walking {
@@ -148,4 +166,25 @@ pub fn enemy_follow_player(
             *velocity = calculate_velocity(*state, direction, character);
         }
     }
-}
\ No newline at end of file
+}
+
+/// True while any living enemy has the player within its detection range.
+#[derive(Resource, Default, PartialEq, Eq)]
+pub struct PlayerInCombat(pub bool);
+
+pub fn update_player_in_combat(
+    player_query: Query<&Transform, With<Player>>,
+    enemies: Query<(&Transform, &AIBehavior, &Health), With<Enemy>>,
+    mut in_combat: ResMut<PlayerInCombat>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    let engaged = enemies.iter().any(|(transform, behavior, health)| {
+        health.is_alive()
+            && transform.translation.truncate().distance(player_pos) <= behavior.detection_range
+    });
+    in_combat.set_if_neq(PlayerInCombat(engaged));
+}
@@ -1,7 +1,8 @@
 // src/enemy/combat.rs
 use super::components::{AIBehavior, Enemy, EnemyCombat};
 use crate::characters::input::Player;
-use crate::combat::systems::{spawn_projectile, ProjectileOwner};
+use crate::combat::spawn_projectile;
+use crate::projectile::ProjectileOwner;
 use bevy::prelude::*;
 
 /// System that handles enemy attacks
@@ -33,6 +33,7 @@ impl EnemyCombat {
 
 /// AI behavior state for enemies
 #[derive(Component)]
+#[require(AiState)]
 pub struct AIBehavior {
     pub attack_range: f32,
     pub detection_range: f32,
@@ -56,6 +57,34 @@ impl AIBehavior {
     }
 }
 
+/// What the enemy AI decided to do on its last update.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AiState {
+    /// Far from the camera, so the AI isn't running
+    #[default]
+    Asleep,
+    /// Player is outside detection range
+    Idle,
+    /// Standing still within attack range
+    Attacking,
+    /// Following an A* path toward the player
+    Chasing,
+    /// Walking straight at the player because no path was found
+    Direct,
+}
+
+impl AiState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AiState::Asleep => "asleep",
+            AiState::Idle => "idle",
+            AiState::Attacking => "attacking",
+            AiState::Chasing => "chasing",
+            AiState::Direct => "direct",
+        }
+    }
+}
+
 #[derive(Component, Default)]
 pub struct EnemyPath {
     /// Waypoints in world coordinates
@@ -4,12 +4,14 @@ pub mod combat;
 pub mod components;
 pub mod spawn;
 
+use crate::characters::input::handle_player_input;
 use crate::collision::CollisionMapBuilt;
 use crate::state::GameState;
 use bevy::prelude::*;
 use spawn::EnemiesSpawned;
 
-pub use components::{AIBehavior, Enemy, EnemyCombat};
+pub use components::{AIBehavior, AiState, Enemy, EnemyCombat, EnemyPath};
+pub use ai::PlayerInCombat;
 pub use spawn::spawn_enemy;
 
 pub struct EnemyPlugin;
@@ -18,6 +20,7 @@ impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<EnemiesSpawned>()
+            .init_resource::<PlayerInCombat>()
             // Spawn enemies AFTER collision map is ready (prevents spawning on obstacles)
             .add_systems(
                 Update,
@@ -26,11 +29,12 @@ impl Plugin for EnemyPlugin {
                     .run_if(resource_equals(EnemiesSpawned(false)))
                     .run_if(in_state(GameState::Playing)),
             )
-            // Enemy AI and combat systems
+            // Enemy AI and combat systems, at the fixed rate movement runs at
             .add_systems(
-                Update,
-                (ai::enemy_follow_player, combat::enemy_attack)
+                FixedUpdate,
+                (ai::enemy_follow_player, combat::enemy_attack, ai::update_player_in_combat)
                     .chain()
+                    .before(handle_player_input)
                     .run_if(in_state(GameState::Playing)),
             );
     }
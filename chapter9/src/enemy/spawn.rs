@@ -6,13 +6,15 @@ use crate::characters::{
     config::{CharacterEntry, CharactersList},
     facing::Facing,
     physics::Velocity,
-    spawn::CharactersListResource, // Add this line
+    spawn::{character_feet_offset, CharactersListResource},
     state::CharacterState,
 };
 use crate::collision::CollisionMap;
 use crate::config::enemy::{ENEMY_SCALE, ENEMY_Z_POSITION};
 use bevy::prelude::*;
 use crate::combat::Health;
+use crate::rendering::YSort;
+use crate::settings::Difficulty;
 
 /// Spawn an enemy at the given position
 pub fn spawn_enemy(
@@ -56,7 +58,10 @@ pub fn spawn_enemy(
             CharacterState::default(),
             Velocity::default(),
             Facing::default(),
-            Collider::default(),
+            (
+                Collider::default(),
+                YSort::new(character_feet_offset(character_entry)),
+            ),
             EnemyCombat::default(),
             Health::new(character_entry.max_health), 
             AIBehavior::default(),
@@ -104,9 +109,11 @@ fn get_valid_spawn_position(collision_map: &CollisionMap, desired_pos: Vec2) ->
     desired_pos
 }
 
-/// System to spawn test enemies when collision map is ready
+/// System to spawn test enemies when collision map is ready; the
+/// difficulty decides how many
 pub fn spawn_test_enemies(
     mut commands: Commands,
+    difficulty: Res<Difficulty>,
     asset_server: Res<AssetServer>,
     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     characters_lists: Res<Assets<CharactersList>>,
@@ -130,9 +137,14 @@ pub fn spawn_test_enemies(
     };
 
     // Define desired spawn positions
-    let spawn_positions = [Vec2::new(200.0, 0.0), Vec2::new(-200.0, 100.0)];
-
-    for desired_pos in spawn_positions {
+    let spawn_positions = [
+        Vec2::new(200.0, 0.0),
+        Vec2::new(-200.0, 100.0),
+        Vec2::new(0.0, -260.0),
+        Vec2::new(320.0, 220.0),
+    ];
+
+    for desired_pos in spawn_positions.into_iter().take(difficulty.enemy_budget()) {
         // Validate position against collision map
         let valid_pos = get_valid_spawn_position(&collision_map, desired_pos);
 
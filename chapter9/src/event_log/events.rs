@@ -0,0 +1,21 @@
+// src/event_log/events.rs
+use bevy::prelude::*;
+
+use super::log::LogCategory;
+
+/// Trigger to record a gameplay event, e.g.
+/// `commands.trigger(LogEvent::new(LogCategory::Save, "Game saved"))`.
+#[derive(Event, Debug, Clone)]
+pub struct LogEvent {
+    pub category: LogCategory,
+    pub text: String,
+}
+
+impl LogEvent {
+    pub fn new(category: LogCategory, text: impl Into<String>) -> Self {
+        Self {
+            category,
+            text: text.into(),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// src/event_log/log.rs
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::config::event_log::MAX_LOG_ENTRIES;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogCategory {
+    /// Items picked up, harvested or caught
+    Pickup,
+    /// First visits to a biome
+    Discovery,
+    /// Damage dealt and taken, defeats
+    Combat,
+    /// Buying and selling with traders
+    Trade,
+    /// Saving and loading
+    Save,
+    /// Achievements unlocked
+    Achievement,
+}
+
+impl LogCategory {
+    pub fn label(self) -> &'static str {
+        match self {
+            LogCategory::Pickup => "Item",
+            LogCategory::Discovery => "Discovery",
+            LogCategory::Combat => "Combat",
+            LogCategory::Trade => "Trade",
+            LogCategory::Save => "Save",
+            LogCategory::Achievement => "Achievement",
+        }
+    }
+
+    /// Tint for the category's label in the log screen.
+    pub fn color(self) -> Color {
+        match self {
+            LogCategory::Pickup => Color::srgb(0.55, 0.85, 0.45),
+            LogCategory::Discovery => Color::srgb(0.45, 0.75, 0.95),
+            LogCategory::Combat => Color::srgb(0.95, 0.45, 0.4),
+            LogCategory::Trade => Color::srgb(0.95, 0.8, 0.35),
+            LogCategory::Save => Color::srgb(0.7, 0.7, 0.7),
+            LogCategory::Achievement => Color::srgb(0.85, 0.55, 0.95),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub category: LogCategory,
+    pub text: String,
+    /// In-game clock hours when it happened
+    pub clock_hours: f32,
+}
+
+impl LogEntry {
+    /// In-game time as "HH:MM".
+    pub fn clock_label(&self) -> String {
+        let hours = self.clock_hours.floor() as u32;
+        let minutes = (self.clock_hours.fract() * 60.0).floor() as u32;
+        format!("{:02}:{:02}", hours, minutes)
+    }
+}
+
+/// History of gameplay events for the log screen, oldest first.
+///
+/// Keeps the last `MAX_LOG_ENTRIES`; older entries are dropped.
+#[derive(Resource, Debug, Default)]
+pub struct EventLog {
+    entries: VecDeque<LogEntry>,
+}
+
+impl EventLog {
+    pub fn push(&mut self, entry: LogEntry) {
+        if self.entries.len() >= MAX_LOG_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &LogEntry> {
+        self.entries.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
@@ -0,0 +1,22 @@
+// src/event_log/mod.rs
+mod events;
+mod log;
+mod systems;
+
+use bevy::prelude::*;
+
+pub use events::LogEvent;
+pub use log::{EventLog, LogCategory};
+
+/// Plugin for the history of gameplay events shown on the log screen.
+///
+/// Gameplay code triggers `LogEvent` for anything the player should be able
+/// to look back on (pickups, discoveries, damage, saves, trades).
+pub struct EventLogPlugin;
+
+impl Plugin for EventLogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EventLog>()
+            .add_observer(systems::record_log_event);
+    }
+}
@@ -0,0 +1,22 @@
+// src/event_log/systems.rs
+use bevy::prelude::*;
+
+use super::events::LogEvent;
+use super::log::{EventLog, LogEntry};
+use crate::clock::TimeOfDay;
+
+/// Observer that stamps a `LogEvent` with the in-game time and adds it to the log.
+///
+/// Entries are echoed to the terminal too, so they still show up there.
+pub fn record_log_event(
+    event: On<LogEvent>,
+    clock: Res<TimeOfDay>,
+    mut log: ResMut<EventLog>,
+) {
+    info!("[{}] {}", event.category.label(), event.text);
+    log.push(LogEntry {
+        category: event.category,
+        text: event.text.clone(),
+        clock_hours: clock.hours,
+    });
+}
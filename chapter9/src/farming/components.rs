@@ -0,0 +1,56 @@
+// src/farming/components.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::clock::GameTimer;
+
+/// Growth stages a crop passes through after planting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CropStage {
+    Seedling,
+    Sprout,
+    Mature,
+}
+
+impl CropStage {
+    /// The stage that follows this one (mature crops stay mature)
+    pub fn next(&self) -> Self {
+        match self {
+            CropStage::Seedling => CropStage::Sprout,
+            CropStage::Sprout | CropStage::Mature => CropStage::Mature,
+        }
+    }
+
+    /// Atlas sprite shown for this stage
+    pub fn sprite_name(&self) -> &'static str {
+        match self {
+            CropStage::Seedling => "crop_seedling",
+            CropStage::Sprout => "crop_sprout",
+            CropStage::Mature => "crop_mature",
+        }
+    }
+}
+
+/// A planted crop growing on a tilled soil tile.
+#[derive(Component)]
+pub struct Crop {
+    pub stage: CropStage,
+    /// Time left in the current stage; shorter for crops near water
+    pub growth: GameTimer,
+    /// Collision map cell the crop was planted on
+    pub cell: IVec2,
+}
+
+impl Crop {
+    pub fn new(cell: IVec2, stage_secs: f32) -> Self {
+        Self {
+            stage: CropStage::Seedling,
+            growth: GameTimer::from_seconds(stage_secs, TimerMode::Repeating),
+            cell,
+        }
+    }
+
+    pub fn is_mature(&self) -> bool {
+        self.stage == CropStage::Mature
+    }
+}
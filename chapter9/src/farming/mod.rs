@@ -0,0 +1,41 @@
+// src/farming/mod.rs
+mod components;
+mod systems;
+
+use bevy::prelude::*;
+use crate::controls::{action_just_pressed, InputAction};
+use crate::inventory::{hotbar_selected, HotbarSlot, ItemKind, Tool};
+use crate::state::GameState;
+
+pub use components::{Crop, CropStage};
+// Loading a save and remote players' tilling go through the same code paths
+pub use systems::{spawn_crop, till_tile};
+#[cfg(any(feature = "net", debug_assertions))]
+pub use systems::set_ground_tile;
+
+/// Plugin for the farming loop: till with the hoe, plant seeds, harvest crops.
+pub struct FarmingPlugin;
+
+impl Plugin for FarmingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, systems::grant_starting_seeds)
+            .add_observer(systems::harvest_crop)
+            .add_systems(
+                Update,
+                (
+                    // Use tool acts with whichever farming slot is selected on the hotbar
+                    systems::use_hoe
+                        .run_if(action_just_pressed(InputAction::UseTool))
+                        .run_if(hotbar_selected(HotbarSlot::Tool(Tool::Hoe))),
+                    systems::plant_seeds
+                        .run_if(action_just_pressed(InputAction::UseTool))
+                        .run_if(hotbar_selected(HotbarSlot::Item(ItemKind::Seeds))),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                FixedUpdate,
+                systems::grow_crops.run_if(in_state(GameState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,257 @@
+// src/farming/systems.rs
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use super::components::{Crop, CropStage};
+use crate::characters::facing::Facing;
+use crate::clock::GameClock;
+use crate::characters::input::Player;
+use crate::audio::{PlaySfx, SfxId};
+use crate::controls::{PlayRumble, RumbleId};
+use crate::event_log::{LogCategory, LogEvent};
+use crate::ui::ShowToast;
+use crate::collision::{CollisionMap, TileMarker, TileType};
+use crate::config::farming::{
+    HARVEST_YIELD, STAGE_SECS, STARTING_SEEDS, WATER_GROWTH_MULTIPLIER, WATER_RADIUS,
+};
+use crate::interaction::{facing_cell, Interact, Interactable};
+use crate::inventory::{Inventory, ItemKind, ItemsCollected};
+use crate::map::assets::TilemapHandles;
+use crate::map::generate::ASSETS_SCALE;
+use crate::map::tilemap::TILEMAP;
+use crate::map::TileChanged;
+use crate::rendering::YSort;
+use crate::state::WorldScoped;
+
+/// Gives a new game a handful of seeds to start the farm with.
+pub fn grant_starting_seeds(mut inventory: ResMut<Inventory>) {
+    inventory.add_many(ItemKind::Seeds, STARTING_SEEDS);
+}
+
+/// Tills the tile in front of the player with the hoe.
+pub fn use_hoe(
+    mut commands: Commands,
+    collision_map: Option<ResMut<CollisionMap>>,
+    player_query: Query<(&Transform, &Facing), With<Player>>,
+    mut ground_tiles: Query<(&mut TileMarker, &mut Sprite, &Transform), Without<YSort>>,
+    props: Query<&Transform, (With<TileMarker>, With<YSort>)>,
+) {
+    let Some(mut map) = collision_map else {
+        return;
+    };
+    let Ok((player_transform, facing)) = player_query.single() else {
+        return;
+    };
+
+    let cell = facing_cell(&map, player_transform, facing);
+    let occupied = props
+        .iter()
+        .any(|transform| map.world_to_grid(transform.translation.truncate()) == cell);
+    if occupied {
+        info!("Something is in the way of the hoe");
+        return;
+    }
+    if let Some(from) = till_tile(&mut map, cell, &mut ground_tiles) {
+        commands.trigger(PlaySfx(SfxId::Till));
+        commands.trigger(TileChanged {
+            cell,
+            from,
+            to: TileType::TilledSoil,
+        });
+    }
+}
+
+/// Plants seeds on the tilled tile in front of the player.
+pub fn plant_seeds(
+    mut commands: Commands,
+    mut inventory: ResMut<Inventory>,
+    collision_map: Option<Res<CollisionMap>>,
+    tilemap_handles: Option<Res<TilemapHandles>>,
+    player_query: Query<(&Transform, &Facing), With<Player>>,
+    crops: Query<&Crop>,
+) {
+    let (Some(map), Some(handles)) = (collision_map, tilemap_handles) else {
+        return;
+    };
+    let Ok((player_transform, facing)) = player_query.single() else {
+        return;
+    };
+
+    let cell = facing_cell(&map, player_transform, facing);
+    if map.get_tile(cell.x, cell.y) != Some(TileType::TilledSoil) {
+        info!("Seeds need tilled soil");
+        return;
+    }
+    if crops.iter().any(|crop| crop.cell == cell) {
+        info!("Something is already planted here");
+        return;
+    }
+    if !inventory.remove(ItemKind::Seeds, 1) {
+        info!("No seeds left");
+        return;
+    }
+    spawn_crop(&mut commands, &map, &handles, cell, CropStage::Seedling, 0.0);
+    info!("Planted seeds at {:?}", cell);
+}
+
+/// Turns the ground at `cell` into tilled soil, in both the collision map and the sprite.
+/// Returns the tile type that was replaced, or `None` if nothing was tilled.
+pub fn till_tile(
+    map: &mut CollisionMap,
+    cell: IVec2,
+    ground_tiles: &mut Query<(&mut TileMarker, &mut Sprite, &Transform), Without<YSort>>,
+) -> Option<TileType> {
+    let from = map.get_tile(cell.x, cell.y)?;
+    if !matches!(from, TileType::Grass | TileType::Dirt) {
+        return None;
+    }
+    set_ground_tile(map, cell, TileType::TilledSoil, ground_tiles)?;
+    info!("Tilled soil at {:?}", cell);
+    Some(from)
+}
+
+/// Sprite for a plain ground tile of each type gameplay can turn ground into.
+fn ground_sprite_name(tile_type: TileType) -> Option<&'static str> {
+    match tile_type {
+        TileType::Grass => Some("green_grass"),
+        TileType::Dirt => Some("dirt"),
+        TileType::TilledSoil => Some("tilled_soil"),
+        _ => None,
+    }
+}
+
+/// Repaints the ground at `cell` as `tile_type`, in both the collision map and
+/// the sprite, with no checks. Returns the tile type that was replaced.
+///
+/// Edges and corners aren't kept: untilling a grass border tile gives plain grass.
+pub fn set_ground_tile(
+    map: &mut CollisionMap,
+    cell: IVec2,
+    tile_type: TileType,
+    ground_tiles: &mut Query<(&mut TileMarker, &mut Sprite, &Transform), Without<YSort>>,
+) -> Option<TileType> {
+    let from = map.get_tile(cell.x, cell.y)?;
+    let name = ground_sprite_name(tile_type)?;
+    let Some(atlas_index) = TILEMAP.sprite_index(name) else {
+        warn!("Unknown atlas sprite '{}'", name);
+        return None;
+    };
+
+    // Only the topmost ground layer is visible (and is what the collision map recorded)
+    let topmost = ground_tiles
+        .iter_mut()
+        .filter(|(_, _, transform)| map.world_to_grid(transform.translation.truncate()) == cell)
+        .max_by(|a, b| a.2.translation.z.total_cmp(&b.2.translation.z));
+    let (mut marker, mut sprite, _) = topmost?;
+
+    marker.tile_type = tile_type;
+    if let Some(atlas) = sprite.texture_atlas.as_mut() {
+        atlas.index = atlas_index;
+    }
+    map.set_tile(cell.x, cell.y, tile_type);
+    Some(from)
+}
+
+/// Spawns a crop on `cell` at `stage`, `elapsed` seconds into it. Crops
+/// near water grow through each stage faster.
+pub fn spawn_crop(
+    commands: &mut Commands,
+    map: &CollisionMap,
+    handles: &TilemapHandles,
+    cell: IVec2,
+    stage: CropStage,
+    elapsed: f32,
+) {
+    let Some(atlas_index) = TILEMAP.sprite_index(stage.sprite_name()) else {
+        warn!("Unknown atlas sprite '{}'", stage.sprite_name());
+        return;
+    };
+
+    let stage_secs = if is_near_water(map, cell) {
+        STAGE_SECS / WATER_GROWTH_MULTIPLIER
+    } else {
+        STAGE_SECS
+    };
+    let mut crop = Crop::new(cell, stage_secs);
+    crop.stage = stage;
+    crop.growth.set_elapsed(Duration::from_secs_f32(elapsed.clamp(0.0, stage_secs)));
+
+    let pos = map.grid_to_world(cell.x, cell.y);
+    commands.spawn((
+        handles.sprite(atlas_index),
+        Transform::from_translation(pos.extend(0.0)).with_scale(ASSETS_SCALE),
+        crop,
+        Interactable::default(),
+        YSort::tile(),
+        WorldScoped,
+    ));
+}
+
+fn is_near_water(map: &CollisionMap, cell: IVec2) -> bool {
+    (-WATER_RADIUS..=WATER_RADIUS).any(|dy| {
+        (-WATER_RADIUS..=WATER_RADIUS).any(|dx| {
+            matches!(
+                map.get_tile(cell.x + dx, cell.y + dy),
+                Some(TileType::Water | TileType::Shore)
+            )
+        })
+    })
+}
+
+/// Advances crops to their next growth stage as their timers finish.
+pub fn grow_crops(clock: Res<GameClock>, mut crops: Query<(&mut Crop, &mut Sprite)>) {
+    for (mut crop, mut sprite) in crops.iter_mut() {
+        if crop.is_mature() {
+            continue;
+        }
+
+        crop.growth.tick(&clock);
+        if !crop.growth.just_finished() {
+            continue;
+        }
+
+        crop.stage = crop.stage.next();
+        if let Some(index) = TILEMAP.sprite_index(crop.stage.sprite_name())
+            && let Some(atlas) = sprite.texture_atlas.as_mut()
+        {
+            atlas.index = index;
+        }
+    }
+}
+
+/// Observer that harvests a mature crop when the player interacts with it.
+pub fn harvest_crop(
+    interact: On<Interact>,
+    mut commands: Commands,
+    crops: Query<&Crop>,
+    mut inventory: ResMut<Inventory>,
+) {
+    let Ok(crop) = crops.get(interact.target) else {
+        return;
+    };
+
+    if !crop.is_mature() {
+        info!("This crop isn't ready yet");
+        return;
+    }
+
+    let count = inventory.add_many(ItemKind::Wheat, HARVEST_YIELD);
+    inventory.add(ItemKind::Seeds);
+    commands.trigger(ItemsCollected {
+        kind: ItemKind::Wheat,
+        amount: HARVEST_YIELD,
+    });
+    commands.entity(interact.target).despawn();
+    commands.trigger(PlaySfx(SfxId::Harvest));
+    commands.trigger(PlayRumble(RumbleId::Harvest));
+    commands.trigger(
+        ShowToast::new(format!("Wheat x{}", HARVEST_YIELD))
+            .with_icon(ItemKind::Wheat.icon_sprite()),
+    );
+
+    commands.trigger(LogEvent::new(
+        LogCategory::Pickup,
+        format!("Harvested Wheat x{} (now {})", HARVEST_YIELD, count),
+    ));
+}
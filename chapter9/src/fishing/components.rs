@@ -0,0 +1,29 @@
+// src/fishing/components.rs
+use bevy::prelude::*;
+
+use crate::clock::GameTimer;
+use crate::inventory::ItemKind;
+
+/// Fish that can be caught, with how often each one bites.
+pub const CATCHES: &[(ItemKind, u32)] = &[
+    (ItemKind::Carp, 50),
+    (ItemKind::Trout, 35),
+    (ItemKind::Pike, 15),
+];
+
+/// What the bobber is currently doing.
+#[derive(Debug)]
+pub enum BobberPhase {
+    /// Floating until a fish bites
+    Waiting(GameTimer),
+    /// A fish is on the hook; reel in before the timer runs out
+    Biting(GameTimer),
+}
+
+/// The floating end of a cast fishing line.
+#[derive(Component, Debug)]
+pub struct Bobber {
+    pub phase: BobberPhase,
+    /// Resting position, the bobber dips below it while a fish bites
+    pub anchor: Vec2,
+}
@@ -0,0 +1,31 @@
+// src/fishing/mod.rs
+mod components;
+mod systems;
+
+use bevy::prelude::*;
+use crate::controls::{action_just_pressed, InputAction};
+use crate::inventory::{hotbar_selected, HotbarSlot, Tool};
+use crate::state::GameState;
+
+/// Plugin for fishing from shore tiles with a timing-based catch.
+pub struct FishingPlugin;
+
+impl Plugin for FishingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                systems::cast_or_reel
+                    .run_if(action_just_pressed(InputAction::UseTool))
+                    .run_if(hotbar_selected(HotbarSlot::Tool(Tool::FishingRod))),
+                systems::snap_long_lines,
+            )
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            FixedUpdate,
+            systems::update_bobbers.run_if(in_state(GameState::Playing)),
+        );
+    }
+}
@@ -0,0 +1,150 @@
+// src/fishing/systems.rs
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::components::{Bobber, BobberPhase, CATCHES};
+use crate::audio::{PlaySfx, SfxId};
+use crate::ui::ShowToast;
+use crate::characters::facing::Facing;
+use crate::characters::input::Player;
+use crate::clock::{GameClock, GameTimer};
+use crate::collision::{CollisionMap, TileType};
+use crate::config::fishing::{
+    BITE_DELAY_MAX, BITE_DELAY_MIN, BITE_WINDOW, BOBBER_SIZE, MAX_LINE_LENGTH,
+};
+use crate::config::player::PLAYER_Z_POSITION;
+use crate::event_log::{LogCategory, LogEvent};
+use crate::interaction::facing_cell;
+use crate::inventory::{Inventory, ItemKind, ItemsCollected};
+use crate::map::generate::GameRng;
+use crate::state::WorldScoped;
+
+/// Casts the line, or reels it in if it is already out.
+///
+/// Casting needs the player on a `Shore` tile facing open `Water`.
+/// Reeling in only lands a fish while it is biting.
+pub fn cast_or_reel(
+    mut commands: Commands,
+    collision_map: Option<Res<CollisionMap>>,
+    mut inventory: ResMut<Inventory>,
+    mut rng: ResMut<GameRng>,
+    player_query: Query<(&Transform, &Facing), With<Player>>,
+    bobbers: Query<(Entity, &Bobber)>,
+) {
+    if let Ok((entity, bobber)) = bobbers.single() {
+        commands.entity(entity).despawn();
+        match bobber.phase {
+            BobberPhase::Biting(_) => {
+                let fish = roll_catch(&mut rng.0);
+                let count = inventory.add(fish);
+                commands.trigger(ItemsCollected { kind: fish, amount: 1 });
+                commands.trigger(PlaySfx(SfxId::Pickup));
+                commands.trigger(
+                    ShowToast::new(format!("Caught a {}!", fish)).with_icon(fish.icon_sprite()),
+                );
+                commands.trigger(LogEvent::new(
+                    LogCategory::Pickup,
+                    format!("Caught a {} (now {})", fish, count),
+                ));
+            }
+            BobberPhase::Waiting(_) => info!("Reeled in too early"),
+        }
+        return;
+    }
+
+    let Some(map) = collision_map else {
+        return;
+    };
+    let Ok((player_transform, facing)) = player_query.single() else {
+        return;
+    };
+
+    let standing = map.world_to_grid(player_transform.translation.truncate());
+    if map.get_tile(standing.x, standing.y) != Some(TileType::Shore) {
+        info!("Stand on the shore to fish");
+        return;
+    }
+    let target = facing_cell(&map, player_transform, facing);
+    if map.get_tile(target.x, target.y) != Some(TileType::Water) {
+        info!("Face open water to cast");
+        return;
+    }
+
+    let anchor = map.grid_to_world(target.x, target.y);
+    let delay = rng.0.gen_range(BITE_DELAY_MIN..BITE_DELAY_MAX);
+    commands.spawn((
+        Sprite::from_color(Color::srgb(0.9, 0.2, 0.2), Vec2::splat(BOBBER_SIZE)),
+        Transform::from_translation(anchor.extend(PLAYER_Z_POSITION)),
+        Bobber {
+            phase: BobberPhase::Waiting(GameTimer::from_seconds(delay, TimerMode::Once)),
+            anchor,
+        },
+        WorldScoped,
+    ));
+    commands.trigger(PlaySfx(SfxId::Splash));
+    info!("Cast the line");
+}
+
+fn roll_catch(rng: &mut impl Rng) -> ItemKind {
+    let total: u32 = CATCHES.iter().map(|(_, weight)| weight).sum();
+    let mut roll = rng.gen_range(0..total);
+    for &(fish, weight) in CATCHES {
+        if roll < weight {
+            return fish;
+        }
+        roll -= weight;
+    }
+    CATCHES[0].0
+}
+
+/// Advances bites and lets fish escape when the player is too slow.
+pub fn update_bobbers(
+    mut commands: Commands,
+    clock: Res<GameClock>,
+    mut bobbers: Query<(Entity, &mut Bobber, &mut Transform)>,
+) {
+    for (entity, mut bobber, mut transform) in bobbers.iter_mut() {
+        let anchor = bobber.anchor;
+        match &mut bobber.phase {
+            BobberPhase::Waiting(timer) => {
+                timer.tick(&clock);
+                if timer.just_finished() {
+                    bobber.phase =
+                        BobberPhase::Biting(GameTimer::from_seconds(BITE_WINDOW, TimerMode::Once));
+                    info!("Something is biting!");
+                }
+            }
+            BobberPhase::Biting(timer) => {
+                timer.tick(&clock);
+                if timer.just_finished() {
+                    info!("The fish got away");
+                    commands.entity(entity).despawn();
+                    continue;
+                }
+                // Dip and shake so the bite is visible
+                let t = timer.elapsed_secs() * 40.0;
+                transform.translation.x = anchor.x + t.sin() * 2.0;
+                transform.translation.y = anchor.y - 4.0;
+            }
+        }
+    }
+}
+
+/// Snaps the line when the player walks away from the bobber.
+pub fn snap_long_lines(
+    mut commands: Commands,
+    player_query: Query<&Transform, With<Player>>,
+    bobbers: Query<(Entity, &Bobber)>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    for (entity, bobber) in bobbers.iter() {
+        if player_pos.distance(bobber.anchor) > MAX_LINE_LENGTH {
+            info!("The line snapped");
+            commands.entity(entity).despawn();
+        }
+    }
+}
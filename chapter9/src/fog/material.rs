@@ -0,0 +1,48 @@
+// src/fog/material.rs
+use bevy::{
+    prelude::*,
+    render::render_resource::{AsBindGroup, ShaderType},
+    shader::ShaderRef,
+    sprite_render::{AlphaMode2d, Material2d},
+};
+
+use crate::config::lighting::MAX_LIGHTS;
+
+/// Uniform block read by `circular_fog.wgsl`.
+#[derive(ShaderType, Reflect, Debug, Clone, Copy)]
+pub struct FogParams {
+    pub color: LinearRgba,
+    pub edge_color: LinearRgba,
+    /// World position the clear circle is centred on
+    pub center: Vec2,
+    /// Distance from the centre that stays fully clear
+    pub radius: f32,
+    /// Width of the fade from clear to full fog; 0 cuts straight to fog
+    pub softness: f32,
+    /// Width of the outline along the vision edge; 0 draws none
+    pub edge_width: f32,
+    /// How many entries of `lights` are in use
+    pub light_count: u32,
+    /// Lights the fog clears around; xy: world position, z: radius
+    pub lights: [Vec4; MAX_LIGHTS],
+}
+
+/// Darkens everything outside a circle around the player.
+///
+/// Works in world space, so the fog quad stays put and only `params.center`
+/// has to follow the player.
+#[derive(Asset, Reflect, AsBindGroup, Debug, Clone)]
+pub struct CircularFogMaterial {
+    #[uniform(0)]
+    pub params: FogParams,
+}
+
+impl Material2d for CircularFogMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/circular_fog.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+}
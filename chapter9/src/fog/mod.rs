@@ -0,0 +1,34 @@
+// src/fog/mod.rs
+mod material;
+mod systems;
+
+use bevy::diagnostic::{Diagnostic, RegisterDiagnostic};
+use bevy::{prelude::*, sprite_render::Material2dPlugin};
+
+use crate::lighting::LitAreas;
+use crate::settings::GameSettings;
+use crate::state::GameState;
+
+pub use material::{CircularFogMaterial, FogParams};
+pub use systems::{FogOverlay, VisionRadius, MATERIAL_WRITES};
+
+/// Plugin for the fog of war that limits how far the player can see.
+pub struct FogPlugin;
+
+impl Plugin for FogPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<CircularFogMaterial>::default())
+            .register_diagnostic(Diagnostic::new(MATERIAL_WRITES).with_suffix("%"))
+            .add_systems(
+                Update,
+                (
+                    systems::spawn_fog.run_if(not(any_with_component::<FogOverlay>)),
+                    systems::restyle_fog.run_if(resource_changed::<GameSettings>),
+                    systems::follow_player_and_fog,
+                    systems::clear_fog_around_lights.run_if(resource_changed::<LitAreas>),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
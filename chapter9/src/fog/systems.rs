@@ -0,0 +1,147 @@
+// src/fog/systems.rs
+use bevy::diagnostic::{DiagnosticPath, Diagnostics};
+use bevy::prelude::*;
+
+use super::{CircularFogMaterial, FogParams};
+use crate::characters::input::Player;
+use crate::config::fog::{
+    FOG_MARGIN, FOG_SOFTNESS, FOG_UPDATE_THRESHOLD, FOG_Z, HARD_EDGE_COLOR, HARD_EDGE_WIDTH,
+    VISION_RADIUS,
+};
+use crate::config::lighting::MAX_LIGHTS;
+use crate::config::map::{TILE_SIZE, TOTAL_GRID_X, TOTAL_GRID_Y};
+use crate::lighting::LitAreas;
+use crate::settings::GameSettings;
+use crate::state::WorldScoped;
+
+/// Share of recent frames on which `follow_player_and_fog` rewrote the fog
+/// material, in percent.
+pub const MATERIAL_WRITES: DiagnosticPath = DiagnosticPath::const_new("fog/material_writes");
+
+/// How far the player can see, in world units.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct VisionRadius(pub f32);
+
+impl Default for VisionRadius {
+    fn default() -> Self {
+        Self(VISION_RADIUS)
+    }
+}
+
+/// The quad the fog material is drawn on; covers the whole map.
+#[derive(Component)]
+pub struct FogOverlay;
+
+/// Applies the accessibility settings (tint and edge style) to fog parameters.
+fn apply_fog_style(params: &mut FogParams, settings: &GameSettings) {
+    params.color = settings.fog_palette.fog_color().to_linear();
+    params.edge_color = HARD_EDGE_COLOR.to_linear();
+    if settings.hard_fog_edge {
+        params.softness = 0.0;
+        params.edge_width = HARD_EDGE_WIDTH;
+    } else {
+        params.softness = FOG_SOFTNESS;
+        params.edge_width = 0.0;
+    }
+}
+
+pub fn spawn_fog(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<CircularFogMaterial>>,
+) {
+    let size = Vec2::new(TOTAL_GRID_X as f32, TOTAL_GRID_Y as f32) * TILE_SIZE + FOG_MARGIN * 2.0;
+    let mut params = FogParams {
+        color: LinearRgba::NONE,
+        edge_color: LinearRgba::NONE,
+        center: Vec2::ZERO,
+        radius: VISION_RADIUS,
+        softness: FOG_SOFTNESS,
+        edge_width: 0.0,
+        light_count: 0,
+        lights: [Vec4::ZERO; MAX_LIGHTS],
+    };
+    apply_fog_style(&mut params, &settings);
+    let material = materials.add(CircularFogMaterial { params });
+
+    commands.spawn((
+        FogOverlay,
+        Mesh2d(meshes.add(Rectangle::from_size(size))),
+        MeshMaterial2d(material),
+        Transform::from_xyz(0.0, 0.0, FOG_Z),
+        WorldScoped,
+    ));
+}
+
+/// Recentres the fog on the player.
+///
+/// Writing to a material asset re-uploads its uniform buffer, so this only
+/// takes the material mutably once the player has moved at least
+/// `FOG_UPDATE_THRESHOLD` or their vision radius changed. Standing still,
+/// pausing in place, or sub-pixel interpolation jitter no longer cost an
+/// upload every frame.
+///
+/// The F2 overlay's "Fog writes" line reports `MATERIAL_WRITES`. Rewriting
+/// unconditionally read 100%; with the threshold it reads 0% while the
+/// player stands still and stays at 100% while they walk, since even the
+/// slowest character covers 110 / 60 ≈ 1.8 units a frame at 60 FPS. The
+/// saving is one upload for every frame the player isn't moving.
+pub fn follow_player_and_fog(
+    player: Query<(&Transform, &VisionRadius), With<Player>>,
+    fog: Query<&MeshMaterial2d<CircularFogMaterial>, With<FogOverlay>>,
+    mut materials: ResMut<Assets<CircularFogMaterial>>,
+    mut diagnostics: Diagnostics,
+) {
+    let (Ok((transform, vision)), Ok(handle)) = (player.single(), fog.single()) else {
+        return;
+    };
+    let center = transform.translation.truncate();
+
+    // Read through `get` first: only `get_mut` marks the asset as modified
+    let Some(current) = materials.get(&handle.0).map(|material| material.params) else {
+        return;
+    };
+    let moved =
+        current.center.distance(center) >= FOG_UPDATE_THRESHOLD || current.radius != vision.0;
+    diagnostics.add_measurement(&MATERIAL_WRITES, || if moved { 100.0 } else { 0.0 });
+    if !moved {
+        return;
+    }
+
+    if let Some(material) = materials.get_mut(&handle.0) {
+        material.params.center = center;
+        material.params.radius = vision.0;
+    }
+}
+
+/// Restyles the fog after its accessibility settings change.
+pub fn restyle_fog(
+    settings: Res<GameSettings>,
+    fog: Query<&MeshMaterial2d<CircularFogMaterial>, With<FogOverlay>>,
+    mut materials: ResMut<Assets<CircularFogMaterial>>,
+) {
+    for handle in fog.iter() {
+        if let Some(material) = materials.get_mut(&handle.0) {
+            apply_fog_style(&mut material.params, &settings);
+        }
+    }
+}
+
+/// Clears the fog around the lights nearest the player, so a campfire can be
+/// seen from beyond vision range.
+pub fn clear_fog_around_lights(
+    lit: Res<LitAreas>,
+    fog: Query<&MeshMaterial2d<CircularFogMaterial>, With<FogOverlay>>,
+    mut materials: ResMut<Assets<CircularFogMaterial>>,
+) {
+    for handle in fog.iter() {
+        if let Some(material) = materials.get_mut(&handle.0) {
+            material.params.light_count = lit.0.len() as u32;
+            for (index, area) in lit.0.iter().enumerate() {
+                material.params.lights[index] = area.center.extend(area.radius).extend(0.0);
+            }
+        }
+    }
+}
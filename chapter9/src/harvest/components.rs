@@ -0,0 +1,91 @@
+// src/harvest/components.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::clock::GameTimer;
+use crate::inventory::ItemKind;
+
+/// The different kinds of harvestable resource nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ResourceNodeKind {
+    BerryBush,
+    Reeds,
+}
+
+impl ResourceNodeKind {
+    /// Item added to the inventory when harvested
+    pub fn item(&self) -> ItemKind {
+        match self {
+            ResourceNodeKind::BerryBush => ItemKind::Berry,
+            ResourceNodeKind::Reeds => ItemKind::Reed,
+        }
+    }
+
+    /// How many items a single harvest yields
+    pub fn yield_amount(&self) -> u32 {
+        match self {
+            ResourceNodeKind::BerryBush => 3,
+            ResourceNodeKind::Reeds => 2,
+        }
+    }
+
+    /// Seconds until a depleted node can be harvested again
+    pub fn regrow_secs(&self) -> f32 {
+        match self {
+            ResourceNodeKind::BerryBush => 60.0,
+            ResourceNodeKind::Reeds => 45.0,
+        }
+    }
+
+    /// Atlas sprite shown while the node can be harvested
+    pub fn sprite_name(&self) -> &'static str {
+        match self {
+            ResourceNodeKind::BerryBush => "berry_bush",
+            ResourceNodeKind::Reeds => "reeds",
+        }
+    }
+
+    /// Atlas sprite shown while the node is regrowing
+    pub fn depleted_sprite_name(&self) -> &'static str {
+        match self {
+            ResourceNodeKind::BerryBush => "berry_bush_depleted",
+            ResourceNodeKind::Reeds => "reeds_depleted",
+        }
+    }
+}
+
+/// A world entity that yields items when harvested and regrows over time.
+#[derive(Component)]
+pub struct ResourceNode {
+    pub kind: ResourceNodeKind,
+    pub regrow: GameTimer,
+    pub depleted: bool,
+}
+
+impl ResourceNode {
+    pub fn new(kind: ResourceNodeKind) -> Self {
+        Self {
+            kind,
+            regrow: GameTimer::from_seconds(kind.regrow_secs(), TimerMode::Once),
+            depleted: false,
+        }
+    }
+
+    /// Mark the node as harvested and restart its regrow timer.
+    pub fn deplete(&mut self) {
+        self.depleted = true;
+        self.regrow.reset();
+    }
+
+    /// Seconds left until the node regrows (zero when harvestable).
+    pub fn regrow_remaining(&self) -> f32 {
+        if self.depleted { self.regrow.remaining_secs() } else { 0.0 }
+    }
+
+    /// Put the node back into a depleted state with `remaining` seconds to go.
+    pub fn restore_depleted(&mut self, remaining: f32) {
+        self.deplete();
+        let elapsed = (self.regrow.duration().as_secs_f32() - remaining).max(0.0);
+        self.regrow.set_elapsed(std::time::Duration::from_secs_f32(elapsed));
+    }
+}
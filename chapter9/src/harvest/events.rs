@@ -0,0 +1,10 @@
+// src/harvest/events.rs
+use bevy::prelude::*;
+
+use super::components::ResourceNodeKind;
+
+/// Triggered when the player harvests a resource node.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ResourceHarvested {
+    pub kind: ResourceNodeKind,
+}
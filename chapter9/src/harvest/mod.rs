@@ -0,0 +1,39 @@
+// src/harvest/mod.rs
+pub mod components;
+mod events;
+mod spawn;
+mod systems;
+
+use bevy::prelude::*;
+use crate::collision::CollisionMapBuilt;
+use crate::state::GameState;
+
+pub use components::{ResourceNode, ResourceNodeKind};
+pub use events::ResourceHarvested;
+pub use spawn::ShoreNodesSpawned;
+
+/// Plugin for harvestable resource nodes (berry bushes, reeds).
+pub struct HarvestPlugin;
+
+impl Plugin for HarvestPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShoreNodesSpawned>()
+            .add_observer(systems::harvest_resource_node)
+            // Shore tiles only exist once the collision map has been post-processed
+            .add_systems(
+                Update,
+                spawn::spawn_shore_reeds
+                    .run_if(resource_equals(CollisionMapBuilt(true)))
+                    .run_if(resource_equals(ShoreNodesSpawned(false)))
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                FixedUpdate,
+                systems::regrow_resource_nodes.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                systems::sync_resource_node_sprites.run_if(in_state(GameState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,70 @@
+// src/harvest/spawn.rs
+use bevy::prelude::*;
+
+use super::components::{ResourceNode, ResourceNodeKind};
+use crate::collision::{CollisionMap, TileType};
+use crate::config::harvest::SHORE_REEDS_PERCENT;
+use crate::interaction::Interactable;
+use crate::map::assets::TilemapHandles;
+use crate::map::generate::ASSETS_SCALE;
+use crate::map::world_id::{IdSpace, WorldEntityId};
+use crate::map::tilemap::TILEMAP;
+use crate::rendering::YSort;
+
+/// Resource to track if shore resource nodes have been placed.
+#[derive(Resource, Default, PartialEq, Eq)]
+pub struct ShoreNodesSpawned(pub bool);
+
+/// Cheap integer hash so placement is stable for a given map layout.
+fn cell_hash(x: i32, y: i32) -> u32 {
+    let mut h = (x as u32).wrapping_mul(0x9E37_79B1) ^ (y as u32).wrapping_mul(0x85EB_CA77);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2C1B_3C6D);
+    h ^ (h >> 12)
+}
+
+/// Places reeds on a fraction of the shore tiles.
+///
+/// Shore is derived from water edges when the collision map is built,
+/// so this pass runs after the WFC generator rather than inside its rules.
+pub fn spawn_shore_reeds(
+    mut commands: Commands,
+    collision_map: Option<Res<CollisionMap>>,
+    tilemap_handles: Option<Res<TilemapHandles>>,
+    mut spawned: ResMut<ShoreNodesSpawned>,
+) {
+    let (Some(map), Some(handles)) = (collision_map, tilemap_handles) else {
+        return;
+    };
+
+    let kind = ResourceNodeKind::Reeds;
+    let Some(atlas_index) = TILEMAP.sprite_index(kind.sprite_name()) else {
+        panic!("Unknown atlas sprite '{}'", kind.sprite_name());
+    };
+
+    let mut count = 0;
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            if map.get_tile(x, y) != Some(TileType::Shore) {
+                continue;
+            }
+            if cell_hash(x, y) % 100 >= SHORE_REEDS_PERCENT {
+                continue;
+            }
+
+            let pos = map.grid_to_world(x, y);
+            commands.spawn((
+                WorldEntityId::tile(IdSpace::Shore, x, y),
+                handles.sprite(atlas_index),
+                Transform::from_translation(pos.extend(0.0)).with_scale(ASSETS_SCALE),
+                ResourceNode::new(kind),
+                Interactable::default(),
+                YSort::tile(),
+            ));
+            count += 1;
+        }
+    }
+
+    spawned.0 = true;
+    info!("Placed {} reed nodes on shore tiles", count);
+}
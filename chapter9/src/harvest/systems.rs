@@ -0,0 +1,97 @@
+// src/harvest/systems.rs
+use bevy::prelude::*;
+
+use super::components::ResourceNode;
+use super::events::ResourceHarvested;
+use crate::audio::{PlaySfx, SfxId};
+use crate::clock::GameClock;
+use crate::controls::{PlayRumble, RumbleId};
+use crate::event_log::{LogCategory, LogEvent};
+use crate::ui::ShowToast;
+use crate::interaction::Interact;
+use crate::inventory::{Inventory, ItemsCollected};
+use crate::map::tilemap::TILEMAP;
+
+/// Observer that harvests a resource node when the player interacts with it.
+pub fn harvest_resource_node(
+    interact: On<Interact>,
+    mut commands: Commands,
+    mut nodes: Query<&mut ResourceNode>,
+    mut inventory: ResMut<Inventory>,
+) {
+    let Ok(mut node) = nodes.get_mut(interact.target) else {
+        return;
+    };
+
+    if node.depleted {
+        info!("Nothing left to harvest here yet");
+        return;
+    }
+
+    let item = node.kind.item();
+    let amount = node.kind.yield_amount();
+    let count = inventory.add_many(item, amount);
+
+    node.deplete();
+    commands.trigger(ResourceHarvested { kind: node.kind });
+    commands.trigger(ItemsCollected { kind: item, amount });
+    commands.trigger(PlaySfx(SfxId::Harvest));
+    commands.trigger(PlayRumble(RumbleId::Harvest));
+    commands.trigger(ShowToast::new(format!("{} x{}", item, amount)).with_icon(item.icon_sprite()));
+
+    commands.trigger(LogEvent::new(
+        LogCategory::Pickup,
+        format!("Harvested {} x{} (now {})", item, amount, count),
+    ));
+}
+
+/// Ticks depleted nodes and restores them once their regrow timer finishes.
+pub fn regrow_resource_nodes(
+    clock: Res<GameClock>,
+    mut nodes: Query<&mut ResourceNode>,
+) {
+    for mut node in nodes.iter_mut() {
+        if !node.depleted {
+            continue;
+        }
+
+        node.regrow.tick(&clock);
+        if node.regrow.just_finished() {
+            node.depleted = false;
+        }
+    }
+}
+
+/// Keeps each node's sprite in step with its depleted flag.
+///
+/// Anything that changes a node (harvesting, regrowing, loading a save)
+/// only touches `ResourceNode` and lets this system pick the sprite.
+pub fn sync_resource_node_sprites(
+    mut nodes: Query<(&ResourceNode, &mut Sprite), Changed<ResourceNode>>,
+) {
+    for (node, mut sprite) in nodes.iter_mut() {
+        let name = if node.depleted {
+            node.kind.depleted_sprite_name()
+        } else {
+            node.kind.sprite_name()
+        };
+        set_atlas_sprite(&mut sprite, name);
+    }
+}
+
+/// Swap a tilemap sprite to another named cell of the same atlas.
+///
+/// Leaves the sprite untouched when it already shows that cell, since
+/// regrowing nodes change every frame.
+fn set_atlas_sprite(sprite: &mut Mut<Sprite>, sprite_name: &str) {
+    let Some(index) = TILEMAP.sprite_index(sprite_name) else {
+        warn!("Unknown atlas sprite '{}'", sprite_name);
+        return;
+    };
+    let current = sprite.texture_atlas.as_ref().map(|atlas| atlas.index);
+    if current.is_some_and(|current| current != index)
+        && let Some(atlas) = sprite.texture_atlas.as_mut()
+    {
+        atlas.index = index;
+    }
+}
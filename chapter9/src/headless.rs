@@ -0,0 +1,216 @@
+// src/headless.rs
+//! The game without a window or renderer, for tests and CI.
+//!
+//! `headless_app` builds an `App` with generation, collision, movement and
+//! inventory but no windowing, rendering, audio or UI. Time advances by one
+//! fixed tick per `App::update`, so the same seed and inputs always give the
+//! same world:
+//!
+//! ```no_run
+//! let mut app = chapter9::headless::headless_app(42);
+//! assert!(chapter9::headless::run_until_ready(&mut app, 10_000));
+//! chapter9::headless::step(&mut app, 120);
+//! ```
+//!
+//! `tests/headless.rs` builds on these for determinism and reachability
+//! checks.
+//!
+//! Started with `--headless [ticks] [--seed <seed>]`, the binary runs the
+//! simulation and prints a summary instead of opening a window. Adding
+//! `--replay <path>` plays a recording back instead, in its own world.
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::time::Duration;
+
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::camera::CameraMode;
+use crate::characters::input::Player;
+use crate::collision::CollisionMap;
+use crate::config::headless::DEFAULT_TICKS;
+use crate::config::physics::FIXED_TICK_HZ;
+use crate::controls::{ControlBindings, InputBufferPlugin};
+use crate::inventory::Inventory;
+use crate::map::generate::MapSeed;
+use crate::replay::{ReplayPlayback, ReplayPlugin};
+use crate::save::PendingLoad;
+use crate::settings::Difficulty;
+use crate::state::{check_assets_loaded, GameState};
+use crate::{characters, collision, interaction, inventory, map, mods};
+
+/// Actions `press` and `release` take.
+pub use crate::controls::InputAction;
+/// Ticks `run_until_ready` is given by `--headless`.
+pub use crate::config::headless::READY_TIMEOUT_TICKS;
+
+/// Builds a windowless app that starts generating the map for `seed` on its
+/// first update.
+pub fn headless_app(seed: u64) -> App {
+    let mut app = App::new();
+    app.add_plugins(mods::ModsPlugin)
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin {
+                file_path: "src/assets".into(),
+                ..default()
+            },
+            ImagePlugin::default_nearest(),
+            TransformPlugin,
+            InputPlugin,
+            StatesPlugin,
+            bevy::gizmos::GizmoPlugin,
+        ))
+        // Every update is exactly one fixed tick
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            1.0 / FIXED_TICK_HZ,
+        )))
+        // Defaults rather than the player's saved key map
+        .init_resource::<ControlBindings>()
+        .init_resource::<Difficulty>()
+        .add_plugins(InputBufferPlugin)
+        .init_resource::<CameraMode>()
+        .insert_state(GameState::Loading)
+        .add_plugins((
+            map::MapPlugin,
+            inventory::InventoryPlugin,
+            interaction::InteractionPlugin,
+            collision::CollisionPlugin,
+            characters::CharactersPlugin,
+        ))
+        .insert_resource(MapSeed(seed))
+        .add_systems(Update, check_assets_loaded.run_if(in_state(GameState::Loading)));
+    app
+}
+
+/// Advances the simulation by `ticks` fixed ticks.
+pub fn step(app: &mut App, ticks: u32) {
+    for _ in 0..ticks {
+        app.update();
+    }
+}
+
+/// Steps until the map is generated, the collision map is built and the
+/// player has spawned. Returns false if that takes more than `max_ticks`.
+pub fn run_until_ready(app: &mut App, max_ticks: u32) -> bool {
+    for _ in 0..max_ticks {
+        app.update();
+        if player_position(app).is_some() {
+            return true;
+        }
+    }
+    false
+}
+
+/// Holds the keys bound to `action` until `release` is called, as if the
+/// player kept them pressed.
+pub fn press(app: &mut App, action: InputAction) {
+    let keys = app.world().resource::<ControlBindings>().keys(action).to_vec();
+    let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    for key in keys {
+        input.press(key);
+    }
+}
+
+/// Lets go of the keys bound to `action`.
+pub fn release(app: &mut App, action: InputAction) {
+    let keys = app.world().resource::<ControlBindings>().keys(action).to_vec();
+    let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    for key in keys {
+        input.release(key);
+    }
+}
+
+/// Where the player stands, once spawned.
+pub fn player_position(app: &mut App) -> Option<Vec2> {
+    app.world_mut()
+        .query_filtered::<&Transform, With<Player>>()
+        .single(app.world())
+        .ok()
+        .map(|transform| transform.translation.truncate())
+}
+
+/// The collision map, once built.
+pub fn collision_map(app: &App) -> Option<&CollisionMap> {
+    app.world().get_resource::<CollisionMap>()
+}
+
+/// The player's inventory.
+pub fn inventory(app: &App) -> &Inventory {
+    app.world().resource::<Inventory>()
+}
+
+/// A hash of every tile in the collision map, equal for equal layouts.
+///
+/// Comparing checksums of two runs with the same seed is a quick
+/// determinism check for generation.
+pub fn collision_checksum(map: &CollisionMap) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    (map.width(), map.height()).hash(&mut hasher);
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            map.get_tile(x, y).hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+/// Options read from `--headless [ticks] [--seed <seed>]`.
+pub struct HeadlessArgs {
+    pub ticks: u32,
+    pub seed: Option<u64>,
+}
+
+impl HeadlessArgs {
+    /// Reads `--headless [ticks]` and `--seed <seed>` from the command line,
+    /// or `None` without `--headless`.
+    pub fn from_args() -> Option<Self> {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        let position = args.iter().position(|arg| arg == "--headless")?;
+        let ticks = args
+            .get(position + 1)
+            .and_then(|next| next.parse().ok())
+            .unwrap_or(DEFAULT_TICKS);
+        let seed = args
+            .iter()
+            .position(|arg| arg == "--seed")
+            .and_then(|index| args.get(index + 1))
+            .and_then(|value| value.parse().ok());
+        Some(Self { ticks, seed })
+    }
+}
+
+/// Generates a world, simulates `args.ticks` ticks with no input and prints
+/// the seed, collision checksum and player position.
+///
+/// With `--replay <path>`, uses the recording's seed and difficulty instead
+/// and simulates exactly its ticks with its input. Recordings that start
+/// from a save are refused, since the headless app has no save loading.
+pub fn run_headless(args: HeadlessArgs) {
+    let seed = args.seed.unwrap_or_else(|| MapSeed::default().0);
+    let mut app = headless_app(seed);
+    app.add_plugins(ReplayPlugin);
+    let seed = app.world().resource::<MapSeed>().0;
+    if app.world().contains_resource::<PendingLoad>() {
+        eprintln!("This replay starts from a saved game; play it back without --headless");
+        std::process::exit(1);
+    }
+
+    if !run_until_ready(&mut app, READY_TIMEOUT_TICKS) {
+        eprintln!("World for seed {} was not ready after {} ticks", seed, READY_TIMEOUT_TICKS);
+        std::process::exit(1);
+    }
+    let ticks = app
+        .world()
+        .get_resource::<ReplayPlayback>()
+        .map_or(args.ticks, |playback| playback.replay.ticks());
+    step(&mut app, ticks);
+
+    let checksum = collision_map(&app).map(collision_checksum).unwrap_or_default();
+    let position = player_position(&mut app).unwrap_or_default();
+    println!("seed: {}", seed);
+    println!("collision checksum: {:016x}", checksum);
+    println!("player after {} ticks: ({:.1}, {:.1})", ticks, position.x, position.y);
+    println!("inventory: {}", inventory(&app).summary());
+}
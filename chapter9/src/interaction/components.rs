@@ -0,0 +1,24 @@
+// src/interaction/components.rs
+use bevy::prelude::*;
+
+use crate::config::interaction::DEFAULT_RADIUS;
+
+/// Marks an entity the player can interact with (harvest, talk, open...).
+#[derive(Component, Debug)]
+pub struct Interactable {
+    /// How close the player must be to interact (in world units)
+    pub radius: f32,
+}
+
+impl Default for Interactable {
+    fn default() -> Self {
+        Self {
+            radius: DEFAULT_RADIUS,
+        }
+    }
+}
+
+/// What the player would interact with right now: the best `Interactable` or
+/// `Pickable` in reach and in front of them.
+#[derive(Resource, Debug, Default, PartialEq)]
+pub struct InteractionTarget(pub Option<Entity>);
@@ -0,0 +1,8 @@
+// src/interaction/events.rs
+use bevy::prelude::*;
+
+/// Event triggered when the player interacts with an `Interactable`.
+#[derive(Event)]
+pub struct Interact {
+    pub target: Entity,
+}
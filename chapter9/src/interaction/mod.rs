@@ -0,0 +1,42 @@
+// src/interaction/mod.rs
+mod components;
+mod events;
+mod systems;
+mod targeting;
+
+use bevy::prelude::*;
+use crate::characters::physics::apply_velocity;
+use crate::state::GameState;
+
+pub use components::{Interactable, InteractionTarget};
+pub use events::Interact;
+pub use targeting::{facing_cell, update_interaction_target};
+
+/// Plugin for player-initiated interactions with world entities.
+/// Systems that own an interactable entity observe `Interact` to react.
+///
+/// Only the `InteractionTarget`, the best interactable or pickable in a cone
+/// in front of the player, can be interacted with or picked up; it is
+/// outlined while targeted.
+pub struct InteractionPlugin;
+
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        // Targeting reads where the player stands on the tick, not where
+        // they're drawn between ticks
+        app.init_resource::<InteractionTarget>()
+            .add_systems(
+                FixedUpdate,
+                (update_interaction_target, systems::handle_interact_input)
+                    .chain()
+                    .after(apply_velocity)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                targeting::highlight_interaction_target
+                    .run_if(resource_changed::<InteractionTarget>)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,29 @@
+// src/interaction/systems.rs
+use bevy::prelude::*;
+
+use super::components::{Interactable, InteractionTarget};
+use super::events::Interact;
+use crate::controls::{InputAction, InputBuffer};
+
+/// Triggers `Interact` on the current target when the interact key (E) is
+/// pressed, if it's an interactable.
+///
+/// A press with nothing targeted stays buffered, so it still goes through if
+/// something comes into reach a moment later.
+pub fn handle_interact_input(
+    mut commands: Commands,
+    mut buffer: ResMut<InputBuffer>,
+    target: Res<InteractionTarget>,
+    interactables: Query<(), With<Interactable>>,
+) {
+    if !buffer.is_buffered(InputAction::Interact) {
+        return;
+    }
+
+    let Some(target) = target.0.filter(|entity| interactables.contains(*entity)) else {
+        return;
+    };
+
+    buffer.consume(InputAction::Interact);
+    commands.trigger(Interact { target });
+}
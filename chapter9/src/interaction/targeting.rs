@@ -0,0 +1,101 @@
+// src/interaction/targeting.rs
+use bevy::prelude::*;
+
+use super::components::{Interactable, InteractionTarget};
+use crate::characters::facing::Facing;
+use crate::characters::input::Player;
+use crate::collision::CollisionMap;
+use crate::config::interaction::{CONE_HALF_ANGLE_DEG, OFF_AXIS_WEIGHT, TOUCH_RADIUS};
+use crate::config::map::TILE_SIZE;
+use crate::config::outline::TARGET_COLOR;
+use crate::inventory::Pickable;
+use crate::outline::Highlighted;
+use crate::rendering::CullBand;
+
+/// Collision map cell directly in front of a character.
+pub fn facing_cell(map: &CollisionMap, transform: &Transform, facing: &Facing) -> IVec2 {
+    let target = transform.translation.truncate() + facing.to_vec2() * TILE_SIZE;
+    map.world_to_grid(target)
+}
+
+/// Whether `target` is in the cone in front of a character at `origin`, or
+/// close enough to be underfoot.
+pub fn is_in_front(origin: Vec2, facing: Facing, target: Vec2) -> bool {
+    let offset = target - origin;
+    if offset.length_squared() <= TOUCH_RADIUS * TOUCH_RADIUS {
+        return true;
+    }
+    offset.normalize().dot(facing.to_vec2()) >= CONE_HALF_ANGLE_DEG.to_radians().cos()
+}
+
+/// How good a target at `target` is, lower being better, or `None` if it's
+/// out of `reach` or not in front.
+///
+/// Distance counts as a fraction of reach, plus a penalty for being off to
+/// the side, so the thing straight ahead wins over a closer one at the edge
+/// of the cone.
+fn target_score(origin: Vec2, facing: Facing, target: Vec2, reach: f32) -> Option<f32> {
+    let offset = target - origin;
+    let distance = offset.length();
+    if distance > reach || !is_in_front(origin, facing, target) {
+        return None;
+    }
+    let off_axis = if distance > 0.0 {
+        1.0 - offset.dot(facing.to_vec2()) / distance
+    } else {
+        0.0
+    };
+    Some(distance / reach + off_axis * OFF_AXIS_WEIGHT)
+}
+
+/// Picks the interactable or pickable the player is facing.
+pub fn update_interaction_target(
+    player_query: Query<(&Transform, &Facing), With<Player>>,
+    interactables: Query<(Entity, &GlobalTransform, &Interactable)>,
+    pickables: Query<(Entity, &GlobalTransform, &Pickable, &CullBand)>,
+    mut target: ResMut<InteractionTarget>,
+) {
+    let Ok((player_transform, facing)) = player_query.single() else {
+        target.set_if_neq(InteractionTarget(None));
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    let interactable_candidates = interactables
+        .iter()
+        .map(|(entity, transform, interactable)| (entity, transform, interactable.radius));
+    // The player is always on screen, so culled pickables can't be in reach
+    let pickable_candidates = pickables
+        .iter()
+        .filter(|(_, _, _, band)| band.is_on_screen())
+        .map(|(entity, transform, pickable, _)| (entity, transform, pickable.radius));
+
+    let best = interactable_candidates
+        .chain(pickable_candidates)
+        .filter_map(|(entity, transform, reach)| {
+            let position = transform.translation().truncate();
+            target_score(player_pos, *facing, position, reach).map(|score| (entity, score))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity);
+
+    target.set_if_neq(InteractionTarget(best));
+}
+
+/// Outlines the interaction target, moving the outline when the target changes.
+pub fn highlight_interaction_target(
+    mut commands: Commands,
+    target: Res<InteractionTarget>,
+    mut previous: Local<Option<Entity>>,
+) {
+    if let Some(old) = previous.take()
+        && let Ok(mut entity) = commands.get_entity(old)
+    {
+        entity.try_remove::<Highlighted>();
+    }
+    if let Some(new) = target.0 {
+        // A targeted item may be picked up and despawned this same frame
+        commands.entity(new).try_insert(Highlighted::new(TARGET_COLOR));
+    }
+    *previous = target.0;
+}
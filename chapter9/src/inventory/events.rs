@@ -0,0 +1,19 @@
+// src/inventory/events.rs
+use bevy::prelude::*;
+
+use super::inventory::ItemKind;
+
+/// Triggered when the player collects a `Pickable` from the world.
+#[derive(Event)]
+pub struct ItemPickedUp {
+    pub entity: Entity,
+    pub kind: ItemKind,
+}
+
+/// Triggered when items are gathered some other way than a `Pickable`:
+/// harvesting a node, landing a fish, reaping a crop.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ItemsCollected {
+    pub kind: ItemKind,
+    pub amount: u32,
+}
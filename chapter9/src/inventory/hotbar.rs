@@ -0,0 +1,88 @@
+// src/inventory/hotbar.rs
+use bevy::prelude::*;
+use std::fmt;
+
+use super::inventory::ItemKind;
+use crate::config::hotbar::SLOT_COUNT;
+
+/// Tools that live in the hotbar rather than the inventory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum Tool {
+    Hoe,
+    FishingRod,
+}
+
+impl Tool {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Tool::Hoe => "Hoe",
+            Tool::FishingRod => "Fishing Rod",
+        }
+    }
+}
+
+/// What a single hotbar slot holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum HotbarSlot {
+    #[default]
+    Empty,
+    Tool(Tool),
+    /// Uses items of this kind from the inventory
+    Item(ItemKind),
+}
+
+impl fmt::Display for HotbarSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HotbarSlot::Empty => f.write_str("empty"),
+            HotbarSlot::Tool(tool) => f.write_str(tool.display_name()),
+            HotbarSlot::Item(kind) => write!(f, "{}", kind),
+        }
+    }
+}
+
+/// The player's quick-access slots and which one is selected.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct Hotbar {
+    slots: [HotbarSlot; SLOT_COUNT],
+    selected: usize,
+}
+
+impl Default for Hotbar {
+    fn default() -> Self {
+        let mut slots = [HotbarSlot::Empty; SLOT_COUNT];
+        slots[0] = HotbarSlot::Tool(Tool::Hoe);
+        slots[1] = HotbarSlot::Item(ItemKind::Seeds);
+        slots[2] = HotbarSlot::Tool(Tool::FishingRod);
+        slots[3] = HotbarSlot::Item(ItemKind::Fence);
+        slots[4] = HotbarSlot::Item(ItemKind::Torch);
+        slots[5] = HotbarSlot::Item(ItemKind::Campfire);
+        slots[6] = HotbarSlot::Item(ItemKind::Boat);
+        Self { slots, selected: 0 }
+    }
+}
+
+impl Hotbar {
+    pub fn slots(&self) -> &[HotbarSlot] {
+        &self.slots
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected(&self) -> HotbarSlot {
+        self.slots[self.selected]
+    }
+
+    /// Move the selection one slot to the right, wrapping around.
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % SLOT_COUNT;
+    }
+}
+
+/// Run condition: true while the given slot content is selected.
+pub fn hotbar_selected(slot: HotbarSlot) -> impl Fn(Res<Hotbar>) -> bool + Clone {
+    move |hotbar: Res<Hotbar>| hotbar.selected() == slot
+}
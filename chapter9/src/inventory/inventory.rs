@@ -1,17 +1,35 @@
 // src/inventory/inventory.rs
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 
+use super::registry::PackItemId;
 use crate::config::pickup::DEFAULT_RADIUS;
+use crate::config::trade::SELL_PRICE_PERCENT;
 
 /// Types of items that can be collected.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
 pub enum ItemKind {
     Plant1,
     Plant2,
     Plant3,
     Plant4,
+    Berry,
+    Reed,
+    Seeds,
+    Wheat,
+    Trout,
+    Carp,
+    Pike,
+    /// Currency traders pay and charge
+    Coin,
+    Campfire,
+    Fence,
+    Torch,
+    Boat,
+    /// Defined by a content pack (see `mods`)
+    Pack(PackItemId),
 }
 
 impl ItemKind {
@@ -21,8 +39,71 @@ impl ItemKind {
             ItemKind::Plant2 => "Flower",
             ItemKind::Plant3 => "Mushroom",
             ItemKind::Plant4 => "Fern",
+            ItemKind::Berry => "Berry",
+            ItemKind::Reed => "Reed",
+            ItemKind::Seeds => "Seeds",
+            ItemKind::Wheat => "Wheat",
+            ItemKind::Trout => "Trout",
+            ItemKind::Carp => "Carp",
+            ItemKind::Pike => "Pike",
+            ItemKind::Coin => "Coin",
+            ItemKind::Campfire => "Campfire",
+            ItemKind::Fence => "Fence",
+            ItemKind::Torch => "Torch",
+            ItemKind::Boat => "Boat",
+            ItemKind::Pack(id) => id.name(),
         }
     }
+
+    /// Name of the tilemap atlas sprite used as this item's icon.
+    pub fn icon_sprite(&self) -> &'static str {
+        match self {
+            ItemKind::Plant1 => "plant_1",
+            ItemKind::Plant2 => "plant_2",
+            ItemKind::Plant3 => "plant_3",
+            ItemKind::Plant4 => "plant_4",
+            ItemKind::Berry => "berry_bush",
+            ItemKind::Reed => "reeds",
+            ItemKind::Seeds => "crop_seedling",
+            ItemKind::Wheat => "crop_mature",
+            // Placeholder until fish get their own art
+            ItemKind::Trout | ItemKind::Carp | ItemKind::Pike => "water",
+            // Placeholder until coins get their own art
+            ItemKind::Coin => "rock_1",
+            // Placeholders until placeables get their own art
+            ItemKind::Campfire => "tree_stump_1",
+            ItemKind::Fence => "tree_stump_2",
+            ItemKind::Torch => "tree_stump_3",
+            ItemKind::Boat => "water_side_t",
+            // Placeholder for items of a pack that's no longer installed
+            ItemKind::Pack(id) => id.icon().unwrap_or("rock_1"),
+        }
+    }
+
+    /// Coins a trader charges for one of this item; `None` if it can't be traded.
+    pub fn price(&self) -> Option<u32> {
+        match self {
+            ItemKind::Plant1 | ItemKind::Plant2 | ItemKind::Plant4 => Some(2),
+            ItemKind::Berry | ItemKind::Reed => Some(2),
+            ItemKind::Plant3 | ItemKind::Seeds => Some(3),
+            ItemKind::Wheat => Some(5),
+            ItemKind::Trout => Some(8),
+            ItemKind::Carp => Some(10),
+            ItemKind::Pike => Some(15),
+            ItemKind::Fence => Some(4),
+            ItemKind::Torch => Some(6),
+            ItemKind::Campfire => Some(12),
+            ItemKind::Boat => Some(40),
+            ItemKind::Coin => None,
+            ItemKind::Pack(id) => id.price(),
+        }
+    }
+
+    /// Coins a trader pays for one of this item, a fraction of its price.
+    pub fn sell_price(&self) -> Option<u32> {
+        self.price()
+            .map(|price| (price * SELL_PRICE_PERCENT / 100).max(1))
+    }
 }
 
 impl fmt::Display for ItemKind {
@@ -31,7 +112,8 @@ impl fmt::Display for ItemKind {
     }
 }
 
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
 pub struct Pickable {
     pub kind: ItemKind,
     pub radius: f32,
@@ -46,7 +128,8 @@ impl Pickable {
     }
 }
 
-#[derive(Resource, Default, Debug)]
+#[derive(Resource, Default, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
 pub struct Inventory {
     items: HashMap<ItemKind, u32>,
 }
@@ -54,11 +137,44 @@ pub struct Inventory {
 impl Inventory {
     /// Add an item to the inventory, returns new count.
     pub fn add(&mut self, kind: ItemKind) -> u32 {
+        self.add_many(kind, 1)
+    }
+
+    /// Add several of the same item at once, returns new count.
+    pub fn add_many(&mut self, kind: ItemKind, amount: u32) -> u32 {
         let entry = self.items.entry(kind).or_insert(0);
-        *entry += 1;
+        *entry += amount;
         *entry
     }
 
+    /// Remove several of an item, returns false (and removes nothing) if there aren't enough.
+    pub fn remove(&mut self, kind: ItemKind, amount: u32) -> bool {
+        let Some(entry) = self.items.get_mut(&kind) else {
+            return false;
+        };
+        if *entry < amount {
+            return false;
+        }
+        *entry -= amount;
+        if *entry == 0 {
+            self.items.remove(&kind);
+        }
+        true
+    }
+
+    /// How many of an item the inventory holds.
+    pub fn count(&self, kind: ItemKind) -> u32 {
+        self.items.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Held items sorted by display name.
+    pub fn sorted_items(&self) -> Vec<(ItemKind, u32)> {
+        let mut items: Vec<(ItemKind, u32)> =
+            self.items.iter().map(|(kind, count)| (*kind, *count)).collect();
+        items.sort_by_key(|(kind, _)| kind.display_name());
+        items
+    }
+
     /// Get a summary string of inventory contents.
     pub fn summary(&self) -> String {
         if self.items.is_empty() {
@@ -1,12 +1,20 @@
 use bevy::prelude::*;
 
+use crate::interaction::update_interaction_target;
 use crate::state::GameState;
 
+mod events;
+mod hotbar;
 mod inventory;
+mod registry;
 mod systems;
 
+pub use events::{ItemPickedUp, ItemsCollected};
+pub use hotbar::{hotbar_selected, Hotbar, HotbarSlot, Tool};
 pub use inventory::{ItemKind, Pickable, Inventory};
-use systems::handle_pickups;
+pub use registry::{clear_pack_items, define_pack_item, PackItemId};
+pub use systems::pick_up;
+use systems::{cycle_hotbar, handle_pickups};
 
 /// Plugin for inventory and pickup functionality.
 pub struct InventoryPlugin;
@@ -14,9 +22,13 @@ pub struct InventoryPlugin;
 impl Plugin for InventoryPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Inventory>()
+            .init_resource::<Hotbar>()
             .add_systems(
-                Update,
-                handle_pickups.run_if(in_state(GameState::Playing)),
-            );
+                FixedUpdate,
+                handle_pickups
+                    .after(update_interaction_target)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, cycle_hotbar.run_if(in_state(GameState::Playing)));
     }
 }
\ No newline at end of file
@@ -0,0 +1,124 @@
+// src/inventory/registry.rs
+use std::sync::RwLock;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Items content packs define, indexed by `PackItemId`.
+///
+/// Lives outside the ECS so `ItemKind`'s methods can look pack items up the
+/// same way they answer for built-in ones. Ids are never removed, only their
+/// definitions, so an id stays valid across pack reloads.
+static PACK_ITEMS: RwLock<Vec<PackItem>> = RwLock::new(Vec::new());
+
+struct PackItem {
+    /// What packs and saves call the item
+    key: &'static str,
+    /// The latest definition, kept after a reload drops it so redefining
+    /// the item with the same text doesn't leak its strings again
+    definition: Option<PackItemDefinition>,
+    defined: bool,
+}
+
+#[derive(Clone, Copy)]
+struct PackItemDefinition {
+    name: &'static str,
+    icon: &'static str,
+    price: Option<u32>,
+}
+
+/// An item added by a content pack; written to saves by its key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub struct PackItemId(u32);
+
+impl PackItemId {
+    /// The id for `key`, registering it on first use. Saves holding items of
+    /// a pack that's no longer installed still load, as undefined items.
+    pub fn intern(key: &str) -> Self {
+        let mut items = PACK_ITEMS.write().expect("pack item registry poisoned");
+        if let Some(index) = items.iter().position(|item| item.key == key) {
+            return Self(index as u32);
+        }
+        items.push(PackItem {
+            key: key.to_string().leak(),
+            definition: None,
+            defined: false,
+        });
+        Self(items.len() as u32 - 1)
+    }
+
+    fn definition(self) -> Option<PackItemDefinition> {
+        let items = PACK_ITEMS.read().expect("pack item registry poisoned");
+        let item = &items[self.0 as usize];
+        item.definition.filter(|_| item.defined)
+    }
+
+    pub fn key(self) -> &'static str {
+        PACK_ITEMS.read().expect("pack item registry poisoned")[self.0 as usize].key
+    }
+
+    /// Whether an installed pack defines this item.
+    pub fn is_defined(self) -> bool {
+        self.definition().is_some()
+    }
+
+    /// Display name; the key for undefined items.
+    pub fn name(self) -> &'static str {
+        self.definition()
+            .map_or_else(|| self.key(), |definition| definition.name)
+    }
+
+    /// Atlas sprite of the item's icon, if it is defined.
+    pub fn icon(self) -> Option<&'static str> {
+        self.definition().map(|definition| definition.icon)
+    }
+
+    pub fn price(self) -> Option<u32> {
+        self.definition().and_then(|definition| definition.price)
+    }
+}
+
+/// Defines the pack item `key`, replacing any earlier definition.
+pub fn define_pack_item(key: &str, name: &str, icon: &str, price: Option<u32>) -> PackItemId {
+    let id = PackItemId::intern(key);
+    let mut items = PACK_ITEMS.write().expect("pack item registry poisoned");
+    let item = &mut items[id.0 as usize];
+    // Strings only leak again when a reload actually changed them
+    let leak = |old: Option<&'static str>, new: &str| -> &'static str {
+        match old {
+            Some(old) if old == new => old,
+            _ => new.to_string().leak(),
+        }
+    };
+    item.definition = Some(PackItemDefinition {
+        name: leak(item.definition.map(|definition| definition.name), name),
+        icon: leak(item.definition.map(|definition| definition.icon), icon),
+        price,
+    });
+    item.defined = true;
+    id
+}
+
+/// Forgets every definition, before the packs are scanned again.
+pub fn clear_pack_items() {
+    for item in PACK_ITEMS
+        .write()
+        .expect("pack item registry poisoned")
+        .iter_mut()
+    {
+        item.defined = false;
+    }
+}
+
+impl Serialize for PackItemId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for PackItemId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = String::deserialize(deserializer)?;
+        Ok(Self::intern(&key))
+    }
+}
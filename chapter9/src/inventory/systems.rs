@@ -1,39 +1,56 @@
 use bevy::prelude::*;
 
-use crate::characters::input::Player;
-use super::inventory::{Pickable, Inventory};
+use super::events::ItemPickedUp;
+use super::hotbar::Hotbar;
+use crate::audio::{PlaySfx, SfxId};
+use crate::controls::{ControlBindings, InputAction};
+use crate::event_log::{LogCategory, LogEvent};
+use super::inventory::{ItemKind, Pickable, Inventory};
+use crate::interaction::InteractionTarget;
 
-/// System that checks for and processes item pickups.
+/// System that picks up the interaction target when it's an item.
+///
+/// Targeting already checked it's in reach and in front of the player, so
+/// walking into items collects them but passing one behind doesn't.
 pub fn handle_pickups(
     mut commands: Commands,
     mut inventory: ResMut<Inventory>,
-    player_query: Query<&Transform, With<Player>>,
-    pickables: Query<(Entity, &GlobalTransform, &Pickable)>,
+    target: Res<InteractionTarget>,
+    pickables: Query<&Pickable>,
 ) {
-    let Ok(player_transform) = player_query.single() else {
+    let Some(entity) = target.0 else {
         return;
     };
+    let Ok(pickable) = pickables.get(entity) else {
+        return;
+    };
+    pick_up(&mut commands, &mut inventory, entity, pickable.kind);
+}
 
-    let player_pos = player_transform.translation.truncate();
-    let mut collected = Vec::new();
+/// Moves a `Pickable` from the world into the inventory.
+pub fn pick_up(commands: &mut Commands, inventory: &mut Inventory, entity: Entity, kind: ItemKind) {
+    let count = inventory.add(kind);
+    // Triggered before the despawn so observers can still read the entity
+    commands.trigger(ItemPickedUp { entity, kind });
+    commands.trigger(PlaySfx(SfxId::Pickup));
+    commands.entity(entity).despawn();
+    commands.trigger(LogEvent::new(
+        LogCategory::Pickup,
+        format!("Picked up {} (now {})", kind, count),
+    ));
+    debug!("Inventory: {}", inventory.summary());
+}
 
-    // Check distance to each pickable
-    for (entity, global_transform, pickable) in pickables.iter() {
-        let item_pos = global_transform.translation().truncate();
-        let distance_sq = player_pos.distance_squared(item_pos);
-        
-        if distance_sq <= pickable.radius * pickable.radius {
-            collected.push((entity, pickable.kind));
-        }
+/// Press the cycle key (Q) to select the next hotbar slot.
+pub fn cycle_hotbar(
+    input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<ControlBindings>,
+    mut hotbar: ResMut<Hotbar>,
+) {
+    if !bindings.just_pressed(InputAction::CycleHotbar, &input) {
+        return;
     }
 
-    // Process collected items
-    for (entity, kind) in collected {
-        commands.entity(entity).despawn();
-        let count = inventory.add(kind);
-        info!(
-            " Picked up {} (total: {}) — inventory: {}",
-            kind, count, inventory.summary()
-        );
-    }
-}
\ No newline at end of file
+    hotbar.select_next();
+    info!("Hotbar slot {}: {}", hotbar.selected_index() + 1, hotbar.selected());
+}
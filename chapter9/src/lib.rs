@@ -0,0 +1,132 @@
+mod map;
+mod characters;
+mod state; 
+pub mod collision;
+mod config;
+mod inventory;
+mod camera;
+mod combat;
+mod particles;
+mod enemy;
+mod rendering;
+mod interaction;
+mod harvest;
+mod farming;
+mod fishing;
+mod trade;
+mod placement;
+mod boat;
+mod status;
+mod stats;
+mod projectile;
+mod save;
+mod settings;
+mod clock;
+mod audio;
+mod ui;
+mod controls;
+mod debug;
+mod event_log;
+mod fog;
+mod lighting;
+mod outline;
+mod wind;
+mod pet;
+mod replay;
+pub mod util;
+mod mods;
+pub mod headless;
+#[cfg(feature = "net")]
+mod net;
+
+use bevy::{
+    prelude::*,
+    window::{MonitorSelection, Window, WindowMode, WindowPlugin}, // Line update alert
+};
+
+use bevy_procedural_tilemaps::prelude::*;
+use crate::camera::CameraPlugin;
+
+/// Builds and runs the game; `main.rs` is a thin wrapper so benchmarks
+/// can link against the collision code.
+pub fn run() {
+    if let Some(args) = headless::HeadlessArgs::from_args() {
+        headless::run_headless(args);
+        return;
+    }
+
+    let mut app = App::new();
+    app.insert_resource(ClearColor(Color::BLACK)) // Line update alert
+        .add_plugins(mods::ModsPlugin)
+        .add_plugins(
+            DefaultPlugins
+                .set(AssetPlugin {
+                    file_path: "src/assets".into(),
+                    // `dev` builds reload edited assets and content packs live
+                    watch_for_changes_override: Some(cfg!(feature = "dev")),
+                    ..default()
+                })
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        title: "Bevy Game".into(),
+                        mode: WindowMode::BorderlessFullscreen(MonitorSelection::Current), // Add this line
+                        ..default()
+                    }),
+                    ..default()
+                })
+                .set(ImagePlugin::default_nearest()),
+        )
+        .add_plugins(state::StatePlugin)
+        .add_plugins(map::MapPlugin)
+        .add_plugins(CameraPlugin) // Add this line
+        .add_plugins(inventory::InventoryPlugin)
+        .add_plugins(collision::CollisionPlugin)
+        .add_plugins(characters::CharactersPlugin)
+        .add_plugins(combat::CombatPlugin)
+        .add_plugins(projectile::ProjectilePlugin)
+        .add_plugins(enemy::EnemyPlugin) 
+        .add_plugins(particles::ParticlesPlugin)
+        .add_plugins(rendering::RenderingPlugin)
+        .add_plugins(fog::FogPlugin)
+        .add_plugins(lighting::LightingPlugin)
+        .add_plugins(outline::OutlinePlugin)
+        .add_plugins(wind::WindPlugin)
+        .add_plugins(pet::PetPlugin)
+        .add_plugins(interaction::InteractionPlugin)
+        .add_plugins(harvest::HarvestPlugin)
+        .add_plugins(farming::FarmingPlugin)
+        .add_plugins(fishing::FishingPlugin)
+        .add_plugins(trade::TradePlugin)
+        .add_plugins(placement::PlacementPlugin)
+        .add_plugins(boat::BoatPlugin)
+        .add_plugins(status::StatusPlugin)
+        .add_plugins(save::SavePlugin)
+        .add_plugins(controls::ControlsPlugin)
+        .add_plugins(settings::SettingsPlugin)
+        .add_plugins(clock::ClockPlugin)
+        .add_plugins(event_log::EventLogPlugin)
+        .add_plugins(stats::StatsPlugin)
+        .add_plugins(replay::ReplayPlugin)
+        .add_plugins(audio::GameAudioPlugin)
+        .add_plugins(ui::GameUiPlugin);
+
+    #[cfg(feature = "inspector")]
+    app.add_plugins(debug::InspectorPlugin);
+
+    #[cfg(debug_assertions)]
+    app.add_plugins((
+        debug::TileInspectorPlugin,
+        debug::TileHistoryPlugin,
+        debug::MovementCheatsPlugin,
+        debug::AiOverlayPlugin,
+        debug::TimeScalePlugin,
+    ));
+
+    #[cfg(feature = "dev")]
+    app.add_plugins(mods::PackHotReloadPlugin);
+
+    #[cfg(feature = "net")]
+    app.add_plugins(net::NetPlugin);
+
+    app.run();
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+// src/lighting/components.rs
+use bevy::prelude::*;
+
+use crate::config::lighting::{CAMPFIRE_COLOR, CAMPFIRE_RADIUS, TORCH_COLOR, TORCH_RADIUS};
+use crate::placement::PlaceableKind;
+
+/// A point light. Lighting doesn't depend on surface normals: it is a warm
+/// glow fading out to `radius` around the entity.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct LightSource {
+    /// Distance at which the light has faded out, in world units
+    pub radius: f32,
+    pub color: Color,
+}
+
+impl LightSource {
+    pub fn new(radius: f32, color: Color) -> Self {
+        Self { radius, color }
+    }
+
+    /// The light a placed object gives off, if any.
+    pub fn for_placeable(kind: PlaceableKind) -> Option<Self> {
+        match kind {
+            PlaceableKind::Campfire => Some(Self::new(CAMPFIRE_RADIUS, CAMPFIRE_COLOR)),
+            PlaceableKind::Torch => Some(Self::new(TORCH_RADIUS, TORCH_COLOR)),
+            PlaceableKind::Fence | PlaceableKind::Boat => None,
+        }
+    }
+}
+
+/// One light as drawn this frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LitArea {
+    pub center: Vec2,
+    pub radius: f32,
+    pub color: LinearRgba,
+}
+
+/// The lights nearest the player, at most `MAX_LIGHTS` of them.
+///
+/// Only written when the set of lights changes, so systems can react to
+/// `resource_changed::<LitAreas>`.
+#[derive(Resource, Debug, Default, PartialEq)]
+pub struct LitAreas(pub Vec<LitArea>);
@@ -0,0 +1,54 @@
+// src/lighting/material.rs
+use bevy::{
+    prelude::*,
+    reflect::TypePath,
+    render::render_resource::{AsBindGroup, ShaderType},
+    shader::ShaderRef,
+    sprite_render::{AlphaMode2d, Material2d},
+};
+
+use crate::config::lighting::MAX_LIGHTS;
+
+/// Uniform block read by `lighting.wgsl`.
+#[derive(ShaderType, Debug, Clone, Copy)]
+pub struct LightingParams {
+    pub night_color: LinearRgba,
+    /// 0 by day, 1 at night
+    pub darkness: f32,
+    /// How many entries of `lights` and `colors` are in use
+    pub light_count: u32,
+    /// xy: world position, z: radius, w: brightness
+    pub lights: [Vec4; MAX_LIGHTS],
+    pub colors: [LinearRgba; MAX_LIGHTS],
+}
+
+impl Default for LightingParams {
+    fn default() -> Self {
+        Self {
+            night_color: LinearRgba::NONE,
+            darkness: 0.0,
+            light_count: 0,
+            lights: [Vec4::ZERO; MAX_LIGHTS],
+            colors: [LinearRgba::NONE; MAX_LIGHTS],
+        }
+    }
+}
+
+/// Darkens the world by night and draws the glow of nearby lights.
+///
+/// Like the fog, it works in world space on a quad covering the map.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct LightingMaterial {
+    #[uniform(0)]
+    pub params: LightingParams,
+}
+
+impl Material2d for LightingMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/lighting.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+}
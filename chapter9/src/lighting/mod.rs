@@ -0,0 +1,38 @@
+// src/lighting/mod.rs
+mod components;
+mod material;
+mod systems;
+
+use bevy::{prelude::*, sprite_render::Material2dPlugin};
+
+use crate::state::GameState;
+
+pub use components::{LightSource, LitArea, LitAreas};
+pub use material::{LightingMaterial, LightingParams};
+pub use systems::LightingOverlay;
+
+/// Plugin for point lights that push back the night.
+///
+/// A full-screen overlay darkens the world as the clock heads into night,
+/// except around `LightSource`s such as campfires and torches, which glow
+/// warm instead. The nearest lights are also published in `LitAreas` so the
+/// fog can clear around them.
+pub struct LightingPlugin;
+
+impl Plugin for LightingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<LightingMaterial>::default())
+            .init_resource::<LitAreas>()
+            .add_observer(systems::light_placed_objects)
+            .add_systems(
+                Update,
+                (
+                    systems::spawn_lighting.run_if(not(any_with_component::<LightingOverlay>)),
+                    systems::collect_lights,
+                    systems::update_lighting,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
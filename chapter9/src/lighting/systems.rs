@@ -0,0 +1,116 @@
+// src/lighting/systems.rs
+use bevy::prelude::*;
+
+use super::{LightSource, LightingMaterial, LightingParams, LitArea, LitAreas};
+use crate::characters::input::Player;
+use crate::clock::TimeOfDay;
+use crate::config::fog::FOG_MARGIN;
+use crate::config::lighting::{FLICKER_AMOUNT, FLICKER_SPEED, LIGHTING_Z, MAX_LIGHTS, NIGHT_COLOR};
+use crate::config::map::{TILE_SIZE, TOTAL_GRID_X, TOTAL_GRID_Y};
+use crate::placement::Placed;
+use crate::state::WorldScoped;
+
+/// The quad the lighting material is drawn on; covers the whole map.
+#[derive(Component)]
+pub struct LightingOverlay;
+
+/// Campfires and torches light up as soon as they are put down.
+pub fn light_placed_objects(add: On<Add, Placed>, placed: Query<&Placed>, mut commands: Commands) {
+    let Ok(placed) = placed.get(add.entity) else {
+        return;
+    };
+    if let Some(light) = LightSource::for_placeable(placed.kind) {
+        commands.entity(add.entity).insert(light);
+    }
+}
+
+pub fn spawn_lighting(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<LightingMaterial>>,
+) {
+    let size = Vec2::new(TOTAL_GRID_X as f32, TOTAL_GRID_Y as f32) * TILE_SIZE + FOG_MARGIN * 2.0;
+    let material = materials.add(LightingMaterial {
+        params: LightingParams {
+            night_color: NIGHT_COLOR.to_linear(),
+            ..default()
+        },
+    });
+
+    commands.spawn((
+        LightingOverlay,
+        Mesh2d(meshes.add(Rectangle::from_size(size))),
+        MeshMaterial2d(material),
+        Transform::from_xyz(0.0, 0.0, LIGHTING_Z),
+        WorldScoped,
+    ));
+}
+
+/// Picks the `MAX_LIGHTS` lights nearest the player.
+pub fn collect_lights(
+    player: Query<&Transform, With<Player>>,
+    lights: Query<(&GlobalTransform, &LightSource)>,
+    mut lit: ResMut<LitAreas>,
+) {
+    let Ok(player) = player.single() else {
+        return;
+    };
+    let player_pos = player.translation.truncate();
+
+    let mut areas: Vec<LitArea> = lights
+        .iter()
+        .map(|(transform, light)| LitArea {
+            center: transform.translation().truncate(),
+            radius: light.radius,
+            color: light.color.to_linear(),
+        })
+        .collect();
+    areas.sort_by(|a, b| {
+        let a = a.center.distance_squared(player_pos);
+        let b = b.center.distance_squared(player_pos);
+        a.total_cmp(&b)
+    });
+    areas.truncate(MAX_LIGHTS);
+
+    lit.set_if_neq(LitAreas(areas));
+}
+
+/// Feeds the clock's darkness and the collected lights to the overlay.
+///
+/// In full daylight with no change in darkness the material is left alone,
+/// so daytime costs no uniform uploads.
+pub fn update_lighting(
+    time: Res<Time>,
+    clock: Res<TimeOfDay>,
+    lit: Res<LitAreas>,
+    overlay: Query<&MeshMaterial2d<LightingMaterial>, With<LightingOverlay>>,
+    mut materials: ResMut<Assets<LightingMaterial>>,
+) {
+    let Ok(handle) = overlay.single() else {
+        return;
+    };
+    let darkness = clock.darkness();
+
+    // Read through `get` first: only `get_mut` marks the asset as modified
+    let Some(current) = materials.get(&handle.0).map(|material| material.params.darkness) else {
+        return;
+    };
+    if darkness == 0.0 && current == 0.0 {
+        return;
+    }
+
+    let Some(material) = materials.get_mut(&handle.0) else {
+        return;
+    };
+    let params = &mut material.params;
+    params.darkness = darkness;
+    params.light_count = lit.0.len() as u32;
+    let t = time.elapsed_secs();
+    for (index, area) in lit.0.iter().enumerate() {
+        // Each light flickers out of step with the others
+        let phase = area.center.x * 0.013 + area.center.y * 0.029;
+        let flicker = 1.0 - FLICKER_AMOUNT * (0.5 + 0.5 * (t * FLICKER_SPEED + phase).sin());
+        params.lights[index] = area.center.extend(area.radius).extend(flicker);
+        params.colors[index] = area.color;
+    }
+}
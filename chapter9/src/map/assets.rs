@@ -1,15 +1,29 @@
 use bevy::{prelude::*};
 use bevy_procedural_tilemaps::prelude::*;
-use crate::collision::{TileMarker, TileType};
+use crate::collision::{Footprint, TileMarker, TileType};
 use crate::map::tilemap::TILEMAP; 
+use crate::harvest::{ResourceNode, ResourceNodeKind};
+use crate::interaction::Interactable;
 use crate::inventory::{ItemKind, Pickable};
+use crate::mods::ContentPacks;
+use crate::rendering::{Ground, YSort};
+use crate::wind::Sway;
 
 
 
+/// Where a spawnable asset's sprite comes from.
+#[derive(Clone, Debug)]
+pub enum SpriteSource {
+    /// Name of the sprite inside our tilemap atlas
+    Atlas(&'static str),
+    /// Index into the atlas, for sprites looked up at runtime such as a
+    /// content pack's
+    Index(usize),
+}
+
 #[derive(Clone)]
 pub struct SpawnableAsset {
-    /// Name of the sprite inside our tilemap atlas
-    sprite_name: &'static str,
+    sprite: SpriteSource,
     /// Offset in grid coordinates (for multi-tile objects)
     grid_offset: GridDelta,
     /// Offset in world coordinates (fine positioning)
@@ -18,16 +32,31 @@ pub struct SpawnableAsset {
     tile_type: Option<TileType>,
 
     pickable: Option<ItemKind>, 
+
+    resource_node: Option<ResourceNodeKind>,
+
+    /// Other cells this asset covers, relative to its own (multi-tile props)
+    footprint: Vec<(GridDelta, TileType)>,
+
+    /// Sways in the wind, for tree canopies
+    sway: bool,
 }
 
 impl SpawnableAsset {
     pub fn new(sprite_name: &'static str) -> Self {
+        Self::from_source(SpriteSource::Atlas(sprite_name))
+    }
+
+    pub fn from_source(sprite: SpriteSource) -> Self {
         Self {
-            sprite_name,
+            sprite,
             grid_offset: GridDelta::new(0, 0, 0),
             offset: Vec3::ZERO,
             tile_type: None, // Default: no extra components
             pickable: None, 
+            resource_node: None,
+            footprint: Vec::new(),
+            sway: false,
         }
     }
 
@@ -45,9 +74,50 @@ impl SpawnableAsset {
         self.pickable = Some(kind);
         self
     }
+
+    pub fn with_resource_node(mut self, kind: ResourceNodeKind) -> Self {
+        self.resource_node = Some(kind);
+        self
+    }
+
+    /// Mark more cells than the asset's own in the collision map, each
+    /// with its own tile type, for props wider or taller than a tile.
+    pub fn with_footprint(mut self, cells: Vec<(GridDelta, TileType)>) -> Self {
+        self.footprint = cells;
+        self
+    }
+
+    /// Sway in the wind. Only for bare sprites such as tree tops; assets
+    /// with a tile type, pickable or resource node get their components
+    /// (and sway, if they're plants) from those instead.
+    pub fn with_sway(mut self) -> Self {
+        self.sway = true;
+        self
+    }
+
+    pub fn tile_type(&self) -> Option<TileType> {
+        self.tile_type
+    }
+
+    /// The footprint as a component, if the asset covers more than one cell.
+    pub fn footprint(&self) -> Option<Footprint> {
+        if self.footprint.is_empty() {
+            return None;
+        }
+        Some(Footprint(
+            self.footprint
+                .iter()
+                .map(|(delta, tile_type)| (IVec2::new(delta.dx, delta.dy), *tile_type))
+                .collect(),
+        ))
+    }
+
+    pub fn grid_offset(&self) -> &GridDelta {
+        &self.grid_offset
+    }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Resource)]
 pub struct TilemapHandles {
     pub image: Handle<Image>,
     pub layout: Handle<TextureAtlasLayout>,
@@ -69,8 +139,9 @@ pub fn prepare_tilemap_handles(
     tilemap_file: &str,
 ) -> TilemapHandles {
     let image = asset_server.load::<Image>(format!("{assets_directory}/{tilemap_file}"));
-    let mut layout = TextureAtlasLayout::new_empty(TILEMAP.atlas_size());
-    for index in 0..TILEMAP.sprites.len() {
+    // Content pack sprites are packed in below the built-in ones once loaded
+    let mut layout = TextureAtlasLayout::new_empty(TILEMAP.packed_atlas_size());
+    for index in 0..TILEMAP.sprite_count() {
         layout.add_texture(TILEMAP.sprite_rect(index));
     }
     let layout = atlas_layouts.add(layout);
@@ -86,35 +157,59 @@ pub fn load_assets(
     
     for (model_index, assets) in assets_definitions.into_iter().enumerate() {
         for asset_def in assets {
-            let SpawnableAsset {
-                sprite_name,
-                grid_offset,
-                offset,
-                tile_type,
-                pickable, // Add this line
-            } = asset_def;
-
-            let Some(atlas_index) = TILEMAP.sprite_index(sprite_name) else {
-                panic!("Unknown atlas sprite '{}'", sprite_name);
-            };
-
-            // Create the spawner function that adds components
-            let spawner = create_spawner(tile_type, pickable); // Line update alert
-
             models_assets.add(
                 model_index,
-                ModelAsset {
-                    assets_bundle: tilemap_handles.sprite(atlas_index),
-                    grid_offset,
-                    world_offset: offset,
-                    spawn_commands: spawner,
-                },
+                model_asset(tilemap_handles, asset_def),
             );
         }
     }
     models_assets
 }
 
+/// Resolve a spawnable asset's sprite and components, ready to spawn.
+pub fn model_asset(
+    tilemap_handles: &TilemapHandles,
+    asset_def: SpawnableAsset,
+) -> ModelAsset<Sprite> {
+    let SpawnableAsset {
+        sprite,
+        grid_offset,
+        offset,
+        tile_type,
+        pickable, // Add this line
+        resource_node,
+        footprint: _,
+        sway,
+    } = asset_def;
+
+    let atlas_index = match sprite {
+        SpriteSource::Atlas(name) => {
+            let Some(atlas_index) = TILEMAP.sprite_index(name) else {
+                panic!("Unknown atlas sprite '{}'", name);
+            };
+            atlas_index
+        }
+        SpriteSource::Index(atlas_index) => atlas_index,
+    };
+    let sprite = tilemap_handles.sprite(atlas_index);
+
+    // Create the spawner function that adds components
+    let spawner = match resource_node {
+        Some(kind) => create_resource_node_spawner(kind),
+        None if sway && tile_type.is_none() && pickable.is_none() => |e: &mut EntityCommands| {
+            e.insert(Sway::canopy());
+        },
+        None => create_spawner(tile_type, pickable), // Line update alert
+    };
+
+    ModelAsset {
+        assets_bundle: sprite,
+        grid_offset,
+        world_offset: offset,
+        spawn_commands: spawner,
+    }
+}
+
 fn create_spawner(
     tile_type: Option<TileType>,
     pickable: Option<ItemKind>,
@@ -122,45 +217,112 @@ fn create_spawner(
     match (tile_type, pickable) {
         // Tile types without pickable
         (Some(TileType::Dirt), None) => |e: &mut EntityCommands| {
-            e.insert(TileMarker::new(TileType::Dirt));
+            e.insert((TileMarker::new(TileType::Dirt), Ground));
         },
         (Some(TileType::Grass), None) => |e: &mut EntityCommands| {
-            e.insert(TileMarker::new(TileType::Grass));
+            e.insert((TileMarker::new(TileType::Grass), Ground));
         },
         (Some(TileType::YellowGrass), None) => |e: &mut EntityCommands| {
-            e.insert(TileMarker::new(TileType::YellowGrass));
+            e.insert((TileMarker::new(TileType::YellowGrass), Ground));
         },
         (Some(TileType::Water), None) => |e: &mut EntityCommands| {
-            e.insert(TileMarker::new(TileType::Water));
+            e.insert((TileMarker::new(TileType::Water), Ground));
         },
         (Some(TileType::Shore), None) => |e: &mut EntityCommands| {
-            e.insert(TileMarker::new(TileType::Shore));
+            e.insert((TileMarker::new(TileType::Shore), Ground));
         },
         (Some(TileType::Tree), None) => |e: &mut EntityCommands| {
-            e.insert(TileMarker::new(TileType::Tree));
+            e.insert((TileMarker::new(TileType::Tree), YSort::tile()));
         },
         (Some(TileType::Rock), None) => |e: &mut EntityCommands| {
-            e.insert(TileMarker::new(TileType::Rock));
+            e.insert((TileMarker::new(TileType::Rock), YSort::tile()));
         },
         (Some(TileType::Empty), None) => |e: &mut EntityCommands| {
-            e.insert(TileMarker::new(TileType::Empty));
+            e.insert((TileMarker::new(TileType::Empty), Ground));
         },
 
         // Pickable plants (with grass tile type)
         (Some(TileType::Grass), Some(ItemKind::Plant1)) => |e: &mut EntityCommands| {
-            e.insert((TileMarker::new(TileType::Grass), Pickable::new(ItemKind::Plant1)));
+            e.insert((
+                TileMarker::new(TileType::Grass),
+                Pickable::new(ItemKind::Plant1),
+                YSort::tile(),
+                Sway::plant(),
+            ));
         },
         (Some(TileType::Grass), Some(ItemKind::Plant2)) => |e: &mut EntityCommands| {
-            e.insert((TileMarker::new(TileType::Grass), Pickable::new(ItemKind::Plant2)));
+            e.insert((
+                TileMarker::new(TileType::Grass),
+                Pickable::new(ItemKind::Plant2),
+                YSort::tile(),
+                Sway::plant(),
+            ));
         },
         (Some(TileType::Grass), Some(ItemKind::Plant3)) => |e: &mut EntityCommands| {
-            e.insert((TileMarker::new(TileType::Grass), Pickable::new(ItemKind::Plant3)));
+            e.insert((
+                TileMarker::new(TileType::Grass),
+                Pickable::new(ItemKind::Plant3),
+                YSort::tile(),
+                Sway::plant(),
+            ));
         },
         (Some(TileType::Grass), Some(ItemKind::Plant4)) => |e: &mut EntityCommands| {
-            e.insert((TileMarker::new(TileType::Grass), Pickable::new(ItemKind::Plant4)));
+            e.insert((
+                TileMarker::new(TileType::Grass),
+                Pickable::new(ItemKind::Plant4),
+                YSort::tile(),
+                Sway::plant(),
+            ));
+        },
+
+        // Pack items; which one depends on the prop, read off its sprite
+        (Some(TileType::Grass), Some(ItemKind::Pack(_))) => |e: &mut EntityCommands| {
+            e.insert((TileMarker::new(TileType::Grass), YSort::tile(), Sway::plant()))
+                .queue(attach_pack_pickable);
         },
 
         // Default: no components
         _ => |_: &mut EntityCommands| {},
     }
-}
\ No newline at end of file
+}
+
+/// Makes a pack prop pickable as the item its pack gave it. Spawners are
+/// plain functions, so they can't carry the item themselves.
+fn attach_pack_pickable(mut entity: EntityWorldMut) {
+    let Some(atlas_index) = entity
+        .get::<Sprite>()
+        .and_then(|sprite| sprite.texture_atlas.as_ref())
+        .map(|atlas| atlas.index)
+    else {
+        return;
+    };
+    let kind = entity
+        .world()
+        .get_resource::<ContentPacks>()
+        .and_then(|packs| packs.pickable_with_sprite(atlas_index));
+    if let Some(kind) = kind {
+        entity.insert(Pickable::new(kind));
+    }
+}
+
+fn create_resource_node_spawner(kind: ResourceNodeKind) -> fn(&mut EntityCommands) {
+    match kind {
+        ResourceNodeKind::BerryBush => |e: &mut EntityCommands| {
+            e.insert((
+                TileMarker::new(TileType::Grass),
+                ResourceNode::new(ResourceNodeKind::BerryBush),
+                Interactable::default(),
+                YSort::tile(),
+                Sway::plant(),
+            ));
+        },
+        ResourceNodeKind::Reeds => |e: &mut EntityCommands| {
+            e.insert((
+                ResourceNode::new(ResourceNodeKind::Reeds),
+                Interactable::default(),
+                YSort::tile(),
+                Sway::plant(),
+            ));
+        },
+    }
+}
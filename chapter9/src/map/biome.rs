@@ -0,0 +1,95 @@
+// src/map/biome.rs
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+use crate::characters::input::Player;
+use crate::collision::{CollisionMap, TileType};
+use crate::config::biome::SETTLE_SECS;
+use crate::event_log::{LogCategory, LogEvent};
+
+/// Broad area of the map, derived from the terrain under the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum Biome {
+    #[default]
+    Meadow,
+    Plains,
+    Wilds,
+    Coast,
+}
+
+impl Biome {
+    /// Biome a tile belongs to; `None` for tiles that don't say (e.g. props, void).
+    pub fn from_tile(tile: TileType) -> Option<Self> {
+        match tile {
+            TileType::Grass | TileType::TilledSoil => Some(Biome::Meadow),
+            TileType::YellowGrass => Some(Biome::Plains),
+            TileType::Dirt | TileType::Thorns | TileType::Mud => Some(Biome::Wilds),
+            TileType::Shore | TileType::Water => Some(Biome::Coast),
+            TileType::Empty | TileType::Tree | TileType::Rock | TileType::Fence => None,
+        }
+    }
+}
+
+/// The biome the player is in.
+///
+/// A new biome only takes over after the player has stayed in it for
+/// `SETTLE_SECS`, so walking along a border doesn't flicker.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct PlayerBiome {
+    pub current: Biome,
+    candidate: Biome,
+    settle: Timer,
+}
+
+impl Default for PlayerBiome {
+    fn default() -> Self {
+        Self {
+            current: Biome::default(),
+            candidate: Biome::default(),
+            settle: Timer::from_seconds(SETTLE_SECS, TimerMode::Once),
+        }
+    }
+}
+
+pub fn track_player_biome(
+    mut commands: Commands,
+    time: Res<Time>,
+    collision_map: Option<Res<CollisionMap>>,
+    player_query: Query<&Transform, With<Player>>,
+    mut biome: ResMut<PlayerBiome>,
+    mut discovered: Local<HashSet<Biome>>,
+) {
+    let Some(map) = collision_map else {
+        return;
+    };
+    let Ok(transform) = player_query.single() else {
+        return;
+    };
+
+    let cell = map.world_to_grid(transform.translation.truncate());
+    let Some(here) = map.get_tile(cell.x, cell.y).and_then(Biome::from_tile) else {
+        return;
+    };
+
+    if here == biome.current {
+        biome.candidate = here;
+        return;
+    }
+    if here != biome.candidate {
+        biome.candidate = here;
+        biome.settle.reset();
+    }
+
+    biome.settle.tick(time.delta());
+    if biome.settle.just_finished() {
+        biome.current = here;
+        info!("Entered {:?}", here);
+        if discovered.insert(here) {
+            commands.trigger(LogEvent::new(
+                LogCategory::Discovery,
+                format!("Discovered the {:?}", here),
+            ));
+        }
+    }
+}
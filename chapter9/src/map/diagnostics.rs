@@ -0,0 +1,15 @@
+// src/map/diagnostics.rs
+use bevy::diagnostic::{DiagnosticPath, Diagnostics};
+use bevy::prelude::*;
+
+use crate::collision::TileMarker;
+
+/// Wall time of the last background world generation, in milliseconds.
+pub const GENERATION_TIME: DiagnosticPath = DiagnosticPath::const_new("map/generation_time");
+
+/// Number of live entities carrying a `TileMarker`.
+pub const TILE_ENTITIES: DiagnosticPath = DiagnosticPath::const_new("map/tile_entities");
+
+pub fn measure_tile_entities(mut diagnostics: Diagnostics, tiles: Query<(), With<TileMarker>>) {
+    diagnostics.add_measurement(&TILE_ENTITIES, || tiles.iter().len() as f64);
+}
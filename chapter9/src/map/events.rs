@@ -0,0 +1,21 @@
+// src/map/events.rs
+use bevy::prelude::*;
+
+use super::biome::Biome;
+use crate::collision::TileType;
+
+/// Triggered whenever gameplay changes the terrain of a tile (tilling, digging, placing).
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TileChanged {
+    /// Collision map cell that changed
+    pub cell: IVec2,
+    pub from: TileType,
+    pub to: TileType,
+}
+
+/// Triggered the first time the player walks into a named region.
+#[derive(Event, Debug, Clone)]
+pub struct RegionDiscovered {
+    pub name: String,
+    pub biome: Biome,
+}
@@ -2,36 +2,81 @@
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
+use std::time::Instant;
 
+use bevy::diagnostic::Diagnostics;
 use bevy::prelude::*;
 use bevy::tasks::{block_on, futures_lite::future::poll_once, AsyncComputeTaskPool, Task};
 use bevy_procedural_tilemaps::prelude::*;
 use bevy_procedural_tilemaps::proc_gen::generator::model::ModelInstance;
 use bevy_procedural_tilemaps::proc_gen::generator::rules::Rules;
 use bevy_procedural_tilemaps::proc_gen::grid::GridData;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
+use crate::collision::{Footprint, TileType};
 use crate::config::map::{
-    CHUNKS_X, CHUNKS_Y, GRID_X, GRID_Y, NODE_SIZE_Z, TILE_SIZE, TOTAL_GRID_X, TOTAL_GRID_Y,
+    CHUNKS_X, CHUNKS_Y, GAME_RNG_SALT, GRID_X, GRID_Y, NODE_SIZE_Z, TILE_SIZE, TOTAL_GRID_X,
+    TOTAL_GRID_Y,
 };
 use crate::map::{
-    assets::{load_assets, prepare_tilemap_handles},
+    assets::{load_assets, model_asset, prepare_tilemap_handles, SpawnableAsset, TilemapHandles},
+    diagnostics::GENERATION_TIME,
+    post_process::{MapPostProcessors, TileGrid},
     rules::build_world,
+    world_id::WorldEntityId,
+    Structures,
 };
+use crate::mods::ContentPacks;
+use crate::settings::Difficulty;
 
 const ASSETS_PATH: &str = "tile_layers";
 const TILEMAP_FILE: &str = "tilemap.png";
 const NODE_SIZE: Vec3 = Vec3::new(TILE_SIZE, TILE_SIZE, NODE_SIZE_Z);
-const ASSETS_SCALE: Vec3 = Vec3::new(2.0, 2.0, 1.0);
+pub const ASSETS_SCALE: Vec3 = Vec3::new(2.0, 2.0, 1.0);
 const GRID_Z: u32 = 5;
 
 /// Maximum unpin radius for progressive corner unpinning fallback.
 const MAX_UNPIN_RADIUS: u32 = 5;
 
+/// Seed the whole map is generated from.
+///
+/// The same seed always produces the same layout, which is what lets a save
+/// file rebuild the world and replay its changes on top.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct MapSeed(pub u64);
+
+impl Default for MapSeed {
+    fn default() -> Self {
+        Self(rand::random())
+    }
+}
+
+/// Randomness for gameplay, like fishing bites and catches or where the pet
+/// looks, so the same seed and input always play out the same way.
+///
+/// Reseeded from the `MapSeed` every time a world loads.
+#[derive(Resource)]
+pub struct GameRng(pub StdRng);
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self(StdRng::seed_from_u64(MapSeed::default().0 ^ GAME_RNG_SALT))
+    }
+}
+
+/// Restarts gameplay randomness for the world about to be generated.
+pub fn reseed_game_rng(seed: Res<MapSeed>, mut rng: ResMut<GameRng>) {
+    rng.0 = StdRng::seed_from_u64(seed.0 ^ GAME_RNG_SALT);
+}
+
 /// Shared progress counter for the loading screen.
 #[derive(Resource)]
 pub struct MapGenProgress {
     pub current: Arc<AtomicU32>,
     pub total: u32,
+    pub started: Instant,
 }
 
 /// Marker resource: inserted when the map is fully spawned.
@@ -43,11 +88,24 @@ pub struct MapReady;
 pub struct MapSpawnResources {
     spawner: NodesSpawner<Sprite>,
     grid_template: CartesianGrid<Cartesian3D>,
+    tilemap_handles: TilemapHandles,
+    /// Footprints of multi-tile assets, by (model index, asset index)
+    footprints: HashMap<(usize, usize), Footprint>,
 }
 
 /// Background task producing generated chunk data.
 #[derive(Resource)]
-pub struct MapGenTask(Task<Vec<ChunkResult>>);
+pub struct MapGenTask(Task<GeneratedMap>);
+
+/// Generated chunks plus the cells post-processing passes painted over them.
+struct GeneratedMap {
+    chunks: Vec<ChunkResult>,
+    grid: TileGrid,
+}
+
+/// Tile types a model's assets give the cells they cover, as
+/// (grid offset from the node, tile type).
+type ModelTiles = Vec<Vec<(IVec3, TileType)>>;
 
 struct ChunkResult {
     grid_data: GridData<Cartesian3D, ModelInstance, CartesianGrid<Cartesian3D>>,
@@ -60,9 +118,17 @@ pub fn setup_generator(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    seed: Res<MapSeed>,
+    difficulty: Res<Difficulty>,
+    packs: Res<ContentPacks>,
+    post_processors: Res<MapPostProcessors>,
 ) {
+    info!("Generating map with seed {} on {}", seed.0, difficulty.label());
+
     // 1. Build rules, models, and assets (shared across all chunks)
-    let (assets_definitions, models, socket_collection) = build_world();
+    let (assets_definitions, models, socket_collection) = build_world(&packs, *difficulty);
+    let model_tiles = model_tiles(&assets_definitions);
+    let footprints = model_footprints(&assets_definitions);
 
     let rules = RulesBuilder::new_cartesian_3d(models, socket_collection)
         .with_rotation_axis(Direction::ZForward)
@@ -76,12 +142,16 @@ pub fn setup_generator(
     let tilemap_handles =
         prepare_tilemap_handles(&asset_server, &mut atlas_layouts, ASSETS_PATH, TILEMAP_FILE);
     let models_assets = load_assets(&tilemap_handles, assets_definitions);
+    // Keep the atlas around for entities placed after generation (e.g. shore reeds)
+    commands.insert_resource(tilemap_handles.clone());
     let spawner = NodesSpawner::new(models_assets, NODE_SIZE, ASSETS_SCALE);
 
     // Store resources needed for spawning later
     commands.insert_resource(MapSpawnResources {
         spawner,
         grid_template: grid_template.clone(),
+        tilemap_handles,
+        footprints,
     });
 
     // Initialize progress tracking
@@ -89,12 +159,18 @@ pub fn setup_generator(
     commands.insert_resource(MapGenProgress {
         current: progress.clone(),
         total: CHUNKS_X * CHUNKS_Y,
+        started: Instant::now(),
     });
 
     // Spawn the background task
     let pool = AsyncComputeTaskPool::get();
+    let seed = seed.0;
+    let post_processors = post_processors.clone();
     let task = pool.spawn(async move {
-        generate_all_chunks(rules_arc, grid_template, progress)
+        let chunks = generate_all_chunks(rules_arc, grid_template.clone(), progress, seed);
+        let mut grid = build_tile_grid(&chunks, &grid_template, &model_tiles);
+        run_post_processors(&post_processors, &mut grid, seed);
+        GeneratedMap { chunks, grid }
     });
     commands.insert_resource(MapGenTask(task));
 }
@@ -103,28 +179,46 @@ pub fn poll_map_generation(
     mut commands: Commands,
     task: Option<ResMut<MapGenTask>>,
     resources: Option<Res<MapSpawnResources>>,
+    progress: Option<Res<MapGenProgress>>,
+    mut diagnostics: Diagnostics,
 ) {
     let (Some(mut task), Some(resources)) = (task, resources) else {
         return;
     };
 
     // Check if the task is done
-    let Some(chunks) = block_on(poll_once(&mut task.0)) else {
+    let Some(generated) = block_on(poll_once(&mut task.0)) else {
         return; // Still running...
     };
 
     // Task finished! Spawn everything.
-    for chunk in &chunks {
+    for chunk in &generated.chunks {
         spawn_chunk_tiles(
             &mut commands,
             &resources.grid_template,
             &resources.spawner,
+            &resources.footprints,
             &chunk.grid_data,
+            &generated.grid,
             chunk.chunk_offset,
             chunk.chunk_x,
             chunk.chunk_y,
         );
     }
+    spawn_painted_tiles(&mut commands, &resources.tilemap_handles, &generated.grid);
+
+    if let Some(progress) = progress {
+        let elapsed = progress.started.elapsed();
+        diagnostics.add_measurement(&GENERATION_TIME, || elapsed.as_secs_f64() * 1000.0);
+    }
+
+    let origin = Vec2::new(TOTAL_GRID_X as f32, TOTAL_GRID_Y as f32) * TILE_SIZE / 2.0;
+    let entrances = generated
+        .grid
+        .structures()
+        .iter()
+        .map(|cell| (cell.as_vec2() + 0.5) * TILE_SIZE - origin);
+    commands.insert_resource(Structures(entrances.collect()));
 
     // Cleanup and mark as ready
     commands.remove_resource::<MapGenTask>();
@@ -142,6 +236,7 @@ fn generate_all_chunks(
     rules_arc: Arc<Rules<Cartesian3D>>,
     grid_template: CartesianGrid<Cartesian3D>,
     progress: Arc<AtomicU32>,
+    seed: u64,
 ) -> Vec<ChunkResult> {
     let mut generated_chunks: HashMap<
         (u32, u32),
@@ -162,6 +257,7 @@ fn generate_all_chunks(
                 is_corner,
                 cx,
                 cy,
+                chunk_seed(seed, cx, cy),
             );
 
             generated_chunks.insert((cx, cy), grid_data);
@@ -191,6 +287,105 @@ fn generate_all_chunks(
     results
 }
 
+/// Where each model's tile-typed assets land relative to its node.
+fn model_tiles(assets_definitions: &[Vec<SpawnableAsset>]) -> ModelTiles {
+    assets_definitions
+        .iter()
+        .map(|assets| {
+            let mut tiles = Vec::new();
+            for asset in assets {
+                let Some(tile_type) = asset.tile_type() else {
+                    continue;
+                };
+                let offset = asset.grid_offset();
+                let offset = IVec3::new(offset.dx, offset.dy, offset.dz);
+                tiles.push((offset, tile_type));
+                // Multi-tile props also cover their footprint cells
+                for (cell, tile_type) in asset.footprint().map(|f| f.0).unwrap_or_default() {
+                    tiles.push((offset + cell.extend(0), tile_type));
+                }
+            }
+            tiles
+        })
+        .collect()
+}
+
+fn model_footprints(assets_definitions: &[Vec<SpawnableAsset>]) -> HashMap<(usize, usize), Footprint> {
+    let mut footprints = HashMap::new();
+    for (model_index, assets) in assets_definitions.iter().enumerate() {
+        for (asset_index, asset) in assets.iter().enumerate() {
+            if let Some(footprint) = asset.footprint() {
+                footprints.insert((model_index, asset_index), footprint);
+            }
+        }
+    }
+    footprints
+}
+
+/// Collision map cell of a node in a chunk (chunks share their border row/column).
+fn global_cell(chunk_x: u32, chunk_y: u32, x: i32, y: i32) -> IVec2 {
+    IVec2::new(
+        (chunk_x * (GRID_X - 1)) as i32 + x,
+        (chunk_y * (GRID_Y - 1)) as i32 + y,
+    )
+}
+
+/// Flatten the generated chunks into the topmost tile type of every cell,
+/// the same way the collision map is built from the spawned tiles.
+fn build_tile_grid(
+    chunks: &[ChunkResult],
+    grid: &CartesianGrid<Cartesian3D>,
+    model_tiles: &ModelTiles,
+) -> TileGrid {
+    let mut tile_grid = TileGrid::new(TOTAL_GRID_X, TOTAL_GRID_Y);
+    let mut top_z = vec![i32::MIN; (TOTAL_GRID_X * TOTAL_GRID_Y) as usize];
+
+    for chunk in chunks {
+        for (node_index, instance) in chunk.grid_data.iter().enumerate() {
+            let Some(tiles) = model_tiles.get(instance.model_index) else {
+                continue;
+            };
+            let position = grid.pos_from_index(node_index);
+            for &(offset, tile_type) in tiles {
+                let cell = global_cell(
+                    chunk.chunk_x,
+                    chunk.chunk_y,
+                    position.x as i32 + offset.x,
+                    position.y as i32 + offset.y,
+                );
+                if !tile_grid.in_bounds(cell.x, cell.y) {
+                    continue;
+                }
+                let z = position.z as i32 + offset.z;
+                let index = (cell.y as u32 * TOTAL_GRID_X + cell.x as u32) as usize;
+                if z >= top_z[index] {
+                    top_z[index] = z;
+                    tile_grid.set_generated(cell.as_uvec2(), tile_type);
+                }
+            }
+        }
+    }
+    tile_grid
+}
+
+fn run_post_processors(processors: &MapPostProcessors, grid: &mut TileGrid, seed: u64) {
+    for (index, processor) in processors.0.iter().enumerate() {
+        // Seeded per pass so adding a pass doesn't change what earlier ones do
+        let mut rng = StdRng::seed_from_u64(pass_seed(seed, index));
+        processor.process(grid, &mut rng);
+        info!("Ran map post-processor '{}'", processor.name());
+    }
+}
+
+fn pass_seed(seed: u64, index: usize) -> u64 {
+    (seed ^ (index as u64 + 1).wrapping_mul(0xD1B5_4A32_D192_ED03)).rotate_left(29)
+}
+
+/// Derive a per-chunk RNG seed so every chunk differs but stays reproducible.
+fn chunk_seed(seed: u64, cx: u32, cy: u32) -> u64 {
+    let chunk_index = (cy * CHUNKS_X + cx) as u64;
+    (seed ^ chunk_index.wrapping_mul(0x9E37_79B9_7F4A_7C15)).rotate_left(17)
+}
 
 fn build_initial_nodes(
     cx: u32,
@@ -238,9 +433,10 @@ fn generate_chunk_with_fallback(
     is_corner: bool,
     cx: u32,
     cy: u32,
+    seed: u64,
 ) -> GridData<Cartesian3D, ModelInstance, CartesianGrid<Cartesian3D>> {
     // Try with full initial nodes first
-    if let Some(data) = try_generate_chunk(rules, grid, initial_nodes) {
+    if let Some(data) = try_generate_chunk(rules, grid, initial_nodes, seed) {
         return data;
     }
 
@@ -259,7 +455,7 @@ fn generate_chunk_with_fallback(
             .copied()
             .collect();
 
-        if let Some(data) = try_generate_chunk(rules, grid, &reduced) {
+        if let Some(data) = try_generate_chunk(rules, grid, &reduced, seed) {
             warn!("Corner chunk ({}, {}) needed unpin radius {}", cx, cy, radius);
             return data;
         }
@@ -272,6 +468,7 @@ fn try_generate_chunk(
     rules: &Arc<Rules<Cartesian3D>>,
     grid: &CartesianGrid<Cartesian3D>,
     initial_nodes: &[((u32, u32, u32), ModelInstance)],
+    seed: u64,
 ) -> Option<GridData<Cartesian3D, ModelInstance, CartesianGrid<Cartesian3D>>> {
     // In v0.3 we explicitly set border zones
     let num_directions = 6;
@@ -286,7 +483,7 @@ fn try_generate_chunk(
     let gen_builder = GeneratorBuilder::new()
         .with_shared_rules(rules.clone())
         .with_grid(grid.clone())
-        .with_rng(RngMode::RandomSeed)
+        .with_rng(RngMode::Seeded(seed))
         .with_node_heuristic(NodeSelectionHeuristic::MinimumRemainingValue)
         .with_model_heuristic(ModelSelectionHeuristic::WeightedProbability)
         .with_border_zones(border_zones);
@@ -315,7 +512,9 @@ fn spawn_chunk_tiles(
     commands: &mut Commands,
     grid: &CartesianGrid<Cartesian3D>,
     spawner: &NodesSpawner<Sprite>,
+    footprints: &HashMap<(usize, usize), Footprint>,
     grid_data: &GridData<Cartesian3D, ModelInstance, CartesianGrid<Cartesian3D>>,
+    tile_grid: &TileGrid,
     chunk_offset: Vec3,
     chunk_x: u32,
     chunk_y: u32,
@@ -334,8 +533,13 @@ fn spawn_chunk_tiles(
         if position.y == GRID_Y - 1 && chunk_y < CHUNKS_Y - 1 {
             continue;
         }
+        // Painted cells are spawned from their pass's layers instead
+        let cell = global_cell(chunk_x, chunk_y, position.x as i32, position.y as i32);
+        if tile_grid.is_painted(cell.as_uvec2()) {
+            continue;
+        }
 
-        for asset in node_assets.iter() {
+        for (asset_index, asset) in node_assets.iter().enumerate() {
             let mut local_pos = Vec3::new(
                 asset.world_offset.x
                     + NODE_SIZE.x
@@ -358,7 +562,8 @@ fn spawn_chunk_tiles(
                 local_pos.z,
             );
 
-            let entity = commands.spawn_empty().id();
+            let id = WorldEntityId::generated(chunk_x, chunk_y, node_index, asset_index);
+            let entity = commands.spawn(id).id();
             let entity_commands = &mut commands.entity(entity);
             asset.assets_bundle.insert_bundle(
                 entity_commands,
@@ -367,6 +572,52 @@ fn spawn_chunk_tiles(
                 instance.rotation,
             );
             (asset.spawn_commands)(entity_commands);
+            if let Some(footprint) = footprints.get(&(instance.model_index, asset_index)) {
+                entity_commands.insert(footprint.clone());
+            }
+            // Lets the debug tile inspector name the model behind a tile
+            #[cfg(debug_assertions)]
+            entity_commands.insert(*instance);
         }
     }
-}
\ No newline at end of file
+}
+
+/// Spawns the layers post-processing passes painted, in place of the
+/// generated tiles `spawn_chunk_tiles` skipped there.
+fn spawn_painted_tiles(
+    commands: &mut Commands,
+    tilemap_handles: &TilemapHandles,
+    tile_grid: &TileGrid,
+) {
+    let origin = Vec2::new(TOTAL_GRID_X as f32, TOTAL_GRID_Y as f32) * TILE_SIZE / 2.0;
+    for (cell, layers) in tile_grid.painted() {
+        for (layer, asset_def) in layers.iter().enumerate() {
+            let footprint = asset_def.footprint();
+            let asset = model_asset(tilemap_handles, asset_def.clone());
+            let world_pos = Vec3::new(
+                asset.world_offset.x
+                    + NODE_SIZE.x * (cell.x as f32 + asset.grid_offset.dx as f32 + 0.5)
+                    - origin.x,
+                asset.world_offset.y
+                    + NODE_SIZE.y * (cell.y as f32 + asset.grid_offset.dy as f32 + 0.5)
+                    - origin.y,
+                asset.world_offset.z
+                    + NODE_SIZE.z * (layer as f32 + 0.5)
+                    + NODE_SIZE_Z * (1.0 - cell.y as f32 / TOTAL_GRID_Y as f32),
+            );
+
+            let entity = commands.spawn(WorldEntityId::painted(cell, layer)).id();
+            let entity_commands = &mut commands.entity(entity);
+            asset.assets_bundle.insert_bundle(
+                entity_commands,
+                world_pos,
+                ASSETS_SCALE,
+                ModelRotation::Rot0,
+            );
+            (asset.spawn_commands)(entity_commands);
+            if let Some(footprint) = footprint {
+                entity_commands.insert(footprint);
+            }
+        }
+    }
+}
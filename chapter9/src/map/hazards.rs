@@ -0,0 +1,71 @@
+// src/map/hazards.rs
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::collision::TileType;
+use crate::config::post_process::{HAZARD_PATCH_COUNT, HAZARD_PATCH_RADIUS, HAZARD_SPAWN_MARGIN};
+use crate::map::assets::SpawnableAsset;
+use crate::map::post_process::{MapPostProcessor, TileGrid};
+
+/// Scatters patches of thorn bushes and deep mud over open ground.
+///
+/// Thorns grow on grass and mud settles on dirt; cells another pass already
+/// painted and the area around spawn are left alone.
+pub struct HazardPass;
+
+impl MapPostProcessor for HazardPass {
+    fn name(&self) -> &str {
+        "hazards"
+    }
+
+    fn process(&self, grid: &mut TileGrid, rng: &mut StdRng) {
+        let center = IVec2::new(grid.width() as i32, grid.height() as i32) / 2;
+        let mut painted = 0;
+
+        for _ in 0..HAZARD_PATCH_COUNT {
+            let middle = IVec2::new(
+                rng.gen_range(0..grid.width() as i32),
+                rng.gen_range(0..grid.height() as i32),
+            );
+            if (middle - center).abs().max_element() < HAZARD_SPAWN_MARGIN + HAZARD_PATCH_RADIUS {
+                continue;
+            }
+
+            for dy in -HAZARD_PATCH_RADIUS..=HAZARD_PATCH_RADIUS {
+                for dx in -HAZARD_PATCH_RADIUS..=HAZARD_PATCH_RADIUS {
+                    // Ragged edges: the further out, the less likely a tile is covered
+                    let distance = dx.abs().max(dy.abs());
+                    if distance > 0 && rng.gen_range(0..=HAZARD_PATCH_RADIUS) < distance {
+                        continue;
+                    }
+                    let cell = middle + IVec2::new(dx, dy);
+                    if let Some(layers) = hazard_layers(grid, cell) {
+                        grid.paint(cell.as_uvec2(), layers);
+                        painted += 1;
+                    }
+                }
+            }
+        }
+        info!("Painted {} hazard tiles", painted);
+    }
+}
+
+/// The hazard a cell turns into, or `None` if it isn't open ground.
+fn hazard_layers(grid: &TileGrid, cell: IVec2) -> Option<Vec<SpawnableAsset>> {
+    let tile = grid.get(cell.x, cell.y)?;
+    if grid.is_painted(cell.as_uvec2()) {
+        return None;
+    }
+    match tile {
+        TileType::Grass | TileType::YellowGrass => Some(vec![
+            SpawnableAsset::new("green_grass").with_tile_type(TileType::Grass),
+            SpawnableAsset::new("thorns").with_tile_type(TileType::Thorns),
+        ]),
+        TileType::Dirt => Some(vec![
+            SpawnableAsset::new("dirt").with_tile_type(TileType::Dirt),
+            SpawnableAsset::new("mud").with_tile_type(TileType::Mud),
+        ]),
+        _ => None,
+    }
+}
@@ -1,6 +1,80 @@
 pub mod assets; 
+pub mod biome;
+pub mod diagnostics;
+pub mod events;
 pub mod tilemap;
 pub mod rules;
 pub mod models;
 pub mod sockets;
-pub mod generate;
\ No newline at end of file
+pub mod generate;
+pub mod world_id;
+pub mod post_process;
+pub mod regions;
+mod hazards;
+mod river;
+mod structures;
+
+use bevy::diagnostic::{Diagnostic, RegisterDiagnostic};
+use bevy::prelude::*;
+
+use crate::characters::physics::apply_velocity;
+use crate::collision::CollisionMap;
+use crate::state::GameState;
+use biome::PlayerBiome;
+use regions::Regions;
+use generate::{GameRng, MapSeed};
+
+pub use events::{RegionDiscovered, TileChanged};
+pub use post_process::AppMapPostProcessExt;
+pub use structures::Structures;
+
+/// Plugin for world generation.
+///
+/// Generation starts every time the game enters `Loading`, so loading a save
+/// can rebuild the map from its seed. Passes registered with
+/// `add_map_post_processor` then edit the result before it is spawned; the
+/// river, ruined structures and hazard patches are added this way. Once the
+/// collision map is built the world is split into named `Regions`.
+pub struct MapPlugin;
+
+impl Plugin for MapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MapSeed>()
+            .init_resource::<GameRng>()
+            .init_resource::<PlayerBiome>()
+            .add_map_post_processor(river::RiverPass)
+            .add_map_post_processor(structures::StructurePass)
+            .add_map_post_processor(hazards::HazardPass)
+            .register_diagnostic(Diagnostic::new(diagnostics::GENERATION_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(diagnostics::TILE_ENTITIES))
+            .add_systems(
+                OnEnter(GameState::Loading),
+                (generate::setup_generator, generate::reseed_game_rng),
+            )
+            .add_systems(
+                Update,
+                generate::poll_map_generation.run_if(in_state(GameState::Loading)),
+            )
+            .add_systems(
+                FixedUpdate,
+                biome::track_player_biome
+                    .after(apply_velocity)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                diagnostics::measure_tile_entities.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    regions::build_regions
+                        .run_if(resource_exists::<CollisionMap>)
+                        .run_if(not(resource_exists::<Regions>)),
+                    regions::track_player_region.run_if(resource_exists::<Regions>),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,140 @@
+// src/map/post_process.rs
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+
+use crate::collision::TileType;
+use crate::map::assets::SpawnableAsset;
+
+/// A pass that edits the generated map before it is spawned.
+///
+/// Passes run in registration order on the background generation task, after
+/// every chunk is generated. Each gets an RNG seeded from the map seed and its
+/// position in the list, so a seed always produces the same edits.
+///
+/// ```ignore
+/// struct Clearing;
+///
+/// impl MapPostProcessor for Clearing {
+///     fn name(&self) -> &str { "clearing" }
+///
+///     fn process(&self, grid: &mut TileGrid, _rng: &mut StdRng) {
+///         let center = UVec2::new(grid.width() / 2, grid.height() / 2);
+///         grid.paint(center, vec![SpawnableAsset::new("dirt").with_tile_type(TileType::Dirt)]);
+///     }
+/// }
+///
+/// app.add_map_post_processor(Clearing);
+/// ```
+pub trait MapPostProcessor: Send + Sync + 'static {
+    /// Shown in logs when the pass runs.
+    fn name(&self) -> &str;
+
+    fn process(&self, grid: &mut TileGrid, rng: &mut StdRng);
+}
+
+/// Registered post-processing passes, in the order they run.
+#[derive(Resource, Default, Clone)]
+pub struct MapPostProcessors(pub(crate) Vec<Arc<dyn MapPostProcessor>>);
+
+/// Registers passes from plugins, like `add_plugins` does for plugins.
+pub trait AppMapPostProcessExt {
+    fn add_map_post_processor(&mut self, processor: impl MapPostProcessor) -> &mut Self;
+}
+
+impl AppMapPostProcessExt for App {
+    fn add_map_post_processor(&mut self, processor: impl MapPostProcessor) -> &mut Self {
+        self.init_resource::<MapPostProcessors>();
+        self.world_mut()
+            .resource_mut::<MapPostProcessors>()
+            .0
+            .push(Arc::new(processor));
+        self
+    }
+}
+
+/// The whole generated map as post-processing passes see it.
+///
+/// Cells use collision map coordinates. Reading a cell gives its topmost
+/// generated tile type, or the one painted over it by an earlier pass.
+/// Painting a cell replaces every generated layer there with the given
+/// assets, bottom first.
+pub struct TileGrid {
+    width: u32,
+    height: u32,
+    tiles: Vec<TileType>,
+    painted: HashMap<UVec2, Vec<SpawnableAsset>>,
+    structures: Vec<UVec2>,
+}
+
+impl TileGrid {
+    pub(crate) fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            tiles: vec![TileType::Empty; (width * height) as usize],
+            painted: HashMap::new(),
+            structures: Vec::new(),
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && y >= 0 && (x as u32) < self.width && (y as u32) < self.height
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<TileType> {
+        self.in_bounds(x, y)
+            .then(|| self.tiles[(y as u32 * self.width + x as u32) as usize])
+    }
+
+    /// Set the generated tile type of a cell, without painting it.
+    pub(crate) fn set_generated(&mut self, cell: UVec2, tile_type: TileType) {
+        let index = (cell.y * self.width + cell.x) as usize;
+        self.tiles[index] = tile_type;
+    }
+
+    /// Whether an earlier pass already painted this cell.
+    pub fn is_painted(&self, cell: UVec2) -> bool {
+        self.painted.contains_key(&cell)
+    }
+
+    /// Replace a cell with `layers`. Its tile type becomes that of the
+    /// topmost layer that has one.
+    pub fn paint(&mut self, cell: UVec2, layers: Vec<SpawnableAsset>) {
+        if !self.in_bounds(cell.x as i32, cell.y as i32) {
+            return;
+        }
+        if let Some(tile_type) = layers.iter().rev().find_map(SpawnableAsset::tile_type) {
+            self.set_generated(cell, tile_type);
+        }
+        self.painted.insert(cell, layers);
+    }
+
+    /// Remember the entrance of a structure a pass built, so gameplay can
+    /// find it once the map is spawned.
+    pub fn mark_structure(&mut self, entrance: UVec2) {
+        self.structures.push(entrance);
+    }
+
+    /// Entrances of every structure marked so far.
+    pub(crate) fn structures(&self) -> &[UVec2] {
+        &self.structures
+    }
+
+    /// Every painted cell with its layers.
+    pub(crate) fn painted(&self) -> impl Iterator<Item = (UVec2, &[SpawnableAsset])> {
+        self.painted
+            .iter()
+            .map(|(cell, layers)| (*cell, layers.as_slice()))
+    }
+}
@@ -0,0 +1,192 @@
+// src/map/regions.rs
+use std::collections::{HashSet, VecDeque};
+
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use super::biome::Biome;
+use super::generate::MapSeed;
+use super::RegionDiscovered;
+use crate::characters::input::Player;
+use crate::collision::CollisionMap;
+use crate::config::regions::{MIN_REGION_CELLS, NAME_SALT};
+use crate::event_log::{LogCategory, LogEvent};
+use crate::ui::ShowToast;
+
+const ADJECTIVES: &[&str] = &[
+    "Whispering", "Amber", "Hollow", "Sunlit", "Misty", "Old", "Quiet", "Windy",
+    "Bramble", "Silver", "Crooked", "Lonely", "Golden", "Mossy", "Far", "Dusky",
+];
+
+fn nouns(biome: Biome) -> &'static [&'static str] {
+    match biome {
+        Biome::Meadow => &["Meadow", "Green", "Lea", "Fields"],
+        Biome::Plains => &["Plains", "Steppe", "Flats", "Downs"],
+        Biome::Wilds => &["Wilds", "Scrub", "Barrens", "Thicket"],
+        Biome::Coast => &["Coast", "Shallows", "Strand", "Lagoon"],
+    }
+}
+
+/// A connected stretch of one biome with its own name.
+#[derive(Debug, Clone)]
+pub struct Region {
+    pub name: String,
+    pub biome: Biome,
+    pub discovered: bool,
+}
+
+/// The named regions of the current map.
+///
+/// Built once the collision map exists by flood-filling cells of the same
+/// biome; stretches smaller than `MIN_REGION_CELLS` and cells without a
+/// biome (trees, rocks, ...) belong to no region. Names come from the map
+/// seed, so a world always gets the same ones.
+#[derive(Resource, Debug, Default)]
+pub struct Regions {
+    pub regions: Vec<Region>,
+    /// Region index per cell, row by row
+    cells: Vec<Option<usize>>,
+    width: i32,
+    /// Region the player was last inside
+    current: Option<usize>,
+}
+
+impl Regions {
+    /// Index into `regions` of the region a cell belongs to.
+    pub fn at(&self, cell: IVec2) -> Option<usize> {
+        if cell.x < 0 || cell.y < 0 || cell.x >= self.width {
+            return None;
+        }
+        self.cells
+            .get((cell.y * self.width + cell.x) as usize)
+            .copied()
+            .flatten()
+    }
+
+    /// Segments `map` into regions and names them from `seed`.
+    pub fn build(map: &CollisionMap, seed: u64) -> Self {
+        let (width, height) = (map.width(), map.height());
+        let biome_at = |cell: IVec2| map.get_tile(cell.x, cell.y).and_then(Biome::from_tile);
+        let mut cells = vec![None; (width * height) as usize];
+        let mut visited = vec![false; cells.len()];
+        let mut regions = Vec::new();
+        let mut rng = StdRng::seed_from_u64(seed ^ NAME_SALT);
+        let mut used_names = HashSet::new();
+
+        for y in 0..height {
+            for x in 0..width {
+                let start = IVec2::new(x, y);
+                if visited[(y * width + x) as usize] {
+                    continue;
+                }
+                visited[(y * width + x) as usize] = true;
+                let Some(biome) = biome_at(start) else {
+                    continue;
+                };
+
+                let mut members = vec![start];
+                let mut queue = VecDeque::from([start]);
+                while let Some(cell) = queue.pop_front() {
+                    for next in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y].map(|d| cell + d) {
+                        if !map.in_bounds(next.x, next.y) {
+                            continue;
+                        }
+                        let index = (next.y * width + next.x) as usize;
+                        if visited[index] || biome_at(next) != Some(biome) {
+                            continue;
+                        }
+                        visited[index] = true;
+                        members.push(next);
+                        queue.push_back(next);
+                    }
+                }
+                if members.len() < MIN_REGION_CELLS {
+                    continue;
+                }
+
+                let index = regions.len();
+                for cell in &members {
+                    cells[(cell.y * width + cell.x) as usize] = Some(index);
+                }
+                regions.push(Region {
+                    name: region_name(biome, &mut rng, &mut used_names),
+                    biome,
+                    discovered: false,
+                });
+            }
+        }
+
+        Self {
+            regions,
+            cells,
+            width,
+            current: None,
+        }
+    }
+}
+
+/// A name not used yet on this map, like "Amber Flats".
+fn region_name(biome: Biome, rng: &mut StdRng, used: &mut HashSet<String>) -> String {
+    let mut adjectives = ADJECTIVES.to_vec();
+    adjectives.shuffle(rng);
+    let mut nouns = nouns(biome).to_vec();
+    nouns.shuffle(rng);
+
+    let name = adjectives
+        .iter()
+        .flat_map(|adjective| nouns.iter().map(move |noun| format!("{} {}", adjective, noun)))
+        .find(|name| !used.contains(name))
+        // Every combination is taken; number the repeats
+        .unwrap_or_else(|| format!("{} {} {}", adjectives[0], nouns[0], used.len() + 1));
+    used.insert(name.clone());
+    name
+}
+
+pub fn build_regions(mut commands: Commands, map: Res<CollisionMap>, seed: Res<MapSeed>) {
+    let regions = Regions::build(&map, seed.0);
+    info!("Named {} regions", regions.regions.len());
+    commands.insert_resource(regions);
+}
+
+/// Announces each region the first time the player walks into it.
+pub fn track_player_region(
+    mut commands: Commands,
+    collision_map: Option<Res<CollisionMap>>,
+    player_query: Query<&Transform, With<Player>>,
+    mut regions: ResMut<Regions>,
+) {
+    let Some(map) = collision_map else {
+        return;
+    };
+    let Ok(transform) = player_query.single() else {
+        return;
+    };
+
+    // Cells outside any region (a tree's shade, a tiny patch) keep the last one
+    let cell = map.world_to_grid(transform.translation.truncate());
+    let Some(index) = regions.at(cell) else {
+        return;
+    };
+    if regions.current == Some(index) {
+        return;
+    }
+    regions.current = Some(index);
+
+    let region = &mut regions.regions[index];
+    if region.discovered {
+        return;
+    }
+    region.discovered = true;
+    info!("Discovered region '{}'", region.name);
+    commands.trigger(RegionDiscovered {
+        name: region.name.clone(),
+        biome: region.biome,
+    });
+    commands.trigger(ShowToast::new(format!("Discovered {}", region.name)));
+    commands.trigger(LogEvent::new(
+        LogCategory::Discovery,
+        format!("Discovered {}", region.name),
+    ));
+}
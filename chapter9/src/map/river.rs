@@ -0,0 +1,55 @@
+// src/map/river.rs
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::collision::TileType;
+use crate::config::post_process::{
+    RIVER_FORD_SPACING, RIVER_MEANDER_PERCENT, RIVER_SPAWN_MARGIN, RIVER_WIDTH,
+};
+use crate::map::assets::SpawnableAsset;
+use crate::map::post_process::{MapPostProcessor, TileGrid};
+
+/// Carves a meandering river from the left edge of the map to the right.
+///
+/// It stays in the half of the map above or below spawn, and is crossed by a
+/// dirt ford every `RIVER_FORD_SPACING` columns.
+pub struct RiverPass;
+
+impl MapPostProcessor for RiverPass {
+    fn name(&self) -> &str {
+        "river"
+    }
+
+    fn process(&self, grid: &mut TileGrid, rng: &mut StdRng) {
+        let center = grid.height() / 2;
+        if center < RIVER_SPAWN_MARGIN + RIVER_WIDTH + 1 {
+            return; // Map too small to keep spawn dry
+        }
+
+        // Band of rows the river's top row may wander in
+        let (min_y, max_y) = if rng.gen_bool(0.5) {
+            (1, center - RIVER_SPAWN_MARGIN - RIVER_WIDTH)
+        } else {
+            (center + RIVER_SPAWN_MARGIN, grid.height() - RIVER_WIDTH - 1)
+        };
+        let mut y = rng.gen_range(min_y..=max_y);
+
+        for x in 0..grid.width() {
+            if rng.gen_range(0..100) < RIVER_MEANDER_PERCENT {
+                y = if rng.gen_bool(0.5) { y.saturating_sub(1) } else { y + 1 };
+                y = y.clamp(min_y, max_y);
+            }
+
+            let ford = x % RIVER_FORD_SPACING == RIVER_FORD_SPACING / 2;
+            for dy in 0..RIVER_WIDTH {
+                let layer = if ford {
+                    SpawnableAsset::new("dirt").with_tile_type(TileType::Dirt)
+                } else {
+                    SpawnableAsset::new("water").with_tile_type(TileType::Water)
+                };
+                grid.paint(UVec2::new(x, y + dy), vec![layer]);
+            }
+        }
+    }
+}
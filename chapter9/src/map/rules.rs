@@ -1,10 +1,13 @@
 // src/map/rules.rs
-use crate::map::assets::SpawnableAsset;
+use crate::map::assets::{SpawnableAsset, SpriteSource};
 use crate::collision::TileType;
 use crate::map::models::TerrainModelBuilder;
 use crate::map::sockets::*;
 use bevy_procedural_tilemaps::prelude::*;
 use crate::inventory::ItemKind;
+use crate::harvest::ResourceNodeKind;
+use crate::mods::{ContentPacks, PackProp};
+use crate::settings::Difficulty;
 
 fn build_dirt_layer(
     terrain_model_builder: &mut TerrainModelBuilder,
@@ -309,6 +312,7 @@ pub fn build_water_layer(
     terrain_model_builder: &mut TerrainModelBuilder,
     terrain_sockets: &TerrainSockets,
     socket_collection: &mut SocketCollection,
+    difficulty: Difficulty,
 ) {
     // Void model - represents land areas where no water exists
     terrain_model_builder.create_model(
@@ -327,7 +331,7 @@ pub fn build_water_layer(
     );
 
     // Main water tile
-    const WATER_WEIGHT: f32 = 0.002;
+    let water_weight = 0.002 * difficulty.water_density();
     terrain_model_builder
         .create_model(
             SocketsCartesian3D::Simple {
@@ -340,7 +344,7 @@ pub fn build_water_layer(
             },
             vec![SpawnableAsset::new("water").with_tile_type(TileType::Water)],
         )
-        .with_weight(10. * WATER_WEIGHT);
+        .with_weight(10. * water_weight);
 
     // Outer corner template
     let water_corner_out = SocketsCartesian3D::Simple {
@@ -352,7 +356,7 @@ pub fn build_water_layer(
         y_neg: terrain_sockets.water.water_and_void,
     }
     .to_template()
-    .with_weight(WATER_WEIGHT);
+    .with_weight(water_weight);
 
     // Inner corner template
     let water_corner_in = SocketsCartesian3D::Simple {
@@ -364,7 +368,7 @@ pub fn build_water_layer(
         y_neg: terrain_sockets.water.void_and_water,
     }
     .to_template()
-    .with_weight(WATER_WEIGHT);
+    .with_weight(water_weight);
 
     // Side edge template
     let water_side = SocketsCartesian3D::Simple {
@@ -376,7 +380,7 @@ pub fn build_water_layer(
         y_neg: terrain_sockets.water.material,
     }
     .to_template()
-    .with_weight(WATER_WEIGHT);
+    .with_weight(water_weight);
 
     // Create rotated versions of outer corners
     terrain_model_builder.create_model(
@@ -456,6 +460,7 @@ pub fn build_props_layer(
     terrain_model_builder: &mut TerrainModelBuilder,
     terrain_sockets: &TerrainSockets,
     socket_collection: &mut SocketCollection,
+    difficulty: Difficulty,
 ) {
     // Void model - represents areas where no props exist
     terrain_model_builder.create_model(
@@ -470,11 +475,15 @@ pub fn build_props_layer(
         Vec::new(),
     );
 
-    // Weight constants for different prop types
-    const PROPS_WEIGHT: f32 = 0.025;
-    const ROCKS_WEIGHT: f32 = 0.008;
-    const PLANTS_WEIGHT: f32 = 0.025;
-    const STUMPS_WEIGHT: f32 = 0.012;
+    // Weights for different prop types: obstacles scale with the
+    // difficulty's density, things to gather with its abundance
+    let obstacles = difficulty.obstacle_density();
+    let pickups = difficulty.pickup_abundance();
+    let props_weight = 0.025 * obstacles;
+    let rocks_weight = 0.008 * obstacles;
+    let stumps_weight = 0.012 * obstacles;
+    let plants_weight = 0.025 * pickups;
+    let bushes_weight = 0.01 * pickups;
 
     // Base prop template - single tile props
     let prop = SocketsCartesian3D::Simple {
@@ -486,19 +495,20 @@ pub fn build_props_layer(
         y_neg: terrain_sockets.void,
     }
     .to_template()
-    .with_weight(PROPS_WEIGHT);
+    .with_weight(props_weight);
 
     // Create different prop types with different weights
-    let plant_prop = prop.clone().with_weight(PLANTS_WEIGHT);
-    let stump_prop = prop.clone().with_weight(STUMPS_WEIGHT);
-    let rock_prop = prop.clone().with_weight(ROCKS_WEIGHT);
+    let plant_prop = prop.clone().with_weight(plants_weight);
+    let stump_prop = prop.clone().with_weight(stumps_weight);
+    let rock_prop = prop.clone().with_weight(rocks_weight);
+    let bush_prop = prop.clone().with_weight(bushes_weight);
 
     // Small tree (2 tiles high)
     terrain_model_builder.create_model(
-        plant_prop.clone(),
+        prop.clone(),
         vec![
             SpawnableAsset::new("small_tree_bottom").with_tile_type(TileType::Tree),
-            SpawnableAsset::new("small_tree_top").with_grid_offset(GridDelta::new(0, 1, 0)),
+            SpawnableAsset::new("small_tree_top").with_grid_offset(GridDelta::new(0, 1, 0)).with_sway(),
         ],
     );
 
@@ -514,11 +524,13 @@ pub fn build_props_layer(
                 y_neg: terrain_sockets.void,
             },
             vec![
-                SpawnableAsset::new("big_tree_1_bl").with_tile_type(TileType::Tree),
-                SpawnableAsset::new("big_tree_1_tl").with_grid_offset(GridDelta::new(0, 1, 0)),
+                SpawnableAsset::new("big_tree_1_bl")
+                    .with_tile_type(TileType::Tree)
+                    .with_footprint(vec![(GridDelta::new(0, 1, 0), TileType::Tree)]),
+                SpawnableAsset::new("big_tree_1_tl").with_grid_offset(GridDelta::new(0, 1, 0)).with_sway(),
             ],
         )
-        .with_weight(PROPS_WEIGHT);
+        .with_weight(props_weight);
 
     terrain_model_builder
         .create_model(
@@ -531,11 +543,13 @@ pub fn build_props_layer(
                 y_neg: terrain_sockets.void,
             },
             vec![
-                SpawnableAsset::new("big_tree_1_br").with_tile_type(TileType::Tree),
-                SpawnableAsset::new("big_tree_1_tr").with_grid_offset(GridDelta::new(0, 1, 0)),
+                SpawnableAsset::new("big_tree_1_br")
+                    .with_tile_type(TileType::Tree)
+                    .with_footprint(vec![(GridDelta::new(0, 1, 0), TileType::Tree)]),
+                SpawnableAsset::new("big_tree_1_tr").with_grid_offset(GridDelta::new(0, 1, 0)).with_sway(),
             ],
         )
-        .with_weight(PROPS_WEIGHT);
+        .with_weight(props_weight);
 
     // Big tree 2 (2x2 tiles)
     terrain_model_builder
@@ -549,11 +563,13 @@ pub fn build_props_layer(
                 y_neg: terrain_sockets.void,
             },
             vec![
-                SpawnableAsset::new("big_tree_2_bl").with_tile_type(TileType::Tree),
-                SpawnableAsset::new("big_tree_2_tl").with_grid_offset(GridDelta::new(0, 1, 0)),
+                SpawnableAsset::new("big_tree_2_bl")
+                    .with_tile_type(TileType::Tree)
+                    .with_footprint(vec![(GridDelta::new(0, 1, 0), TileType::Tree)]),
+                SpawnableAsset::new("big_tree_2_tl").with_grid_offset(GridDelta::new(0, 1, 0)).with_sway(),
             ],
         )
-        .with_weight(PROPS_WEIGHT);
+        .with_weight(props_weight);
 
     terrain_model_builder
         .create_model(
@@ -566,11 +582,13 @@ pub fn build_props_layer(
                 y_neg: terrain_sockets.void,
             },
             vec![
-                SpawnableAsset::new("big_tree_2_br").with_tile_type(TileType::Tree),
-                SpawnableAsset::new("big_tree_2_tr").with_grid_offset(GridDelta::new(0, 1, 0)),
+                SpawnableAsset::new("big_tree_2_br")
+                    .with_tile_type(TileType::Tree)
+                    .with_footprint(vec![(GridDelta::new(0, 1, 0), TileType::Tree)]),
+                SpawnableAsset::new("big_tree_2_tr").with_grid_offset(GridDelta::new(0, 1, 0)).with_sway(),
             ],
         )
-        .with_weight(PROPS_WEIGHT);
+        .with_weight(props_weight);
 
     // Tree stumps
     terrain_model_builder.create_model(
@@ -598,6 +616,14 @@ pub fn build_props_layer(
     terrain_model_builder.create_model(plant_prop.clone(), vec![SpawnableAsset::new("plant_3").with_tile_type(TileType::Grass).with_pickable(ItemKind::Plant3)]);
     terrain_model_builder.create_model(plant_prop.clone(), vec![SpawnableAsset::new("plant_4").with_tile_type(TileType::Grass).with_pickable(ItemKind::Plant4)]);
 
+    // Harvestable berry bushes
+    terrain_model_builder.create_model(
+        bush_prop.clone(),
+        vec![SpawnableAsset::new("berry_bush")
+            .with_tile_type(TileType::Grass)
+            .with_resource_node(ResourceNodeKind::BerryBush)],
+    );
+
     // Add connection rules
     socket_collection.add_connections(vec![
         (
@@ -622,7 +648,34 @@ pub fn build_props_layer(
         );
 }
 
-pub fn build_world() -> (
+/// Adds each content pack prop as a single-tile model on the props layer.
+fn build_pack_props_layer(
+    terrain_model_builder: &mut TerrainModelBuilder,
+    terrain_sockets: &TerrainSockets,
+    props: &[PackProp],
+) {
+    for prop in props {
+        let mut asset = SpawnableAsset::from_source(SpriteSource::Index(prop.sprite)).with_tile_type(prop.tile_type);
+        if let Some(kind) = prop.pickable {
+            asset = asset.with_pickable(kind);
+        }
+        terrain_model_builder
+            .create_model(
+                SocketsCartesian3D::Simple {
+                    x_pos: terrain_sockets.void,
+                    x_neg: terrain_sockets.void,
+                    z_pos: terrain_sockets.props.layer_up,
+                    z_neg: terrain_sockets.props.props_down,
+                    y_pos: terrain_sockets.void,
+                    y_neg: terrain_sockets.void,
+                },
+                vec![asset],
+            )
+            .with_weight(prop.weight);
+    }
+}
+
+pub fn build_world(packs: &ContentPacks, difficulty: Difficulty) -> (
     Vec<Vec<SpawnableAsset>>,
     ModelCollection<Cartesian3D>,
     SocketCollection,
@@ -657,6 +710,7 @@ pub fn build_world() -> (
         &mut terrain_model_builder,
         &terrain_sockets,
         &mut socket_collection,
+        difficulty,
     );
 
      // Build props layer
@@ -664,8 +718,11 @@ pub fn build_world() -> (
         &mut terrain_model_builder,
         &terrain_sockets,
         &mut socket_collection,
+        difficulty,
     );
 
+    build_pack_props_layer(&mut terrain_model_builder, &terrain_sockets, &packs.props);
+
     let (assets, models) = terrain_model_builder.into_parts();
 
     (assets, models, socket_collection)
@@ -0,0 +1,98 @@
+// src/map/structures.rs
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::collision::TileType;
+use crate::config::post_process::{STRUCTURE_ATTEMPTS, STRUCTURE_COUNT, STRUCTURE_SIZE};
+use crate::inventory::ItemKind;
+use crate::map::assets::SpawnableAsset;
+use crate::map::post_process::{MapPostProcessor, TileGrid};
+
+const WALL_SPRITES: [&str; 4] = ["rock_1", "rock_2", "rock_3", "rock_4"];
+
+/// World positions of the generated structures' entrances: the centre of
+/// the tile just outside each doorway.
+#[derive(Resource, Debug, Default)]
+pub struct Structures(pub Vec<Vec2>);
+
+/// Places small ruins on open ground: a ring of rocks around a dirt floor,
+/// with a doorway on the south side and a plant to pick in the middle.
+pub struct StructurePass;
+
+impl MapPostProcessor for StructurePass {
+    fn name(&self) -> &str {
+        "structures"
+    }
+
+    fn process(&self, grid: &mut TileGrid, rng: &mut StdRng) {
+        if grid.width() <= STRUCTURE_SIZE || grid.height() <= STRUCTURE_SIZE {
+            return;
+        }
+
+        let mut placed = 0;
+        for _ in 0..STRUCTURE_COUNT {
+            let spot = (0..STRUCTURE_ATTEMPTS)
+                .map(|_| {
+                    UVec2::new(
+                        rng.gen_range(0..grid.width() - STRUCTURE_SIZE),
+                        rng.gen_range(0..grid.height() - STRUCTURE_SIZE),
+                    )
+                })
+                .find(|corner| is_open_ground(grid, *corner));
+            if let Some(corner) = spot {
+                place_ruin(grid, corner, rng);
+                placed += 1;
+            }
+        }
+        info!("Placed {} of {} structures", placed, STRUCTURE_COUNT);
+    }
+}
+
+/// Every cell under the structure (and a tile around it) is plain walkable
+/// ground no other pass has claimed, away from spawn at the map centre.
+fn is_open_ground(grid: &TileGrid, corner: UVec2) -> bool {
+    let center = IVec2::new(grid.width() as i32, grid.height() as i32) / 2;
+    let min = corner.as_ivec2() - IVec2::ONE;
+    let max = corner.as_ivec2() + IVec2::splat(STRUCTURE_SIZE as i32);
+    if (min.x..=max.x).contains(&center.x) && (min.y..=max.y).contains(&center.y) {
+        return false;
+    }
+
+    (min.y..=max.y).all(|y| {
+        (min.x..=max.x).all(|x| {
+            let ground = matches!(
+                grid.get(x, y),
+                Some(TileType::Grass | TileType::YellowGrass | TileType::Dirt)
+            );
+            ground && !grid.is_painted(UVec2::new(x as u32, y as u32))
+        })
+    })
+}
+
+fn place_ruin(grid: &mut TileGrid, corner: UVec2, rng: &mut StdRng) {
+    let last = STRUCTURE_SIZE - 1;
+    let middle = STRUCTURE_SIZE / 2;
+
+    for dy in 0..STRUCTURE_SIZE {
+        for dx in 0..STRUCTURE_SIZE {
+            let mut layers = vec![SpawnableAsset::new("dirt").with_tile_type(TileType::Dirt)];
+            let on_wall = dx == 0 || dy == 0 || dx == last || dy == last;
+            let doorway = dy == 0 && dx == middle;
+
+            if on_wall && !doorway {
+                let sprite = WALL_SPRITES[rng.gen_range(0..WALL_SPRITES.len())];
+                layers.push(SpawnableAsset::new(sprite).with_tile_type(TileType::Rock));
+            } else if dx == middle && dy == middle {
+                layers.push(
+                    SpawnableAsset::new("plant_1")
+                        .with_tile_type(TileType::Grass)
+                        .with_pickable(ItemKind::Plant1),
+                );
+            }
+            grid.paint(corner + UVec2::new(dx, dy), layers);
+        }
+    }
+    // The open ground check keeps a free tile around the ruin
+    grid.mark_structure(UVec2::new(corner.x + middle, corner.y - 1));
+}
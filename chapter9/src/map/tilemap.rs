@@ -1,5 +1,25 @@
+use std::sync::RwLock;
+
 use bevy::math::{URect, UVec2};
 
+/// Names of the sprites content packs add, packed into the atlas below the
+/// built-in ones; index `i` here is atlas index `TILEMAP.sprites.len() + i`.
+static PACK_SPRITES: RwLock<Vec<&'static str>> = RwLock::new(Vec::new());
+
+/// Replaces the pack sprites the atlas holds, in atlas order.
+pub fn register_pack_sprites(names: &[String]) {
+    let mut sprites = PACK_SPRITES.write().expect("pack sprite registry poisoned");
+    // Reuse the leaked names a reload kept, so only new ones leak
+    let registered: Vec<&'static str> = names
+        .iter()
+        .map(|name| match sprites.iter().find(|old| **old == name.as_str()) {
+            Some(old) => *old,
+            None => name.clone().leak(),
+        })
+        .collect();
+    *sprites = registered;
+}
+
 pub struct TilemapSprite {
     pub name: &'static str,
     pub pixel_x: u32,
@@ -23,13 +43,51 @@ impl TilemapDefinition {
         UVec2::new(self.atlas_width, self.atlas_height)
     }
 
+    /// Index of a built-in or pack sprite.
     pub fn sprite_index(&self, name: &str) -> Option<usize> {
-        self.sprites.iter().position(|sprite| sprite.name == name)
+        self.sprites
+            .iter()
+            .position(|sprite| sprite.name == name)
+            .or_else(|| {
+                let pack = PACK_SPRITES.read().expect("pack sprite registry poisoned");
+                let index = pack.iter().position(|pack_sprite| *pack_sprite == name)?;
+                Some(self.sprites.len() + index)
+            })
+    }
+
+    pub fn sprite_name(&self, index: usize) -> Option<&'static str> {
+        match self.sprites.get(index) {
+            Some(sprite) => Some(sprite.name),
+            None => {
+                let pack = PACK_SPRITES.read().expect("pack sprite registry poisoned");
+                pack.get(index - self.sprites.len()).copied()
+            }
+        }
+    }
+
+    /// Built-in plus pack sprites.
+    pub fn sprite_count(&self) -> usize {
+        self.sprites.len() + PACK_SPRITES.read().expect("pack sprite registry poisoned").len()
+    }
+
+    /// Size of the atlas once the pack sprites are packed in, a row of
+    /// tiles at a time below the built-in ones.
+    pub fn packed_atlas_size(&self) -> UVec2 {
+        let pack_sprites = (self.sprite_count() - self.sprites.len()) as u32;
+        let rows = pack_sprites.div_ceil(self.atlas_width / self.tile_width);
+        UVec2::new(self.atlas_width, self.atlas_height + rows * self.tile_height)
     }
 
     pub fn sprite_rect(&self, index: usize) -> URect {
-        let sprite = &self.sprites[index];
-        let min = UVec2::new(sprite.pixel_x, sprite.pixel_y);
+        let min = match self.sprites.get(index) {
+            Some(sprite) => UVec2::new(sprite.pixel_x, sprite.pixel_y),
+            None => {
+                let columns = (self.atlas_width / self.tile_width) as usize;
+                let pack_index = index - self.sprites.len();
+                let cell = UVec2::new((pack_index % columns) as u32, (pack_index / columns) as u32);
+                UVec2::new(0, self.atlas_height) + cell * self.tile_size()
+            }
+        };
         URect::from_corners(min, min + self.tile_size())
     }
 }
@@ -38,7 +96,7 @@ pub const TILEMAP: TilemapDefinition = TilemapDefinition {
     tile_width: 32,
     tile_height: 32,
     atlas_width: 256,
-    atlas_height: 320,
+    atlas_height: 352,
     sprites: &[
           TilemapSprite {
             name: "dirt",
@@ -300,6 +358,59 @@ pub const TILEMAP: TilemapDefinition = TilemapDefinition {
             pixel_x: 224,
             pixel_y: 64,
         },
+        // Resource nodes share the bush and reed plant cells; their depleted
+        // states are withered copies in the bottom row
+        TilemapSprite {
+            name: "berry_bush",
+            pixel_x: 224,
+            pixel_y: 64,
+        },
+        TilemapSprite {
+            name: "berry_bush_depleted",
+            pixel_x: 0,
+            pixel_y: 320,
+        },
+        TilemapSprite {
+            name: "reeds",
+            pixel_x: 160,
+            pixel_y: 64,
+        },
+        TilemapSprite {
+            name: "reeds_depleted",
+            pixel_x: 32,
+            pixel_y: 320,
+        },
+        TilemapSprite {
+            name: "thorns",
+            pixel_x: 128,
+            pixel_y: 64,
+        },
+        // Farmland: furrowed dirt and wheat growing from seedling to harvest
+        TilemapSprite {
+            name: "tilled_soil",
+            pixel_x: 64,
+            pixel_y: 320,
+        },
+        TilemapSprite {
+            name: "crop_seedling",
+            pixel_x: 96,
+            pixel_y: 320,
+        },
+        TilemapSprite {
+            name: "crop_sprout",
+            pixel_x: 128,
+            pixel_y: 320,
+        },
+        TilemapSprite {
+            name: "crop_mature",
+            pixel_x: 160,
+            pixel_y: 320,
+        },
+        TilemapSprite {
+            name: "mud",
+            pixel_x: 128,
+            pixel_y: 0,
+        },
         TilemapSprite {
             name: "rock_1",
             pixel_x: 0,
@@ -0,0 +1,55 @@
+// src/map/world_id.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Which pass placed an entity; keeps ids from different passes from colliding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum IdSpace {
+    /// Spawned by the WFC generator
+    Generated = 0,
+    /// Placed on shore tiles after the collision map is built
+    Shore = 1,
+    /// Painted over the generated map by a `MapPostProcessor`
+    PostProcess = 2,
+}
+
+/// Stable identifier for a world entity.
+///
+/// Derived from where the entity was placed rather than from its `Entity`,
+/// so the same map seed always hands out the same ids.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct WorldEntityId(pub u64);
+
+impl WorldEntityId {
+    fn pack(space: IdSpace, high: u32, low: u32) -> Self {
+        Self(((space as u64) << 56) | ((high as u64 & 0x00FF_FFFF) << 32) | low as u64)
+    }
+
+    /// Id for an asset spawned by the generator at a node of a chunk.
+    pub fn generated(chunk_x: u32, chunk_y: u32, node_index: usize, asset_index: usize) -> Self {
+        let chunk = (chunk_y << 12) | chunk_x;
+        let node = ((node_index as u32) << 8) | (asset_index as u32 & 0xFF);
+        Self::pack(IdSpace::Generated, chunk, node)
+    }
+
+    /// Chunk an id from `generated` belongs to, or `None` for ids from other passes.
+    pub fn generated_chunk(&self) -> Option<UVec2> {
+        if (self.0 >> 56) as u8 != IdSpace::Generated as u8 {
+            return None;
+        }
+        let chunk = ((self.0 >> 32) & 0x00FF_FFFF) as u32;
+        Some(UVec2::new(chunk & 0xFFF, chunk >> 12))
+    }
+
+    /// Id for an entity placed on a collision map tile by a post-generation pass.
+    pub fn tile(space: IdSpace, x: i32, y: i32) -> Self {
+        Self::pack(space, x as u32, y as u32)
+    }
+
+    /// Id for one layer of a cell painted by a post-processing pass.
+    pub fn painted(cell: UVec2, layer: usize) -> Self {
+        Self::pack(IdSpace::PostProcess, ((layer as u32 & 0xFF) << 16) | cell.x, cell.y)
+    }
+}
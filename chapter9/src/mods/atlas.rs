@@ -0,0 +1,122 @@
+// src/mods/atlas.rs
+use bevy::prelude::*;
+
+use super::pack::ContentPacks;
+use crate::map::assets::TilemapHandles;
+use crate::map::tilemap::TILEMAP;
+
+/// The pack sprite images, in atlas order, until they are packed in.
+#[derive(Resource, Default)]
+pub struct PackSpriteImages(Vec<Handle<Image>>);
+
+pub fn load_pack_sprites(asset_server: &AssetServer, packs: &ContentPacks) -> PackSpriteImages {
+    PackSpriteImages(
+        packs
+            .sprites
+            .iter()
+            .map(|sprite| asset_server.load(sprite.path.clone()))
+            .collect(),
+    )
+}
+
+pub fn start_loading_pack_sprites(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    packs: Res<ContentPacks>,
+) {
+    commands.insert_resource(load_pack_sprites(&asset_server, &packs));
+}
+
+/// Run condition: the atlas holds every pack sprite, so its size matches
+/// `TILEMAP.packed_atlas_size` and sprite rects can be turned into UVs.
+pub fn atlas_packed(handles: Option<Res<TilemapHandles>>, images: Res<Assets<Image>>) -> bool {
+    handles
+        .and_then(|handles| images.get(&handles.image))
+        .is_some_and(|atlas| atlas.size() == TILEMAP.packed_atlas_size())
+}
+
+/// Copies the pack sprites into the rows `TILEMAP.sprite_rect` puts them
+/// in, below the built-in sprites, once the atlas and every pack image have
+/// loaded. Runs again whenever the atlas is reloaded from disk.
+pub fn pack_sprites_into_atlas(
+    asset_server: Res<AssetServer>,
+    handles: Option<Res<TilemapHandles>>,
+    packs: Res<ContentPacks>,
+    sprites: Res<PackSpriteImages>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let Some(handles) = handles else {
+        return;
+    };
+    let Some(atlas) = images.get(&handles.image) else {
+        return;
+    };
+    // Already packed, or nothing to pack
+    if sprites.0.is_empty() || atlas.size() != TILEMAP.atlas_size() {
+        return;
+    }
+    let still_loading = sprites
+        .0
+        .iter()
+        .any(|sprite| images.get(sprite).is_none() && !asset_server.load_state(sprite).is_failed());
+    if still_loading {
+        return;
+    }
+
+    let format = atlas.texture_descriptor.format;
+    let Some(bytes_per_pixel) = atlas
+        .data
+        .as_ref()
+        .map(|data| data.len() / (atlas.width() * atlas.height()) as usize)
+    else {
+        warn!("The tilemap atlas has no pixel data to pack sprites into");
+        return;
+    };
+    let tile_row = TILEMAP.tile_width as usize * bytes_per_pixel;
+    let atlas_row = TILEMAP.atlas_width as usize * bytes_per_pixel;
+
+    // Failed images were already reported by the asset server; their
+    // cells stay transparent
+    let mut blits = Vec::new();
+    for (index, (sprite, pack_sprite)) in sprites.0.iter().zip(&packs.sprites).enumerate() {
+        let Some(image) = images.get(sprite) else {
+            continue;
+        };
+        match image.data.as_ref() {
+            Some(pixels)
+                if image.size() == TILEMAP.tile_size()
+                    && image.texture_descriptor.format == format =>
+            {
+                blits.push((
+                    TILEMAP.sprite_rect(TILEMAP.sprites.len() + index).min,
+                    pixels.clone(),
+                ));
+            }
+            _ => warn!(
+                "Pack sprite '{}' must be a {}x{} 8-bit RGBA image, left blank",
+                pack_sprite.name, TILEMAP.tile_width, TILEMAP.tile_height
+            ),
+        }
+    }
+
+    let Some(atlas) = images.get_mut(&handles.image) else {
+        return;
+    };
+    let size = TILEMAP.packed_atlas_size();
+    let Some(data) = atlas.data.as_mut() else {
+        return;
+    };
+    // Same width, so growing the data only adds rows at the bottom
+    data.resize(size.y as usize * atlas_row, 0);
+    for (min, pixels) in &blits {
+        for (y, row) in pixels.chunks_exact(tile_row).enumerate() {
+            let start = (min.y as usize + y) * atlas_row + min.x as usize * bytes_per_pixel;
+            data[start..start + tile_row].copy_from_slice(row);
+        }
+    }
+    atlas.texture_descriptor.size.height = size.y;
+    info!(
+        "Packed {} content pack sprites into the tilemap atlas",
+        blits.len()
+    );
+}
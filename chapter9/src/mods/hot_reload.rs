@@ -0,0 +1,111 @@
+// src/mods/hot_reload.rs
+use bevy::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+
+use super::atlas::load_pack_sprites;
+use super::pack::{ContentPacks, PackManifest};
+use crate::config::mods::{MODS_ASSET_SOURCE, PACK_MANIFEST};
+use crate::map::assets::TilemapHandles;
+use crate::map::generate::MapSeed;
+use crate::save::{GameSnapshot, PendingLoad};
+use crate::state::GameState;
+use crate::ui::ShowToast;
+
+/// `dev`-feature plugin that watches content pack manifests: saving one while
+/// playing rescans the packs and regenerates the map from the same seed.
+///
+/// Added after `DefaultPlugins`, unlike `ModsPlugin`, since it loads the
+/// manifests as assets.
+pub struct PackHotReloadPlugin;
+
+impl Plugin for PackHotReloadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RonAssetPlugin::<PackManifest>::new(&["pack.ron"]))
+            .add_systems(Startup, watch_pack_manifests)
+            .add_systems(Update, reload_changed_packs);
+    }
+}
+
+/// Handles to every loaded pack's manifest; holding them keeps the asset
+/// server watching the files.
+#[derive(Resource, Default)]
+pub struct WatchedManifests(Vec<Handle<PackManifest>>);
+
+fn load_manifests(asset_server: &AssetServer, packs: &ContentPacks) -> WatchedManifests {
+    WatchedManifests(
+        packs
+            .dirs
+            .iter()
+            .map(|dir| asset_server.load(format!("{MODS_ASSET_SOURCE}://{dir}/{PACK_MANIFEST}")))
+            .collect(),
+    )
+}
+
+fn watch_pack_manifests(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    packs: Res<ContentPacks>,
+) {
+    commands.insert_resource(load_manifests(&asset_server, &packs));
+}
+
+/// Rescans the packs when a manifest is edited and, mid-game, regenerates
+/// the map from the same seed so the new props show up.
+///
+/// Going through `Loading` rebuilds the collision map and respawns the
+/// player, the same as loading a save; the game is snapshotted into a
+/// `PendingLoad` first so the player's progress is restored on top. While
+/// the player is on a boat the reload waits until they step off, as saving
+/// does.
+fn reload_changed_packs(
+    mut commands: Commands,
+    mut events: MessageReader<AssetEvent<PackManifest>>,
+    mut reload_waiting: Local<bool>,
+    asset_server: Res<AssetServer>,
+    mut packs: ResMut<ContentPacks>,
+    tilemap_handles: Option<Res<TilemapHandles>>,
+    seed: Res<MapSeed>,
+    snapshot: GameSnapshot,
+    pending_load: Option<Res<PendingLoad>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let modified = events
+        .read()
+        .filter(|event| matches!(event, AssetEvent::Modified { .. }))
+        .count();
+    if modified > 0 {
+        *reload_waiting = true;
+    }
+    if !*reload_waiting {
+        return;
+    }
+    let playing = *state.get() == GameState::Playing;
+    if playing && snapshot.player_riding() {
+        if modified > 0 {
+            commands.trigger(ShowToast::new("Content packs will reload once you leave the boat"));
+        }
+        return;
+    }
+    *reload_waiting = false;
+
+    *packs = ContentPacks::load();
+    commands.insert_resource(load_manifests(&asset_server, &packs));
+    commands.insert_resource(load_pack_sprites(&asset_server, &packs));
+    // Start over from the atlas on disk, to pack in the new set of sprites
+    if let Some(path) = tilemap_handles.and_then(|handles| handles.image.path().cloned()) {
+        asset_server.reload(path);
+    }
+
+    if playing {
+        info!("Content pack changed, regenerating map with seed {}", seed.0);
+        // A load still being applied already holds the game to restore
+        if pending_load.is_none()
+            && let Some(data) = snapshot.take()
+        {
+            commands.insert_resource(PendingLoad(data));
+        }
+        commands.trigger(ShowToast::new("Content packs reloaded"));
+        next_state.set(GameState::Loading);
+    }
+}
@@ -0,0 +1,59 @@
+// src/mods/mod.rs
+mod atlas;
+mod pack;
+#[cfg(feature = "dev")]
+mod hot_reload;
+
+use bevy::asset::io::AssetSourceBuilder;
+use bevy::prelude::*;
+
+use crate::config::mods::{MODS_ASSET_SOURCE, MODS_DIR};
+
+pub use atlas::atlas_packed;
+pub use pack::{ContentPacks, PackProp};
+#[cfg(feature = "dev")]
+pub use hot_reload::PackHotReloadPlugin;
+
+/// Plugin that loads content packs from `mods/` at startup.
+///
+/// Each pack is a directory holding a `pack.ron` manifest and its sprites:
+///
+/// ```ron
+/// (
+///     name: "Wildflowers",
+///     sprites: { "bluebell": "bluebell.png" },
+///     items: {
+///         "bluebell": (name: "Bluebell", sprite: "bluebell", price: Some(3)),
+///     },
+///     props: [
+///         (sprite: "bluebell", tile_type: Grass, pickable: Some(Pack("bluebell")), weight: 0.01),
+///         (sprite: "rock_2", tile_type: Rock, weight: 0.004),
+///     ],
+/// )
+/// ```
+///
+/// Sprites are 32x32 RGBA images, packed into the tilemap atlas below the
+/// built-in sprites, so the rest of the game looks them up by name like any
+/// other. Items become `ItemKind::Pack` and go in the inventory, the shop
+/// and saves like built-in ones. Props become extra models on the
+/// generator's props layer; one on grass may drop a built-in plant or a
+/// pack item.
+///
+/// There's no crafting, so `recipes` are ignored with a warning.
+///
+/// Must be added before `DefaultPlugins`, since asset sources are fixed
+/// once `AssetPlugin` builds.
+pub struct ModsPlugin;
+
+impl Plugin for ModsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_asset_source(
+            MODS_ASSET_SOURCE,
+            AssetSourceBuilder::platform_default(MODS_DIR, None),
+        )
+        .insert_resource(ContentPacks::load())
+        .init_resource::<atlas::PackSpriteImages>()
+        .add_systems(Startup, atlas::start_loading_pack_sprites)
+        .add_systems(Update, atlas::pack_sprites_into_atlas);
+    }
+}
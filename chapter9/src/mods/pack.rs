@@ -0,0 +1,247 @@
+// src/mods/pack.rs
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use bevy::asset::io::file::FileAssetReader;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::collision::TileType;
+use crate::config::mods::{MODS_ASSET_SOURCE, MODS_DIR, PACK_MANIFEST};
+use crate::inventory::{clear_pack_items, define_pack_item, ItemKind};
+use crate::map::tilemap::{register_pack_sprites, TILEMAP};
+
+/// A pack's `pack.ron`, as written by the pack author.
+///
+/// Also loaded as an asset with the `dev` feature, only so the asset server
+/// reports when a manifest is edited.
+#[derive(Asset, TypePath, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PackManifest {
+    /// Display name; defaults to the directory name
+    name: String,
+    /// Sprite name to image file, relative to the pack directory
+    sprites: HashMap<String, String>,
+    /// Item key to definition; keys are shared by all packs
+    items: HashMap<String, ItemDefinition>,
+    props: Vec<PropDefinition>,
+    /// Not supported; only read so packs that ship them get a warning
+    recipes: Option<ron::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ItemDefinition {
+    name: String,
+    /// Icon, a pack sprite or a built-in one
+    sprite: String,
+    /// Coins a trader charges for one; can't be traded without
+    #[serde(default)]
+    price: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PropDefinition {
+    sprite: String,
+    tile_type: TileType,
+    #[serde(default)]
+    pickable: Option<ItemKind>,
+    weight: f32,
+}
+
+/// A validated pack prop, ready to become a generator model.
+#[derive(Debug, Clone)]
+pub struct PackProp {
+    /// Atlas index, of a built-in sprite or one packed in from a pack
+    pub sprite: usize,
+    pub tile_type: TileType,
+    pub pickable: Option<ItemKind>,
+    pub weight: f32,
+}
+
+/// Everything the installed content packs add to the game.
+#[derive(Resource, Debug, Default)]
+pub struct ContentPacks {
+    /// Names of the packs that loaded, in load order
+    pub names: Vec<String>,
+    /// Directory names of those packs under `MODS_DIR`
+    pub dirs: Vec<String>,
+    /// Sprites to pack into the atlas, in atlas order after the built-in ones
+    pub sprites: Vec<PackSprite>,
+    /// How many items the packs defined (they live in `ItemKind::Pack`)
+    pub items: usize,
+    pub props: Vec<PackProp>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PackSprite {
+    pub name: String,
+    /// Asset path of the image, through the `mods://` source
+    pub path: String,
+}
+
+impl ContentPacks {
+    /// Scans `MODS_DIR` for packs; broken packs are skipped with a warning.
+    ///
+    /// Registers the pack items with `ItemKind` and the pack sprites with
+    /// `TILEMAP`, replacing those of an earlier scan.
+    pub fn load() -> Self {
+        let mut packs = Self::default();
+        clear_pack_items();
+        // Until the scan is done, name lookups only see built-in sprites
+        register_pack_sprites(&[]);
+        // Same base directory the `mods://` asset source reads from
+        let root = FileAssetReader::get_base_path().join(MODS_DIR);
+        let Ok(entries) = fs::read_dir(&root) else {
+            return packs;
+        };
+
+        let mut dirs: Vec<_> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.join(PACK_MANIFEST).is_file())
+            .collect();
+        // Load order decides model order, so keep it stable for seeded maps
+        dirs.sort();
+
+        for dir in dirs {
+            if let Err(err) = packs.load_pack(&dir) {
+                warn!("Skipping content pack {}: {}", dir.display(), err);
+            }
+        }
+        let sprite_names: Vec<String> =
+            packs.sprites.iter().map(|sprite| sprite.name.clone()).collect();
+        register_pack_sprites(&sprite_names);
+        if !packs.names.is_empty() {
+            info!(
+                "Loaded {} content pack(s) adding {} sprites, {} items and {} props: {}",
+                packs.names.len(),
+                packs.sprites.len(),
+                packs.items,
+                packs.props.len(),
+                packs.names.join(", ")
+            );
+        }
+        packs
+    }
+
+    /// Atlas index of a built-in sprite or one added by a pack loaded so far.
+    fn sprite_index(&self, name: &str) -> Option<usize> {
+        TILEMAP.sprite_index(name).or_else(|| {
+            let index = self.sprites.iter().position(|sprite| sprite.name == name)?;
+            Some(TILEMAP.sprites.len() + index)
+        })
+    }
+
+    /// The pack item a prop with this atlas sprite can be picked up as.
+    pub fn pickable_with_sprite(&self, atlas_index: usize) -> Option<ItemKind> {
+        self.props.iter().find_map(|prop| match prop.pickable {
+            Some(kind @ ItemKind::Pack(_)) if prop.sprite == atlas_index => Some(kind),
+            _ => None,
+        })
+    }
+
+    fn load_pack(&mut self, dir: &Path) -> Result<(), String> {
+        let dir_name = dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or("directory name is not valid UTF-8")?;
+        let text = fs::read_to_string(dir.join(PACK_MANIFEST)).map_err(|err| err.to_string())?;
+        let manifest: PackManifest = ron::from_str(&text).map_err(|err| err.to_string())?;
+        let name = if manifest.name.is_empty() {
+            dir_name.to_string()
+        } else {
+            manifest.name
+        };
+        if manifest.recipes.is_some() {
+            warn!("{}: packs can't add recipes, there is no crafting; ignored", name);
+        }
+
+        // Sorted so atlas order doesn't change between runs
+        let mut sprites: Vec<_> = manifest.sprites.into_iter().collect();
+        sprites.sort();
+        for (sprite, file) in sprites {
+            if self.sprite_index(&sprite).is_some() {
+                warn!("{}: sprite '{}' already exists, skipped", name, sprite);
+                continue;
+            }
+            self.sprites.push(PackSprite {
+                name: sprite,
+                path: format!("{MODS_ASSET_SOURCE}://{dir_name}/{file}"),
+            });
+        }
+
+        let mut items: Vec<_> = manifest.items.into_iter().collect();
+        items.sort_by(|a, b| a.0.cmp(&b.0));
+        for (key, item) in items {
+            if self.sprite_index(&item.sprite).is_none() {
+                warn!("{}: unknown sprite '{}', item '{}' skipped", name, item.sprite, key);
+                continue;
+            }
+            define_pack_item(&key, &item.name, &item.sprite, item.price);
+            self.items += 1;
+        }
+
+        for prop in manifest.props {
+            let Some(sprite) = self.sprite_index(&prop.sprite) else {
+                warn!("{}: unknown sprite '{}', prop skipped", name, prop.sprite);
+                continue;
+            };
+            if let Some(kind @ ItemKind::Pack(id)) = prop.pickable {
+                if !id.is_defined() {
+                    warn!("{}: unknown item '{}', prop '{}' skipped", name, id.key(), prop.sprite);
+                    continue;
+                }
+                // Pack pickables are told apart by their sprite once spawned
+                if self.pickable_with_sprite(sprite).is_some_and(|other| other != kind) {
+                    warn!(
+                        "{}: sprite '{}' already drops another item, prop skipped",
+                        name, prop.sprite
+                    );
+                    continue;
+                }
+            }
+            if !is_spawnable_pickable(prop.tile_type, prop.pickable) {
+                warn!(
+                    "{}: {:?} can't be picked up from {:?} tiles, prop '{}' skipped",
+                    name, prop.pickable, prop.tile_type, prop.sprite
+                );
+                continue;
+            }
+            if prop.weight <= 0.0 || !prop.weight.is_finite() {
+                warn!("{}: prop '{}' needs a positive weight", name, prop.sprite);
+                continue;
+            }
+
+            self.props.push(PackProp {
+                sprite,
+                tile_type: prop.tile_type,
+                pickable: prop.pickable,
+                weight: prop.weight,
+            });
+        }
+
+        self.names.push(name);
+        self.dirs.push(dir_name.to_string());
+        Ok(())
+    }
+}
+
+/// Pickups the map spawner knows how to attach: plants and pack items
+/// growing on grass.
+fn is_spawnable_pickable(tile_type: TileType, pickable: Option<ItemKind>) -> bool {
+    match pickable {
+        None => true,
+        Some(kind) => {
+            tile_type == TileType::Grass
+                && matches!(
+                    kind,
+                    ItemKind::Plant1
+                        | ItemKind::Plant2
+                        | ItemKind::Plant3
+                        | ItemKind::Plant4
+                        | ItemKind::Pack(_)
+                )
+        }
+    }
+}
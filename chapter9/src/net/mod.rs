@@ -0,0 +1,88 @@
+// src/net/mod.rs
+//! Two-player co-op: one side hosts, the other joins and adopts its map seed.
+//!
+//! Start the host with `--host [port]` and the client with `--join <addr>`.
+//!
+//! A joining client gets the host's world changes so far in `Welcome`; after
+//! that each side sends its own changes as numbered edits, resent until the
+//! peer acks them.
+mod protocol;
+mod reliable;
+mod session;
+mod systems;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+
+use crate::characters::spawn::PlayerSpawned;
+use crate::collision::CollisionMap;
+use crate::config::net::{HELLO_INTERVAL_SECS, RESEND_INTERVAL_SECS, SEND_INTERVAL_SECS};
+use crate::harvest::ShoreNodesSpawned;
+use crate::save::PendingLoad;
+use crate::state::GameState;
+
+pub use protocol::{NetMessage, WorldEdit};
+pub use session::{NetRole, NetSession};
+
+pub struct NetPlugin;
+
+impl Plugin for NetPlugin {
+    fn build(&self, app: &mut App) {
+        let session = match NetSession::from_args() {
+            Some(Ok(session)) => session,
+            Some(Err(err)) => {
+                error!("Failed to open network socket: {}", err);
+                return;
+            }
+            None => return,
+        };
+
+        app.insert_resource(session)
+            .init_resource::<systems::Inbox>()
+            .init_resource::<systems::RemoteTileEdits>()
+            .init_resource::<reliable::EditInbox>()
+            .init_resource::<reliable::EditOutbox>()
+            .add_observer(systems::forward_tile_changes)
+            .add_observer(systems::forward_placements)
+            .add_observer(systems::forward_pickups)
+            .add_systems(
+                Update,
+                (
+                    systems::receive_messages,
+                    // The snapshot in `Welcome` must describe a finished world
+                    systems::answer_hello
+                        .run_if(in_state(GameState::Playing))
+                        .run_if(not(resource_exists::<PendingLoad>)),
+                    systems::accept_welcome,
+                    systems::receive_edits,
+                    systems::send_hello
+                        .run_if(systems::awaiting_welcome)
+                        .run_if(on_timer(Duration::from_secs_f32(HELLO_INTERVAL_SECS))),
+                    systems::resend_edits
+                        .run_if(systems::connected)
+                        .run_if(on_timer(Duration::from_secs_f32(RESEND_INTERVAL_SECS))),
+                    (
+                        // Edits wait in `EditInbox` until the world they apply to exists
+                        (
+                            systems::apply_welcome_world
+                                .run_if(resource_exists::<systems::PendingWorld>),
+                            systems::apply_remote_edits
+                                .run_if(not(resource_exists::<systems::PendingWorld>)),
+                        )
+                            .chain()
+                            .run_if(resource_exists::<CollisionMap>)
+                            .run_if(resource_equals(PlayerSpawned(true)))
+                            .run_if(resource_equals(ShoreNodesSpawned(true))),
+                        systems::apply_remote_player,
+                        systems::smooth_remote_player,
+                        systems::send_player_position
+                            .run_if(on_timer(Duration::from_secs_f32(SEND_INTERVAL_SECS))),
+                    )
+                        .run_if(in_state(GameState::Playing)),
+                )
+                    .chain(),
+            );
+    }
+}
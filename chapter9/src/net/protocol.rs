@@ -0,0 +1,63 @@
+// src/net/protocol.rs
+use serde::{Deserialize, Serialize};
+
+use crate::collision::TileType;
+use crate::map::world_id::WorldEntityId;
+use crate::placement::PlaceableKind;
+use crate::save::WorldDiff;
+use crate::settings::Difficulty;
+
+/// Everything host and client say to each other, one message per datagram.
+///
+/// Both sides generate the world from the same seed, so only changes to it
+/// are sent, never the world itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum NetMessage {
+    /// Client asking to join; repeated until the host answers
+    Hello,
+    /// Host's answer, with the seed and difficulty to generate the shared
+    /// world from, what the host has changed in it so far, and the sequence
+    /// number of the first `Edit` the diff doesn't already cover
+    Welcome {
+        seed: u64,
+        difficulty: Difficulty,
+        world: WorldDiff,
+        next_edit: u32,
+    },
+    /// Sender's player moved; only the latest one matters, so lost ones are
+    /// never resent
+    PlayerMoved { position: [f32; 2] },
+    /// Sender changed the world. Numbered per sender and resent until acked
+    Edit { seq: u32, edit: WorldEdit },
+    /// Receiver has every `Edit` numbered below `next`
+    Ack { next: u32 },
+}
+
+/// One change to the shared world, applied on the receiving side through the
+/// same code path that made it on the sending side.
+///
+/// Edits describe the result rather than a toggle, so applying one twice is
+/// harmless.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum WorldEdit {
+    /// Soil tilled with the hoe
+    Tilled { cell: [i32; 2] },
+    /// Something put down from the hotbar
+    Placed { kind: PlaceableKind, cell: [i32; 2] },
+    /// A placed object taken away again, leaving `ground` under it
+    Unplaced { cell: [i32; 2], ground: TileType },
+    /// A ground tile swapped for another (debug tile undo/redo)
+    Repainted { cell: [i32; 2], to: TileType },
+    /// A generated pickable collected
+    PickedUp { id: WorldEntityId },
+}
+
+impl NetMessage {
+    pub fn encode(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("net messages always serialize")
+    }
+
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        serde_json::from_slice(bytes).ok()
+    }
+}
@@ -0,0 +1,88 @@
+// src/net/reliable.rs
+//! Sequence numbers and acks for world edits, which UDP may drop, repeat or
+//! reorder.
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+
+use super::{NetMessage, NetSession, WorldEdit};
+
+/// Our edits, numbered in the order we made them, kept until the peer acks.
+#[derive(Resource, Default)]
+pub struct EditOutbox {
+    next_seq: u32,
+    unacked: BTreeMap<u32, WorldEdit>,
+}
+
+impl EditOutbox {
+    /// Numbers `edit` and sends it; it is resent until acked.
+    pub fn send(&mut self, session: &NetSession, edit: WorldEdit) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        session.send(&NetMessage::Edit {
+            seq,
+            edit: edit.clone(),
+        });
+        self.unacked.insert(seq, edit);
+    }
+
+    /// The peer has every edit numbered below `next`.
+    pub fn ack(&mut self, next: u32) {
+        self.unacked = self.unacked.split_off(&next);
+    }
+
+    /// Number of the oldest edit the peer may not have.
+    pub fn first_unacked(&self) -> u32 {
+        self.unacked.keys().next().copied().unwrap_or(self.next_seq)
+    }
+
+    pub fn resend(&self, session: &NetSession) {
+        for (&seq, edit) in &self.unacked {
+            session.send(&NetMessage::Edit {
+                seq,
+                edit: edit.clone(),
+            });
+        }
+    }
+}
+
+/// The peer's edits, held until every earlier one has arrived and the world
+/// is ready to apply them.
+#[derive(Resource, Default)]
+pub struct EditInbox {
+    next_apply: u32,
+    received: BTreeMap<u32, WorldEdit>,
+}
+
+impl EditInbox {
+    /// An inbox whose first edit is numbered `seq`.
+    pub fn starting_at(seq: u32) -> Self {
+        Self {
+            next_apply: seq,
+            received: BTreeMap::new(),
+        }
+    }
+
+    /// Keeps `edit` unless it was applied already.
+    pub fn receive(&mut self, seq: u32, edit: WorldEdit) {
+        if seq >= self.next_apply {
+            self.received.entry(seq).or_insert(edit);
+        }
+    }
+
+    /// Number of the first edit still missing; what to ack.
+    pub fn next_missing(&self) -> u32 {
+        let mut seq = self.next_apply;
+        while self.received.contains_key(&seq) {
+            seq += 1;
+        }
+        seq
+    }
+
+    /// The next edit in order, if it has arrived.
+    pub fn pop(&mut self) -> Option<WorldEdit> {
+        let edit = self.received.remove(&self.next_apply)?;
+        self.next_apply += 1;
+        Some(edit)
+    }
+}
@@ -0,0 +1,118 @@
+// src/net/session.rs
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+
+use bevy::prelude::*;
+
+use super::NetMessage;
+use crate::config::net::{DEFAULT_PORT, MAX_DATAGRAM_SIZE};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetRole {
+    /// Owns the seed; waits for a client to say hello
+    Host,
+    /// Joins the host at this address
+    Client { host: SocketAddr },
+}
+
+/// The open socket and who is on the other end of it.
+#[derive(Resource)]
+pub struct NetSession {
+    socket: UdpSocket,
+    pub role: NetRole,
+    /// The other player; for a host, unknown until a client says hello
+    pub peer: Option<SocketAddr>,
+    /// Client only: the host has answered with its seed
+    pub welcomed: bool,
+}
+
+impl NetSession {
+    /// Opens a session from `--host [port]` or `--join <addr>`, or `None`
+    /// if neither was given.
+    pub fn from_args() -> Option<io::Result<Self>> {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        let value_after = |flag: &str| {
+            let at = args.iter().position(|arg| arg == flag)?;
+            Some(args.get(at + 1).filter(|next| !next.starts_with("--")).cloned())
+        };
+
+        if let Some(host) = value_after("--join") {
+            let Some(host) = host.and_then(|addr| addr.parse().ok()) else {
+                return Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "--join needs a host address like 192.168.1.20:7777",
+                )));
+            };
+            return Some(Self::open(0, NetRole::Client { host }));
+        }
+        if let Some(port) = value_after("--host") {
+            let port = port.and_then(|p| p.parse().ok()).unwrap_or(DEFAULT_PORT);
+            return Some(Self::open(port, NetRole::Host));
+        }
+        None
+    }
+
+    fn open(port: u16, role: NetRole) -> io::Result<Self> {
+        let socket = UdpSocket::bind(("0.0.0.0", port))?;
+        socket.set_nonblocking(true)?;
+        info!("Networking as {:?} on {}", role, socket.local_addr()?);
+
+        let peer = match role {
+            NetRole::Host => None,
+            NetRole::Client { host } => Some(host),
+        };
+        Ok(Self {
+            socket,
+            role,
+            peer,
+            welcomed: false,
+        })
+    }
+
+    /// Whether there is a peer to share the world with: a client the host
+    /// adopted, or a host that has welcomed us.
+    pub fn is_connected(&self) -> bool {
+        match self.role {
+            NetRole::Host => self.peer.is_some(),
+            NetRole::Client { .. } => self.welcomed,
+        }
+    }
+
+    /// Sends to the other player, if there is one yet. UDP gives no delivery
+    /// guarantee, so failures are only logged.
+    pub fn send(&self, message: &NetMessage) {
+        let Some(peer) = self.peer else {
+            return;
+        };
+        let bytes = message.encode();
+        if bytes.len() > MAX_DATAGRAM_SIZE {
+            error!(
+                "Not sending a {} byte message to {}, the limit is {}",
+                bytes.len(),
+                peer,
+                MAX_DATAGRAM_SIZE
+            );
+            return;
+        }
+        if let Err(err) = self.socket.send_to(&bytes, peer) {
+            debug!("Failed to send {:?} to {}: {}", message, peer, err);
+        }
+    }
+
+    /// Next datagram waiting on the socket, if any.
+    pub fn recv(&self, buffer: &mut [u8]) -> Option<(NetMessage, SocketAddr)> {
+        loop {
+            match self.socket.recv_from(buffer) {
+                Ok((len, from)) => match NetMessage::decode(&buffer[..len]) {
+                    Some(message) => return Some((message, from)),
+                    None => debug!("Ignoring malformed datagram from {}", from),
+                },
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => return None,
+                Err(err) => {
+                    debug!("Network receive failed: {}", err);
+                    return None;
+                }
+            }
+        }
+    }
+}
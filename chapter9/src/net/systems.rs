@@ -0,0 +1,373 @@
+// src/net/systems.rs
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use super::reliable::{EditInbox, EditOutbox};
+use super::{NetMessage, NetRole, NetSession, WorldEdit};
+use crate::characters::input::Player;
+use crate::collision::{CollisionMap, TileMarker, TileType};
+use crate::config::net::{MAX_DATAGRAM_SIZE, POSITION_EPSILON, REMOTE_LERP_SPEED};
+use crate::farming::{set_ground_tile, till_tile};
+use crate::inventory::{ItemPickedUp, Pickable};
+use crate::map::generate::MapSeed;
+use crate::map::world_id::WorldEntityId;
+use crate::map::TileChanged;
+use crate::placement::{remove_placed, spawn_placed, ObjectPlaced, PlacedObjects};
+use crate::rendering::YSort;
+use crate::save::{WorldChanges, WorldDiff, WorldRestorer};
+use crate::settings::Difficulty;
+use crate::state::{GameState, WorldScoped};
+
+const REMOTE_PLAYER_TINT: Color = Color::srgb(0.7, 0.85, 1.0);
+
+/// Messages received this frame, drained by the handlers below.
+#[derive(Resource, Default)]
+pub struct Inbox(Vec<NetMessage>);
+
+/// Cells whose `TileChanged` came from the peer, so it isn't sent back.
+#[derive(Resource, Default)]
+pub struct RemoteTileEdits(HashSet<IVec2>);
+
+/// Client only: the host's world diff from `Welcome`, replayed once our copy
+/// of the world has spawned.
+#[derive(Resource)]
+pub struct PendingWorld(WorldDiff);
+
+/// The other player, drawn with the local player's sprite.
+#[derive(Component)]
+pub struct RemotePlayer {
+    target: Vec2,
+}
+
+pub fn receive_messages(
+    mut session: ResMut<NetSession>,
+    mut inbox: ResMut<Inbox>,
+    mut edit_inbox: ResMut<EditInbox>,
+    mut outbox: ResMut<EditOutbox>,
+    mut buffer: Local<Vec<u8>>,
+) {
+    inbox.0.clear();
+    buffer.resize(MAX_DATAGRAM_SIZE, 0);
+    while let Some((message, from)) = session.recv(&mut buffer) {
+        // A host adopts whoever says hello first; everyone else is ignored
+        if session.role == NetRole::Host && session.peer.is_none() {
+            if matches!(message, NetMessage::Hello) {
+                info!("Player joined from {}", from);
+                session.peer = Some(from);
+                // Edits made before now reach the new peer in `Welcome`
+                *edit_inbox = EditInbox::default();
+                *outbox = EditOutbox::default();
+            } else {
+                continue;
+            }
+        }
+        if session.peer != Some(from) {
+            continue;
+        }
+        inbox.0.push(message);
+    }
+}
+
+/// Host: answers hellos with the seed, difficulty and everything changed in
+/// the world so far. Only runs once the host's own world is in place.
+pub fn answer_hello(
+    session: Res<NetSession>,
+    inbox: Res<Inbox>,
+    seed: Res<MapSeed>,
+    difficulty: Res<Difficulty>,
+    changes: WorldChanges,
+    outbox: Res<EditOutbox>,
+) {
+    if session.role != NetRole::Host
+        || !inbox.0.iter().any(|message| matches!(message, NetMessage::Hello))
+    {
+        return;
+    }
+    session.send(&NetMessage::Welcome {
+        seed: seed.0,
+        difficulty: *difficulty,
+        world: changes.snapshot(),
+        next_edit: outbox.first_unacked(),
+    });
+}
+
+/// Client: takes the host's seed and difficulty and starts generating the
+/// shared world; the host's changes are replayed once it has spawned.
+pub fn accept_welcome(
+    mut commands: Commands,
+    mut session: ResMut<NetSession>,
+    inbox: Res<Inbox>,
+    mut edit_inbox: ResMut<EditInbox>,
+    mut seed: ResMut<MapSeed>,
+    mut difficulty: ResMut<Difficulty>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if session.welcomed {
+        return;
+    }
+    for message in &inbox.0 {
+        let NetMessage::Welcome {
+            seed: host_seed,
+            difficulty: host_difficulty,
+            world,
+            next_edit,
+        } = message
+        else {
+            continue;
+        };
+        info!(
+            "Joined host, generating world with seed {} on {}",
+            host_seed,
+            host_difficulty.label()
+        );
+        session.welcomed = true;
+        seed.0 = *host_seed;
+        *difficulty = *host_difficulty;
+        *edit_inbox = EditInbox::starting_at(*next_edit);
+        commands.insert_resource(PendingWorld(world.clone()));
+        if *state.get() != GameState::Loading {
+            next_state.set(GameState::Loading);
+        }
+        return;
+    }
+}
+
+pub fn awaiting_welcome(session: Res<NetSession>) -> bool {
+    matches!(session.role, NetRole::Client { .. }) && !session.welcomed
+}
+
+pub fn connected(session: Res<NetSession>) -> bool {
+    session.is_connected()
+}
+
+pub fn send_hello(session: Res<NetSession>) {
+    session.send(&NetMessage::Hello);
+}
+
+/// Files the peer's edits in order and acks them, and drops our own edits
+/// the peer has acked.
+pub fn receive_edits(
+    session: Res<NetSession>,
+    inbox: Res<Inbox>,
+    mut edit_inbox: ResMut<EditInbox>,
+    mut outbox: ResMut<EditOutbox>,
+) {
+    // Before `Welcome` a client doesn't know where the host's numbering starts
+    if !session.is_connected() {
+        return;
+    }
+    let mut received = false;
+    for message in &inbox.0 {
+        match message {
+            NetMessage::Edit { seq, edit } => {
+                edit_inbox.receive(*seq, edit.clone());
+                received = true;
+            }
+            NetMessage::Ack { next } => outbox.ack(*next),
+            _ => {}
+        }
+    }
+    // Acked even for repeats, in case the previous ack was the one lost
+    if received {
+        session.send(&NetMessage::Ack {
+            next: edit_inbox.next_missing(),
+        });
+    }
+}
+
+pub fn resend_edits(session: Res<NetSession>, outbox: Res<EditOutbox>) {
+    outbox.resend(&session);
+}
+
+/// Sends our position when it has moved since the last update.
+pub fn send_player_position(
+    session: Res<NetSession>,
+    player: Query<&Transform, With<Player>>,
+    mut last_sent: Local<Option<Vec2>>,
+) {
+    let Ok(transform) = player.single() else {
+        return;
+    };
+    let position = transform.translation.truncate();
+    if last_sent.is_some_and(|last| last.distance(position) < POSITION_EPSILON) {
+        return;
+    }
+    session.send(&NetMessage::PlayerMoved {
+        position: position.to_array(),
+    });
+    *last_sent = Some(position);
+}
+
+/// Moves the remote player toward the latest received position, spawning
+/// it on first contact.
+pub fn apply_remote_player(
+    mut commands: Commands,
+    inbox: Res<Inbox>,
+    mut remote: Query<&mut RemotePlayer>,
+    local: Query<(&Sprite, &Transform), With<Player>>,
+) {
+    let Some(position) = inbox.0.iter().rev().find_map(|message| match message {
+        NetMessage::PlayerMoved { position } => Some(Vec2::from_array(*position)),
+        _ => None,
+    }) else {
+        return;
+    };
+
+    if let Ok(mut remote) = remote.single_mut() {
+        remote.target = position;
+        return;
+    }
+    let Ok((sprite, transform)) = local.single() else {
+        return;
+    };
+    commands.spawn((
+        RemotePlayer { target: position },
+        Sprite {
+            color: REMOTE_PLAYER_TINT,
+            ..sprite.clone()
+        },
+        Transform::from_translation(position.extend(transform.translation.z))
+            .with_scale(transform.scale),
+        YSort::new(0.0),
+        WorldScoped,
+    ));
+}
+
+pub fn smooth_remote_player(time: Res<Time>, mut remote: Query<(&RemotePlayer, &mut Transform)>) {
+    let t = (REMOTE_LERP_SPEED * time.delta_secs()).clamp(0.0, 1.0);
+    for (remote, mut transform) in remote.iter_mut() {
+        let position = transform.translation.truncate().lerp(remote.target, t);
+        transform.translation = position.extend(transform.translation.z);
+    }
+}
+
+pub fn forward_tile_changes(
+    changed: On<TileChanged>,
+    session: Res<NetSession>,
+    mut outbox: ResMut<EditOutbox>,
+    mut remote_edits: ResMut<RemoteTileEdits>,
+) {
+    if remote_edits.0.remove(&changed.cell) || !session.is_connected() {
+        return;
+    }
+    let cell = changed.cell.to_array();
+    let edit = match (changed.from, changed.to) {
+        (TileType::Fence, ground) => WorldEdit::Unplaced { cell, ground },
+        (_, TileType::TilledSoil) => WorldEdit::Tilled { cell },
+        // Sent as `ObjectPlaced`, which covers every placeable
+        (_, TileType::Fence) => return,
+        (_, to) => WorldEdit::Repainted { cell, to },
+    };
+    outbox.send(&session, edit);
+}
+
+pub fn forward_placements(
+    placed: On<ObjectPlaced>,
+    session: Res<NetSession>,
+    mut outbox: ResMut<EditOutbox>,
+) {
+    if !session.is_connected() {
+        return;
+    }
+    outbox.send(
+        &session,
+        WorldEdit::Placed {
+            kind: placed.kind,
+            cell: placed.cell.to_array(),
+        },
+    );
+}
+
+pub fn forward_pickups(
+    picked: On<ItemPickedUp>,
+    session: Res<NetSession>,
+    mut outbox: ResMut<EditOutbox>,
+    ids: Query<&WorldEntityId>,
+) {
+    if !session.is_connected() {
+        return;
+    }
+    if let Ok(id) = ids.get(picked.entity) {
+        outbox.send(&session, WorldEdit::PickedUp { id: *id });
+    }
+}
+
+/// Client: replays the host's changes from `Welcome` onto our freshly
+/// generated copy of the world, before any later edit.
+pub fn apply_welcome_world(
+    mut commands: Commands,
+    pending: Res<PendingWorld>,
+    mut restorer: WorldRestorer,
+    mut diff: ResMut<WorldDiff>,
+    mut remote_edits: ResMut<RemoteTileEdits>,
+) {
+    let world = &pending.0;
+    if !restorer.restore(world) {
+        return;
+    }
+    // The host tilled these; don't tell it about them again
+    remote_edits
+        .0
+        .extend(world.tilled.iter().copied().map(IVec2::from_array));
+    *diff = world.clone();
+    commands.remove_resource::<PendingWorld>();
+}
+
+/// Replays the peer's edits on our copy of the world through the same
+/// functions that made them on theirs. Collected pickables go into the
+/// peer's inventory, not ours.
+pub fn apply_remote_edits(
+    mut commands: Commands,
+    mut edit_inbox: ResMut<EditInbox>,
+    mut map: ResMut<CollisionMap>,
+    mut placed: ResMut<PlacedObjects>,
+    mut ground_tiles: Query<(&mut TileMarker, &mut Sprite, &Transform), Without<YSort>>,
+    mut remote_edits: ResMut<RemoteTileEdits>,
+    mut diff: ResMut<WorldDiff>,
+    pickables: Query<(Entity, &WorldEntityId), With<Pickable>>,
+) {
+    while let Some(edit) = edit_inbox.pop() {
+        let changed = match edit {
+            WorldEdit::Tilled { cell } => {
+                let cell = IVec2::from_array(cell);
+                till_tile(&mut map, cell, &mut ground_tiles)
+                    .map(|from| (cell, from, TileType::TilledSoil))
+            }
+            WorldEdit::Placed { kind, cell } => {
+                let cell = IVec2::from_array(cell);
+                if placed.is_occupied(cell) {
+                    None
+                } else {
+                    spawn_placed(&mut commands, &mut map, &mut placed, kind, cell)
+                        .map(|from| (cell, from, TileType::Fence))
+                }
+            }
+            WorldEdit::Unplaced { cell, ground } => {
+                let cell = IVec2::from_array(cell);
+                let from = map.get_tile(cell.x, cell.y);
+                if remove_placed(&mut commands, &mut map, &mut placed, cell, ground) {
+                    from.filter(|&from| from != ground).map(|from| (cell, from, ground))
+                } else {
+                    None
+                }
+            }
+            WorldEdit::Repainted { cell, to } => {
+                let cell = IVec2::from_array(cell);
+                set_ground_tile(&mut map, cell, to, &mut ground_tiles).map(|from| (cell, from, to))
+            }
+            WorldEdit::PickedUp { id } => {
+                if let Some((entity, _)) = pickables.iter().find(|(_, other)| **other == id) {
+                    commands.entity(entity).despawn();
+                }
+                diff.removed.insert(id);
+                None
+            }
+        };
+        if let Some((cell, from, to)) = changed {
+            remote_edits.0.insert(cell);
+            commands.trigger(TileChanged { cell, from, to });
+        }
+    }
+}
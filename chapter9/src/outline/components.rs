@@ -0,0 +1,43 @@
+// src/outline/components.rs
+use bevy::prelude::*;
+
+use crate::config::outline::{DEFAULT_COLOR, DEFAULT_THICKNESS};
+
+/// Outlines the entity's sprite while present.
+///
+/// Any system can insert or remove it; changing the color or thickness
+/// restyles the outline in place.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Highlighted {
+    pub color: Color,
+    /// Outline width in sprite pixels
+    pub thickness: f32,
+}
+
+impl Default for Highlighted {
+    fn default() -> Self {
+        Self {
+            color: DEFAULT_COLOR,
+            thickness: DEFAULT_THICKNESS,
+        }
+    }
+}
+
+impl Highlighted {
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            ..default()
+        }
+    }
+
+    pub fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+}
+
+/// The outline quad drawn for a `Highlighted` entity, a child of it.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OutlineQuad(pub Entity);
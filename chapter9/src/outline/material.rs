@@ -0,0 +1,44 @@
+// src/outline/material.rs
+use bevy::{
+    prelude::*,
+    reflect::TypePath,
+    render::render_resource::{AsBindGroup, ShaderType},
+    shader::ShaderRef,
+    sprite_render::{AlphaMode2d, Material2d},
+};
+
+/// Uniform block read by `outline.wgsl`.
+#[derive(ShaderType, Debug, Clone, Copy)]
+pub struct OutlineParams {
+    pub color: LinearRgba,
+    /// Corners of the sprite inside its texture, in UV coordinates
+    pub uv_min: Vec2,
+    pub uv_max: Vec2,
+    /// Sprite size in texture pixels
+    pub size: Vec2,
+    /// Outline width in texture pixels
+    pub thickness: f32,
+}
+
+/// Draws a solid outline around the opaque pixels of a sprite.
+///
+/// Lives on a quad just in front of the sprite, `thickness` larger on every
+/// side, and samples the sprite's own texture to find its edges.
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+pub struct OutlineMaterial {
+    #[uniform(0)]
+    pub params: OutlineParams,
+    #[texture(1)]
+    #[sampler(2)]
+    pub texture: Handle<Image>,
+}
+
+impl Material2d for OutlineMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/outline.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        AlphaMode2d::Blend
+    }
+}
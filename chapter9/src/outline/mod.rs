@@ -0,0 +1,24 @@
+// src/outline/mod.rs
+mod components;
+mod material;
+mod systems;
+
+use bevy::{prelude::*, sprite_render::Material2dPlugin};
+
+pub use components::{Highlighted, OutlineQuad};
+pub use material::{OutlineMaterial, OutlineParams};
+
+/// Plugin for sprite outlines.
+///
+/// Inserting `Highlighted` on an entity with a `Sprite` draws an outline
+/// around its opaque pixels; removing it takes the outline away. Used for
+/// the interaction target and the debug tile inspector.
+pub struct OutlinePlugin;
+
+impl Plugin for OutlinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<OutlineMaterial>::default())
+            .add_observer(systems::remove_outline)
+            .add_systems(PostUpdate, systems::update_outlines);
+    }
+}
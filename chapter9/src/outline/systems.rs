@@ -0,0 +1,101 @@
+// src/outline/systems.rs
+use bevy::prelude::*;
+
+use super::{Highlighted, OutlineMaterial, OutlineParams, OutlineQuad};
+use crate::config::outline::OUTLINE_Z_OFFSET;
+
+/// Texture rect of a sprite, in pixels, and its image size.
+fn sprite_rect(
+    sprite: &Sprite,
+    images: &Assets<Image>,
+    layouts: &Assets<TextureAtlasLayout>,
+) -> Option<(Rect, Vec2)> {
+    let image_size = images.get(&sprite.image)?.size_f32();
+    let rect = match &sprite.texture_atlas {
+        Some(atlas) => {
+            let layout = layouts.get(&atlas.layout)?;
+            layout.textures.get(atlas.index)?.as_rect()
+        }
+        None => sprite.rect.unwrap_or(Rect::from_corners(Vec2::ZERO, image_size)),
+    };
+    Some((rect, image_size))
+}
+
+/// Spawns outline quads for newly highlighted sprites and restyles existing
+/// ones when their highlight or sprite changes.
+pub fn update_outlines(
+    mut commands: Commands,
+    highlighted: Query<
+        (Entity, &Highlighted, &Sprite, Option<&OutlineQuad>),
+        Or<(Changed<Highlighted>, Changed<Sprite>)>,
+    >,
+    quads: Query<&MeshMaterial2d<OutlineMaterial>>,
+    images: Res<Assets<Image>>,
+    layouts: Res<Assets<TextureAtlasLayout>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<OutlineMaterial>>,
+) {
+    for (entity, highlight, sprite, quad) in highlighted.iter() {
+        // Not loaded yet; `Changed<Sprite>` won't fire again, so keep the
+        // highlight marked changed and retry next frame
+        let Some((rect, image_size)) = sprite_rect(sprite, &images, &layouts) else {
+            commands.entity(entity).insert(*highlight);
+            continue;
+        };
+        let size = sprite.custom_size.unwrap_or(rect.size());
+        let (mut uv_min, mut uv_max) = (rect.min / image_size, rect.max / image_size);
+        if sprite.flip_x {
+            std::mem::swap(&mut uv_min.x, &mut uv_max.x);
+        }
+        if sprite.flip_y {
+            std::mem::swap(&mut uv_min.y, &mut uv_max.y);
+        }
+        let params = OutlineParams {
+            color: highlight.color.to_linear(),
+            uv_min,
+            uv_max,
+            size: rect.size(),
+            thickness: highlight.thickness,
+        };
+
+        let existing = quad
+            .and_then(|quad| quads.get(quad.0).ok())
+            .and_then(|handle| materials.get_mut(&handle.0));
+        if let Some(material) = existing {
+            material.params = params;
+            material.texture = sprite.image.clone();
+            continue;
+        }
+
+        // Custom sizes stretch the sprite, so stretch the outline to match
+        let padding = 2.0 * highlight.thickness * size / rect.size();
+        let quad = commands
+            .spawn((
+                Mesh2d(meshes.add(Rectangle::from_size(size + padding))),
+                MeshMaterial2d(materials.add(OutlineMaterial {
+                    params,
+                    texture: sprite.image.clone(),
+                })),
+                Transform::from_xyz(0.0, 0.0, OUTLINE_Z_OFFSET),
+                ChildOf(entity),
+            ))
+            .id();
+        commands.entity(entity).insert(OutlineQuad(quad));
+    }
+}
+
+/// Removes the outline quad when the highlight goes away.
+pub fn remove_outline(
+    remove: On<Remove, Highlighted>,
+    mut commands: Commands,
+    quads: Query<&OutlineQuad>,
+) {
+    let Ok(quad) = quads.get(remove.entity) else {
+        return;
+    };
+    // Already gone if the highlighted entity is being despawned with its children
+    if let Ok(mut quad) = commands.get_entity(quad.0) {
+        quad.try_despawn();
+    }
+    commands.entity(remove.entity).try_remove::<OutlineQuad>();
+}
@@ -0,0 +1,148 @@
+// src/particles/ambient.rs
+use bevy::prelude::*;
+use rand::Rng;
+
+use super::components::{EmissionShape, ParticleConfig};
+use super::material::ParticleMaterial;
+use super::systems::spawn_particle;
+use crate::camera::MainCamera;
+use crate::clock::TimeOfDay;
+use crate::collision::{CollisionMap, TileType};
+use crate::config::ambient::{
+    MAX_AMBIENT_PARTICLES, MIDDAY_END_HOUR, MIDDAY_START_HOUR, SAMPLES_PER_PASS,
+};
+use crate::rendering::camera_view;
+
+/// Marks particles spawned by the ambient layer, to cap how many exist.
+#[derive(Component)]
+pub struct AmbientParticle;
+
+/// Environmental effects picked from the terrain and time of day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmbientEffect {
+    /// Drifting over grass during the day
+    Pollen,
+    /// Near water at night
+    Fireflies,
+    /// Over dirt around midday
+    HeatShimmer,
+}
+
+impl AmbientEffect {
+    /// What, if anything, a tile gives off at this time of day.
+    pub fn for_tile(tile: TileType, clock: &TimeOfDay) -> Option<Self> {
+        let midday = (MIDDAY_START_HOUR..MIDDAY_END_HOUR).contains(&clock.hours);
+        match tile {
+            TileType::Grass | TileType::YellowGrass if !clock.is_night() => Some(AmbientEffect::Pollen),
+            TileType::Shore | TileType::Water if clock.is_night() => Some(AmbientEffect::Fireflies),
+            TileType::Dirt if midday => Some(AmbientEffect::HeatShimmer),
+            _ => None,
+        }
+    }
+
+    fn config(self) -> ParticleConfig {
+        match self {
+            AmbientEffect::Pollen => ParticleConfig {
+                lifetime: 4.0,
+                lifetime_variance: 1.0,
+                speed: 14.0,
+                speed_variance: 6.0,
+                direction: Vec3::X,
+                direction_variance: std::f32::consts::PI,
+                scale: 0.15,
+                scale_variance: 0.05,
+                color: Color::srgba(1.0, 0.95, 0.6, 0.7),
+                angular_velocity: 0.0,
+                angular_velocity_variance: 0.5,
+                acceleration: Vec3::new(0.0, 2.0, 0.0),
+                emission_shape: EmissionShape::Circle { radius: 24.0 },
+            },
+            AmbientEffect::Fireflies => ParticleConfig {
+                lifetime: 3.0,
+                lifetime_variance: 1.0,
+                speed: 10.0,
+                speed_variance: 5.0,
+                direction: Vec3::Y,
+                direction_variance: std::f32::consts::PI,
+                scale: 0.12,
+                scale_variance: 0.04,
+                color: Color::srgb(0.75, 1.0, 0.3),
+                angular_velocity: 0.0,
+                angular_velocity_variance: 0.5,
+                acceleration: Vec3::ZERO,
+                emission_shape: EmissionShape::Circle { radius: 32.0 },
+            },
+            AmbientEffect::HeatShimmer => ParticleConfig {
+                lifetime: 1.5,
+                lifetime_variance: 0.5,
+                speed: 20.0,
+                speed_variance: 5.0,
+                direction: Vec3::Y,
+                direction_variance: 0.2,
+                scale: 0.35,
+                scale_variance: 0.1,
+                color: Color::srgba(1.0, 0.97, 0.9, 0.12),
+                angular_velocity: 0.0,
+                angular_velocity_variance: 0.2,
+                acceleration: Vec3::ZERO,
+                emission_shape: EmissionShape::Circle { radius: 28.0 },
+            },
+        }
+    }
+}
+
+/// Samples a few random tiles inside the camera view and emits whatever
+/// ambient effect each one calls for, up to `MAX_AMBIENT_PARTICLES`.
+pub fn emit_ambient_particles(
+    mut commands: Commands,
+    clock: Res<TimeOfDay>,
+    collision_map: Option<Res<CollisionMap>>,
+    camera: Query<(&Transform, &Projection), With<MainCamera>>,
+    existing: Query<(), With<AmbientParticle>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ParticleMaterial>>,
+) {
+    let Some(map) = collision_map else {
+        return;
+    };
+    let Some(view) = camera
+        .single()
+        .ok()
+        .and_then(|(transform, projection)| camera_view(transform, projection))
+    else {
+        return;
+    };
+
+    let mut rng = rand::thread_rng();
+    let mut count = existing.iter().count();
+    for _ in 0..SAMPLES_PER_PASS {
+        if count >= MAX_AMBIENT_PARTICLES {
+            return;
+        }
+        let pos = Vec2::new(
+            rng.gen_range(view.min.x..view.max.x),
+            rng.gen_range(view.min.y..view.max.y),
+        );
+        let cell = map.world_to_grid(pos);
+        let Some(effect) = map
+            .get_tile(cell.x, cell.y)
+            .and_then(|tile| AmbientEffect::for_tile(tile, &clock))
+        else {
+            continue;
+        };
+
+        let origin = GlobalTransform::from_translation(pos.extend(0.0));
+        let particle = spawn_particle(
+            &mut commands,
+            &effect.config(),
+            &origin,
+            &mut rng,
+            &mut meshes,
+            &mut materials,
+            None,
+            0,
+        );
+        commands.entity(particle).insert(AmbientParticle);
+        count += 1;
+    }
+}
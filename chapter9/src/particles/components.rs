@@ -85,6 +85,17 @@ impl Particle {
     }
 }
 
+/// Ties an emitter, and every particle it spawns, to another entity:
+/// despawning that entity despawns them too.
+#[derive(Component, Clone, Copy)]
+#[relationship(relationship_target = OwnedParticles)]
+pub struct ParticleOf(pub Entity);
+
+/// Emitters and particles that vanish with this entity.
+#[derive(Component, Default)]
+#[relationship_target(relationship = ParticleOf, linked_spawn)]
+pub struct OwnedParticles(Vec<Entity>);
+
 #[derive(Component, Clone)]
 pub struct ParticleEmitter {
     pub spawn_timer: Timer,
@@ -1,9 +1,13 @@
+pub mod ambient;
 pub mod components;
 pub mod material;
 pub mod systems;
 
+use std::time::Duration;
+
+use crate::config::ambient::AMBIENT_INTERVAL_SECS;
 use crate::state::GameState;
-use bevy::{prelude::*, sprite_render::Material2dPlugin};
+use bevy::{prelude::*, sprite_render::Material2dPlugin, time::common_conditions::on_timer};
 
 pub use material::*;
 pub use systems::*;
@@ -19,6 +23,13 @@ impl Plugin for ParticlesPlugin {
                 (update_emitters, update_particles, cleanup_finished_emitters)
                     .chain()
                     .run_if(in_state(GameState::Playing)),
+            )
+            // Pollen, fireflies and heat shimmer around the camera view
+            .add_systems(
+                Update,
+                ambient::emit_ambient_particles
+                    .run_if(on_timer(Duration::from_secs_f32(AMBIENT_INTERVAL_SECS)))
+                    .run_if(in_state(GameState::Playing)),
             );
         info!("ParticlesPlugin initialized");
     }
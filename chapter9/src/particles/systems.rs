@@ -7,13 +7,13 @@ use rand::Rng;
 pub fn update_emitters(
     mut commands: Commands,
     time: Res<Time>,
-    mut emitters: Query<(Entity, &mut ParticleEmitter, &GlobalTransform)>,
+    mut emitters: Query<(Entity, &mut ParticleEmitter, &GlobalTransform, Option<&ParticleOf>)>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ParticleMaterial>>,
 ) {
     let mut rng = rand::thread_rng();
 
-    for (entity, mut emitter, global_transform) in emitters.iter_mut() {
+    for (entity, mut emitter, global_transform, particle_of) in emitters.iter_mut() {
         if !emitter.active {
             continue;
         }
@@ -31,7 +31,7 @@ pub fn update_emitters(
 
             // Spawn particles
             for i in 0..emitter.particles_per_spawn {
-                spawn_particle(
+                let particle = spawn_particle(
                     &mut commands,
                     &emitter.particle_config,
                     global_transform,
@@ -41,6 +41,9 @@ pub fn update_emitters(
                     Some(entity),
                     i,
                 );
+                if let Some(&particle_of) = particle_of {
+                    commands.entity(particle).insert(particle_of);
+                }
             }
 
             if emitter.one_shot {
@@ -59,7 +62,7 @@ pub fn spawn_particle(
     materials: &mut ResMut<Assets<ParticleMaterial>>,
     owner: Option<Entity>,
     _particle_index: u32,
-) {
+) -> Entity {
     // Calculate randomized values
     let lifetime =
         config.lifetime + rng.gen_range(-config.lifetime_variance..config.lifetime_variance);
@@ -130,12 +133,14 @@ pub fn spawn_particle(
     let mesh = meshes.add(Rectangle::new(size, size));
     let material = materials.add(ParticleMaterial::new(start_color));
 
-    commands.spawn((
-        particle,
-        Mesh2d(mesh),
-        MeshMaterial2d(material),
-        Transform::from_translation(position),
-    ));
+    commands
+        .spawn((
+            particle,
+            Mesh2d(mesh),
+            MeshMaterial2d(material),
+            Transform::from_translation(position),
+        ))
+        .id()
 }
 
 fn apply_direction_variance(
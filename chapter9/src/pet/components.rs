@@ -0,0 +1,93 @@
+// src/pet/components.rs
+use bevy::prelude::*;
+
+use crate::config::pet::{COLLECT_RADIUS, IDLE_LOOK_SECS, REPATH_SECS};
+
+/// The player's companion. It follows a step behind the player and, with
+/// `AutoCollect`, picks up items it walks past.
+#[derive(Component, Debug, Default)]
+#[require(PetPath, PetIdle)]
+pub struct Pet;
+
+/// The A* path the pet is walking toward its spot behind the player.
+#[derive(Component, Debug)]
+pub struct PetPath {
+    waypoints: Vec<Vec2>,
+    current: usize,
+    repath: Timer,
+}
+
+impl Default for PetPath {
+    fn default() -> Self {
+        Self {
+            waypoints: Vec::new(),
+            current: 0,
+            repath: Timer::from_seconds(REPATH_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+impl PetPath {
+    /// Distance at which a waypoint counts as reached
+    pub const WAYPOINT_THRESHOLD: f32 = 16.0;
+
+    /// Replace the path. The first waypoint is where the pet stands, so
+    /// it's skipped.
+    pub fn set(&mut self, waypoints: Vec<Vec2>) {
+        self.waypoints = waypoints;
+        self.current = 1.min(self.waypoints.len());
+    }
+
+    pub fn clear(&mut self) {
+        self.waypoints.clear();
+        self.current = 0;
+    }
+
+    /// Whether it's time to look for a new path.
+    pub fn needs_repath(&mut self, delta: std::time::Duration) -> bool {
+        self.repath.tick(delta).just_finished() || self.current >= self.waypoints.len()
+    }
+
+    /// The waypoint to head for from `position`, moving past reached ones.
+    pub fn next_waypoint(&mut self, position: Vec2) -> Option<Vec2> {
+        while let Some(&waypoint) = self.waypoints.get(self.current) {
+            if waypoint.distance(position) >= Self::WAYPOINT_THRESHOLD {
+                return Some(waypoint);
+            }
+            self.current += 1;
+        }
+        None
+    }
+}
+
+/// Idle fidgeting: looking around now and then and a slight breathing bob.
+#[derive(Component, Debug)]
+pub struct PetIdle {
+    pub look: Timer,
+    /// Seconds spent idle, the phase of the bob
+    pub elapsed: f32,
+}
+
+impl Default for PetIdle {
+    fn default() -> Self {
+        Self {
+            look: Timer::from_seconds(IDLE_LOOK_SECS, TimerMode::Repeating),
+            elapsed: 0.0,
+        }
+    }
+}
+
+/// Lets the pet collect `Pickable`s within `radius` into the player's
+/// inventory. Remove it to keep the pet from touching items.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AutoCollect {
+    pub radius: f32,
+}
+
+impl Default for AutoCollect {
+    fn default() -> Self {
+        Self {
+            radius: COLLECT_RADIUS,
+        }
+    }
+}
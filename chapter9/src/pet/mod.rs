@@ -0,0 +1,50 @@
+// src/pet/mod.rs
+mod components;
+mod spawn;
+mod systems;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+
+use crate::characters::input::handle_player_input;
+use crate::characters::spawn::PlayerSpawned;
+use crate::config::pet::COLLECT_INTERVAL_SECS;
+use crate::interaction::update_interaction_target;
+use crate::state::GameState;
+
+pub use components::{AutoCollect, Pet};
+
+/// Plugin for the pet that follows the player around.
+///
+/// The pet spawns next to the player, paths to a spot just behind them,
+/// catches up at a run and teleports back if left far behind. While
+/// waiting it fidgets, and with `AutoCollect` it gathers nearby items.
+pub struct PetPlugin;
+
+impl Plugin for PetPlugin {
+    fn build(&self, app: &mut App) {
+        // Fixed rate, like the player it follows
+        app.add_systems(
+            FixedUpdate,
+            (
+                spawn::spawn_pet
+                    .run_if(resource_equals(PlayerSpawned(true)))
+                    .run_if(not(any_with_component::<Pet>)),
+                systems::pet_follow_player,
+                systems::animate_pet_idle,
+            )
+                .chain()
+                .before(handle_player_input)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            FixedUpdate,
+            systems::pet_collect_pickups
+                .after(update_interaction_target)
+                .run_if(on_timer(Duration::from_secs_f32(COLLECT_INTERVAL_SECS)))
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
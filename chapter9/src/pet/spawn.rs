@@ -0,0 +1,90 @@
+// src/pet/spawn.rs
+use bevy::prelude::*;
+
+use super::components::{AutoCollect, Pet};
+use crate::characters::{
+    animation::{AnimationController, AnimationTimer, DEFAULT_ANIMATION_FRAME_TIME},
+    collider::Collider,
+    config::CharactersList,
+    facing::Facing,
+    input::Player,
+    physics::Velocity,
+    spawn::{create_character_atlas_layout, CharactersListResource},
+    state::CharacterState,
+};
+use crate::collision::CollisionMap;
+use crate::config::pet::{FOLLOW_DISTANCE, PET_CHARACTER, PET_COLLIDER_RADIUS, PET_SCALE};
+use crate::rendering::YSort;
+use crate::state::WorldScoped;
+
+/// Walkable spot nearest `position`: the position itself when it's clear,
+/// else the centre of the closest walkable cell.
+pub fn walkable_near(map: &CollisionMap, position: Vec2, radius: f32) -> Vec2 {
+    if map.is_circle_clear(position, radius) {
+        return position;
+    }
+    map.find_nearest_walkable(map.world_to_grid(position))
+        .map(|cell| map.grid_to_world(cell.x, cell.y))
+        .unwrap_or(position)
+}
+
+/// Spawns the pet behind the player once the player exists.
+pub fn spawn_pet(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    characters_lists: Res<Assets<CharactersList>>,
+    characters_list_res: Option<Res<CharactersListResource>>,
+    collision_map: Option<Res<CollisionMap>>,
+    player_query: Query<(&Transform, &Facing), With<Player>>,
+) {
+    let Some(collision_map) = collision_map else {
+        return;
+    };
+    let Ok((player_transform, facing)) = player_query.single() else {
+        return;
+    };
+    let Some(characters_list) = characters_list_res
+        .as_ref()
+        .and_then(|res| characters_lists.get(&res.handle))
+    else {
+        return;
+    };
+    let Some(character_entry) = characters_list
+        .characters
+        .iter()
+        .find(|character| character.name == PET_CHARACTER)
+    else {
+        warn!("Pet character '{}' not found", PET_CHARACTER);
+        return;
+    };
+
+    let behind = player_transform.translation.truncate() - facing.to_vec2() * FOLLOW_DISTANCE;
+    let position = walkable_near(&collision_map, behind, PET_COLLIDER_RADIUS);
+
+    let texture = asset_server.load(&character_entry.texture_path);
+    let layout = create_character_atlas_layout(&mut atlas_layouts, character_entry);
+    commands.spawn((
+        Pet,
+        AutoCollect::default(),
+        Sprite::from_atlas_image(texture, TextureAtlas { layout, index: 0 }),
+        Transform::from_translation(position.extend(player_transform.translation.z))
+            .with_scale(Vec3::splat(PET_SCALE)),
+        AnimationController::default(),
+        CharacterState::default(),
+        Velocity::default(),
+        *facing,
+        Collider {
+            radius: PET_COLLIDER_RADIUS,
+            ..default()
+        },
+        YSort::new(-(character_entry.tile_size as f32 * PET_SCALE) / 2.0),
+        AnimationTimer(Timer::from_seconds(
+            DEFAULT_ANIMATION_FRAME_TIME,
+            TimerMode::Repeating,
+        )),
+        character_entry.clone(),
+        WorldScoped,
+    ));
+    info!("Spawned pet '{}' at {:?}", PET_CHARACTER, position);
+}
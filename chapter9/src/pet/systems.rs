@@ -0,0 +1,148 @@
+// src/pet/systems.rs
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use rand::Rng;
+
+use super::components::{AutoCollect, Pet, PetIdle, PetPath};
+use super::spawn::walkable_near;
+use crate::characters::{
+    config::CharacterEntry,
+    facing::Facing,
+    input::Player,
+    physics::{calculate_velocity, Velocity},
+    state::CharacterState,
+};
+use crate::collision::CollisionMap;
+use crate::config::pet::{
+    ARRIVE_RADIUS, FOLLOW_DISTANCE, IDLE_BOB_PX, IDLE_BOB_SPEED, PET_COLLIDER_RADIUS,
+    RUN_DISTANCE, TELEPORT_DISTANCE,
+};
+use crate::interaction::InteractionTarget;
+use crate::map::generate::GameRng;
+use crate::inventory::{pick_up, Inventory, Pickable};
+
+/// Walks the pet to a spot behind the player, along an A* path, and
+/// teleports it there if it falls too far behind.
+pub fn pet_follow_player(
+    time: Res<Time>,
+    collision_map: Option<Res<CollisionMap>>,
+    player_query: Query<(&Transform, &Facing), (With<Player>, Without<Pet>)>,
+    mut pets: Query<
+        (
+            &mut Transform,
+            &mut CharacterState,
+            &mut Velocity,
+            &mut Facing,
+            &CharacterEntry,
+            &mut PetPath,
+        ),
+        (With<Pet>, Without<Player>),
+    >,
+) {
+    let Some(map) = collision_map else {
+        return;
+    };
+    let Ok((player_transform, player_facing)) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+    let behind = player_pos - player_facing.to_vec2() * FOLLOW_DISTANCE;
+    let target = walkable_near(&map, behind, PET_COLLIDER_RADIUS);
+
+    for (mut transform, mut state, mut velocity, mut facing, character, mut path) in pets.iter_mut() {
+        let position = transform.translation.truncate();
+
+        if position.distance(player_pos) > TELEPORT_DISTANCE {
+            transform.translation = target.extend(transform.translation.z);
+            path.clear();
+            state.set_if_neq(CharacterState::Idle);
+            *velocity = Velocity::ZERO;
+            facing.set_if_neq(*player_facing);
+            debug!("Pet fell behind, teleported to {:?}", target);
+            continue;
+        }
+
+        // Once settled, wait for the player to move off a little before
+        // following, so the pet doesn't shuffle back and forth
+        let arrive_radius = if *state == CharacterState::Idle {
+            ARRIVE_RADIUS * 2.0
+        } else {
+            ARRIVE_RADIUS
+        };
+        if position.distance(target) <= arrive_radius {
+            state.set_if_neq(CharacterState::Idle);
+            *velocity = Velocity::ZERO;
+            path.clear();
+            continue;
+        }
+
+        if path.needs_repath(time.delta()) {
+            match map.find_path(position, target) {
+                Some(waypoints) => path.set(waypoints),
+                None => path.clear(),
+            }
+        }
+        // Straight at the target when there's no path, or on its last stretch
+        let waypoint = path.next_waypoint(position).unwrap_or(target);
+        let direction = (waypoint - position).normalize_or_zero();
+
+        let moving = if position.distance(player_pos) > RUN_DISTANCE {
+            CharacterState::Running
+        } else {
+            CharacterState::Walking
+        };
+        state.set_if_neq(moving);
+        if direction != Vec2::ZERO {
+            facing.set_if_neq(Facing::from_velocity(direction));
+        }
+        *velocity = calculate_velocity(*state, direction, character);
+    }
+}
+
+/// While the pet stands still it breathes and now and then looks around.
+pub fn animate_pet_idle(
+    time: Res<Time>,
+    mut rng: ResMut<GameRng>,
+    mut pets: Query<(&CharacterState, &CharacterEntry, &mut Facing, &mut PetIdle, &mut Anchor), With<Pet>>,
+) {
+    for (state, character, mut facing, mut idle, mut anchor) in pets.iter_mut() {
+        if *state != CharacterState::Idle {
+            idle.elapsed = 0.0;
+            idle.look.reset();
+            anchor.0.y = 0.0;
+            continue;
+        }
+
+        idle.elapsed += time.delta_secs();
+        if idle.look.tick(time.delta()).just_finished() {
+            let directions = [Facing::Up, Facing::Left, Facing::Down, Facing::Right];
+            *facing = directions[rng.0.gen_range(0..directions.len())];
+        }
+
+        // The anchor is in sprite sizes; a lower anchor raises the sprite
+        let bob = (idle.elapsed * IDLE_BOB_SPEED).sin().max(0.0) * IDLE_BOB_PX;
+        anchor.0.y = -bob / character.tile_size as f32;
+    }
+}
+
+/// Pets with `AutoCollect` gather items around them into the inventory.
+pub fn pet_collect_pickups(
+    mut commands: Commands,
+    mut inventory: ResMut<Inventory>,
+    target: Res<InteractionTarget>,
+    pets: Query<(&Transform, &AutoCollect), With<Pet>>,
+    pickables: Query<(Entity, &Transform, &Pickable)>,
+) {
+    for (pet_transform, collect) in pets.iter() {
+        let pet_pos = pet_transform.translation.truncate();
+        for (entity, transform, pickable) in pickables.iter() {
+            // The player is picking that one up this frame already
+            if target.0 == Some(entity) {
+                continue;
+            }
+            if transform.translation.truncate().distance(pet_pos) <= collect.radius {
+                pick_up(&mut commands, &mut inventory, entity, pickable.kind);
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// src/placement/components.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::collision::CollisionLayer;
+use crate::config::map::TILE_SIZE;
+use crate::inventory::ItemKind;
+
+/// Objects the player can put down from the hotbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlaceableKind {
+    Campfire,
+    Fence,
+    Torch,
+    Boat,
+}
+
+impl PlaceableKind {
+    /// The placeable an inventory item turns into, if any.
+    pub fn from_item(item: ItemKind) -> Option<Self> {
+        match item {
+            ItemKind::Campfire => Some(PlaceableKind::Campfire),
+            ItemKind::Fence => Some(PlaceableKind::Fence),
+            ItemKind::Torch => Some(PlaceableKind::Torch),
+            ItemKind::Boat => Some(PlaceableKind::Boat),
+            _ => None,
+        }
+    }
+
+    /// Item consumed when placing one
+    pub fn item(&self) -> ItemKind {
+        match self {
+            PlaceableKind::Campfire => ItemKind::Campfire,
+            PlaceableKind::Fence => ItemKind::Fence,
+            PlaceableKind::Torch => ItemKind::Torch,
+            PlaceableKind::Boat => ItemKind::Boat,
+        }
+    }
+
+    /// Fences are written into the collision map; the others can be walked past.
+    pub fn blocks_movement(&self) -> bool {
+        matches!(self, PlaceableKind::Fence)
+    }
+
+    /// Tiles this can be put down on: boats go on the water, the rest on land.
+    pub fn layer(&self) -> CollisionLayer {
+        match self {
+            PlaceableKind::Boat => CollisionLayer::Water,
+            _ => CollisionLayer::Land,
+        }
+    }
+
+    /// Placeholder sprite color until placeables get their own art
+    pub fn color(&self) -> Color {
+        match self {
+            PlaceableKind::Campfire => Color::srgb(0.9, 0.4, 0.1),
+            PlaceableKind::Fence => Color::srgb(0.55, 0.35, 0.2),
+            PlaceableKind::Torch => Color::srgb(1.0, 0.85, 0.3),
+            PlaceableKind::Boat => Color::srgb(0.4, 0.24, 0.12),
+        }
+    }
+
+    /// Placeholder sprite size, in world units
+    pub fn size(&self) -> Vec2 {
+        match self {
+            PlaceableKind::Campfire => Vec2::splat(TILE_SIZE * 0.6),
+            PlaceableKind::Fence => Vec2::new(TILE_SIZE, TILE_SIZE * 0.5),
+            PlaceableKind::Torch => Vec2::new(TILE_SIZE * 0.15, TILE_SIZE * 0.5),
+            PlaceableKind::Boat => Vec2::new(TILE_SIZE * 0.9, TILE_SIZE * 0.45),
+        }
+    }
+}
+
+/// An object the player placed, and the cell it occupies.
+#[derive(Component, Debug)]
+pub struct Placed {
+    pub kind: PlaceableKind,
+    pub cell: IVec2,
+}
+
+/// Semi-transparent preview of where the selected placeable would go.
+#[derive(Component)]
+pub struct PlacementGhost;
+
+/// Cells taken by placed objects, on top of what the collision map knows.
+///
+/// Campfires and torches don't block movement, so the collision map has no
+/// record of them; this layer keeps two objects from sharing a cell.
+#[derive(Resource, Default)]
+pub struct PlacedObjects(HashMap<IVec2, Entity>);
+
+impl PlacedObjects {
+    pub fn is_occupied(&self, cell: IVec2) -> bool {
+        self.0.contains_key(&cell)
+    }
+
+    pub fn get(&self, cell: IVec2) -> Option<Entity> {
+        self.0.get(&cell).copied()
+    }
+
+    pub fn insert(&mut self, cell: IVec2, entity: Entity) {
+        self.0.insert(cell, entity);
+    }
+
+    pub fn remove(&mut self, cell: IVec2) {
+        self.0.remove(&cell);
+    }
+
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
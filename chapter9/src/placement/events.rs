@@ -0,0 +1,13 @@
+// src/placement/events.rs
+use bevy::prelude::*;
+
+use super::components::PlaceableKind;
+
+/// Triggered when the player puts something down. Only fences change the
+/// tile under them, so the others never show up as `TileChanged`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ObjectPlaced {
+    pub kind: PlaceableKind,
+    /// Collision map cell it was placed on
+    pub cell: IVec2,
+}
@@ -0,0 +1,38 @@
+// src/placement/mod.rs
+mod components;
+mod events;
+mod systems;
+
+use bevy::prelude::*;
+use crate::controls::{action_just_pressed, InputAction};
+use crate::state::GameState;
+
+pub use components::{PlaceableKind, Placed, PlacedObjects};
+pub use events::ObjectPlaced;
+pub use systems::{remove_placed, spawn_placed};
+
+/// Plugin for putting campfires, fences, torches and boats down from the hotbar.
+///
+/// With a placeable item selected, a ghost previews the tile in front of the
+/// player and use tool places it there.
+pub struct PlacementPlugin;
+
+impl Plugin for PlacementPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlacedObjects>()
+            .add_systems(
+                Update,
+                (
+                    systems::place_selected
+                        .run_if(action_just_pressed(InputAction::UseTool))
+                        .run_if(systems::placeable_selected),
+                    systems::update_placement_ghost.run_if(systems::placeable_selected),
+                    systems::despawn_placement_ghost.run_if(not(systems::placeable_selected)),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Placed objects are world-scoped and go away with the old world
+            .add_systems(OnEnter(GameState::Loading), systems::clear_placed_objects);
+    }
+}
@@ -0,0 +1,216 @@
+// src/placement/systems.rs
+use bevy::prelude::*;
+
+use super::components::{PlaceableKind, Placed, PlacedObjects, PlacementGhost};
+use super::events::ObjectPlaced;
+use crate::audio::{PlaySfx, SfxId};
+use crate::boat::Boat;
+use crate::characters::collider::Collider;
+use crate::characters::facing::Facing;
+use crate::characters::input::Player;
+use crate::collision::{CollisionMap, TileMarker, TileType};
+use crate::config::boat::{BOARD_RADIUS, BOAT_SORT_OFFSET};
+use crate::config::placement::{GHOST_ALPHA, GHOST_Z};
+use crate::interaction::{facing_cell, Interactable};
+use crate::inventory::{Hotbar, HotbarSlot, Inventory};
+use crate::map::TileChanged;
+use crate::rendering::YSort;
+use crate::state::WorldScoped;
+
+const BLOCKED_GHOST_COLOR: Color = Color::srgb(0.9, 0.15, 0.15);
+
+fn selected_placeable(hotbar: &Hotbar) -> Option<PlaceableKind> {
+    match hotbar.selected() {
+        HotbarSlot::Item(item) => PlaceableKind::from_item(item),
+        _ => None,
+    }
+}
+
+/// Run condition: true while the selected hotbar slot holds a placeable item.
+pub fn placeable_selected(hotbar: Res<Hotbar>) -> bool {
+    selected_placeable(&hotbar).is_some()
+}
+
+/// Why `kind` can't be placed on `cell`, or `None` if it can.
+fn placement_problem(
+    map: &CollisionMap,
+    placed: &PlacedObjects,
+    props: &Query<&Transform, (With<TileMarker>, With<YSort>)>,
+    player_feet: Vec2,
+    player_radius: f32,
+    kind: PlaceableKind,
+    cell: IVec2,
+) -> Option<&'static str> {
+    let fits = map
+        .get_tile(cell.x, cell.y)
+        .is_some_and(|tile| tile.is_passable(kind.layer()));
+    if !fits {
+        return Some("Can't place that here");
+    }
+    let has_prop = props
+        .iter()
+        .any(|transform| map.world_to_grid(transform.translation.truncate()) == cell);
+    if placed.is_occupied(cell) || has_prop {
+        return Some("Something is in the way");
+    }
+    if kind.blocks_movement() {
+        // A fence dropped on the player's own feet would trap them
+        let half = map.tile_size() / 2.0;
+        let center = map.grid_to_world(cell.x, cell.y);
+        let closest = player_feet.clamp(center - half, center + half);
+        if closest.distance_squared(player_feet) < player_radius * player_radius {
+            return Some("You're standing there");
+        }
+    }
+    None
+}
+
+/// Shows a grid-snapped preview of the selected placeable in front of the
+/// player, tinted red where it can't go.
+pub fn update_placement_ghost(
+    mut commands: Commands,
+    hotbar: Res<Hotbar>,
+    map: Option<Res<CollisionMap>>,
+    placed: Res<PlacedObjects>,
+    props: Query<&Transform, (With<TileMarker>, With<YSort>)>,
+    player_query: Query<(&Transform, &Facing, &Collider), With<Player>>,
+    mut ghosts: Query<(&PlacementGhost, &mut Transform, &mut Sprite), Without<YSort>>,
+) {
+    let (Some(kind), Some(map)) = (selected_placeable(&hotbar), map) else {
+        return;
+    };
+    let Ok((player_transform, facing, collider)) = player_query.single() else {
+        return;
+    };
+
+    let cell = facing_cell(&map, player_transform, facing);
+    let feet = collider.world_position(player_transform);
+    let problem = placement_problem(&map, &placed, &props, feet, collider.radius, kind, cell);
+    let color = match problem {
+        None => kind.color(),
+        Some(_) => BLOCKED_GHOST_COLOR,
+    }
+    .with_alpha(GHOST_ALPHA);
+    let translation = map.grid_to_world(cell.x, cell.y).extend(GHOST_Z);
+
+    if let Ok((_, mut transform, mut sprite)) = ghosts.single_mut() {
+        transform.translation = translation;
+        sprite.color = color;
+        sprite.custom_size = Some(kind.size());
+    } else {
+        commands.spawn((
+            PlacementGhost,
+            Sprite::from_color(color, kind.size()),
+            Transform::from_translation(translation),
+            WorldScoped,
+        ));
+    }
+}
+
+/// Removes the preview once something other than a placeable is selected.
+pub fn despawn_placement_ghost(mut commands: Commands, ghosts: Query<Entity, With<PlacementGhost>>) {
+    for ghost in ghosts.iter() {
+        commands.entity(ghost).despawn();
+    }
+}
+
+/// Puts the selected placeable down where the ghost is, using up one item.
+pub fn place_selected(
+    mut commands: Commands,
+    hotbar: Res<Hotbar>,
+    mut inventory: ResMut<Inventory>,
+    collision_map: Option<ResMut<CollisionMap>>,
+    mut placed: ResMut<PlacedObjects>,
+    props: Query<&Transform, (With<TileMarker>, With<YSort>)>,
+    player_query: Query<(&Transform, &Facing, &Collider), With<Player>>,
+) {
+    let (Some(kind), Some(mut map)) = (selected_placeable(&hotbar), collision_map) else {
+        return;
+    };
+    let Ok((player_transform, facing, collider)) = player_query.single() else {
+        return;
+    };
+
+    let cell = facing_cell(&map, player_transform, facing);
+    let feet = collider.world_position(player_transform);
+    if let Some(problem) = placement_problem(&map, &placed, &props, feet, collider.radius, kind, cell) {
+        info!("{}", problem);
+        return;
+    }
+    if !inventory.remove(kind.item(), 1) {
+        info!("No {} left", kind.item());
+        return;
+    }
+
+    if let Some(from) = spawn_placed(&mut commands, &mut map, &mut placed, kind, cell) {
+        commands.trigger(TileChanged {
+            cell,
+            from,
+            to: TileType::Fence,
+        });
+    }
+    commands.trigger(ObjectPlaced { kind, cell });
+    commands.trigger(PlaySfx(SfxId::Till));
+    info!("Placed {} at {:?}", kind.item(), cell);
+}
+
+/// Spawns a placed object on `cell` without any checks, writing fences into
+/// the collision map. Returns the tile type a fence replaced.
+pub fn spawn_placed(
+    commands: &mut Commands,
+    map: &mut CollisionMap,
+    placed: &mut PlacedObjects,
+    kind: PlaceableKind,
+    cell: IVec2,
+) -> Option<TileType> {
+    let pos = map.grid_to_world(cell.x, cell.y);
+    let entity = commands
+        .spawn((
+            Placed { kind, cell },
+            Sprite::from_color(kind.color(), kind.size()),
+            Transform::from_translation(pos.extend(0.0)),
+            YSort::tile(),
+            WorldScoped,
+        ))
+        .id();
+    placed.insert(cell, entity);
+
+    if kind == PlaceableKind::Boat {
+        commands.entity(entity).insert((
+            Boat,
+            Interactable {
+                radius: BOARD_RADIUS,
+            },
+            YSort::new(BOAT_SORT_OFFSET),
+        ));
+    }
+
+    if !kind.blocks_movement() {
+        return None;
+    }
+    let from = map.get_tile(cell.x, cell.y)?;
+    map.set_tile(cell.x, cell.y, TileType::Fence);
+    Some(from)
+}
+
+/// Despawns whatever was placed on `cell` and gives the collision map back
+/// `ground` there. Returns false if nothing was placed on it.
+pub fn remove_placed(
+    commands: &mut Commands,
+    map: &mut CollisionMap,
+    placed: &mut PlacedObjects,
+    cell: IVec2,
+    ground: TileType,
+) -> bool {
+    let Some(entity) = placed.get(cell) else {
+        return false;
+    };
+    commands.entity(entity).despawn();
+    placed.remove(cell);
+    map.set_tile(cell.x, cell.y, ground);
+    true
+}
+
+pub fn clear_placed_objects(mut placed: ResMut<PlacedObjects>) {
+    placed.clear();
+}
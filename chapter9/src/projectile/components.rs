@@ -0,0 +1,55 @@
+// src/projectile/components.rs
+use bevy::prelude::*;
+
+use crate::combat::PowerType;
+use crate::config::projectile::{DEFAULT_LIFETIME, DEFAULT_MAX_RANGE};
+
+/// Who fired a projectile determines which entities it can hit.
+#[derive(Component, Clone, Copy)]
+pub enum ProjectileOwner {
+    Player,
+    Enemy,
+}
+
+/// What was fired. Thrown items (rocks, slingshot ammo) add variants here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectileKind {
+    Power(PowerType),
+}
+
+/// Invisible hitbox that travels and checks for collisions.
+#[derive(Component)]
+pub struct Projectile {
+    pub kind: ProjectileKind,
+    pub owner: ProjectileOwner,
+    /// World units per second
+    pub velocity: Vec2,
+    pub damage: f32,
+    pub radius: f32,
+    /// Remaining time before the projectile despawns (seconds)
+    pub lifetime: f32,
+    /// Distance after which the projectile despawns (world units)
+    pub max_range: f32,
+    pub traveled: f32,
+}
+
+impl Projectile {
+    pub fn new(
+        kind: ProjectileKind,
+        owner: ProjectileOwner,
+        velocity: Vec2,
+        damage: f32,
+        radius: f32,
+    ) -> Self {
+        Self {
+            kind,
+            owner,
+            velocity,
+            damage,
+            radius,
+            lifetime: DEFAULT_LIFETIME,
+            max_range: DEFAULT_MAX_RANGE,
+            traveled: 0.0,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+// src/projectile/events.rs
+use bevy::prelude::*;
+
+use super::components::ProjectileKind;
+
+/// Event triggered when a projectile hits a target entity.
+#[derive(Event)]
+pub struct ProjectileHit {
+    pub target: Entity,
+    pub damage: f32,
+    pub kind: ProjectileKind,
+}
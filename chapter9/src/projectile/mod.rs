@@ -0,0 +1,27 @@
+// src/projectile/mod.rs
+mod components;
+mod events;
+mod systems;
+
+use bevy::prelude::*;
+use crate::characters::physics::apply_velocity;
+use crate::state::GameState;
+
+pub use components::{Projectile, ProjectileKind, ProjectileOwner};
+pub use events::ProjectileHit;
+
+/// Plugin for projectile movement, obstacle stops, and hit detection.
+/// Anything that fires (powers, future thrown items) just spawns a `Projectile`.
+pub struct ProjectilePlugin;
+
+impl Plugin for ProjectilePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (systems::move_projectiles, systems::check_projectile_hits)
+                .chain()
+                .after(apply_velocity)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
@@ -0,0 +1,73 @@
+// src/projectile/systems.rs
+use bevy::prelude::*;
+
+use super::components::{Projectile, ProjectileOwner};
+use super::events::ProjectileHit;
+use crate::audio::{PlaySfx, SfxId};
+use crate::characters::input::Player;
+use crate::collision::{CollisionMap, SpatialHash};
+use crate::enemy::Enemy;
+
+/// Moves projectile hitboxes forward, stopping them on obstacles.
+/// Despawns them when they run out of lifetime or range.
+pub fn move_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    collision_map: Option<Res<CollisionMap>>,
+    mut projectiles: Query<(Entity, &mut Projectile, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut proj, mut transform) in projectiles.iter_mut() {
+        proj.lifetime -= dt;
+        if proj.lifetime <= 0.0 || proj.traveled >= proj.max_range {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let start = transform.translation.truncate();
+        let end = start + proj.velocity * dt;
+
+        // Trees and rocks stop projectiles; water does not
+        if let Some(map) = collision_map.as_deref()
+            && map.raycast(start, end, |tile| tile.blocks_projectiles()).is_some()
+        {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        proj.traveled += start.distance(end);
+        transform.translation = end.extend(transform.translation.z);
+    }
+}
+
+/// Checks each projectile hitbox against nearby valid targets; triggers hit events on collision.
+pub fn check_projectile_hits(
+    mut commands: Commands,
+    spatial_hash: Res<SpatialHash>,
+    projectiles: Query<(Entity, &Projectile, &Transform)>,
+    players: Query<(), With<Player>>,
+    enemies: Query<(), With<Enemy>>,
+) {
+    for (proj_entity, proj, proj_transform) in &projectiles {
+        let proj_pos = proj_transform.translation.truncate();
+
+        let hit_target = spatial_hash
+            .query_radius(proj_pos, proj.radius)
+            .map(|(entity, _)| entity)
+            .find(|&entity| match proj.owner {
+                ProjectileOwner::Player => enemies.contains(entity),
+                ProjectileOwner::Enemy => players.contains(entity),
+            });
+
+        if let Some(target) = hit_target {
+            // Trigger hit event instead of directly applying damage
+            commands.trigger(ProjectileHit {
+                target,
+                damage: proj.damage,
+                kind: proj.kind,
+            });
+            commands.trigger(PlaySfx(SfxId::Hit));
+            commands.entity(proj_entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,179 @@
+// src/rendering/chunk_mesh.rs
+use std::collections::{HashMap, HashSet};
+
+use bevy::asset::RenderAssetUsages;
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::prelude::*;
+
+use crate::config::map::{CHUNKS_X, CHUNKS_Y, GRID_X, GRID_Y};
+use crate::map::assets::TilemapHandles;
+use crate::map::tilemap::TILEMAP;
+use crate::map::world_id::WorldEntityId;
+use crate::map::TileChanged;
+use crate::state::WorldScoped;
+
+/// Resource to track if the terrain has been baked into chunk meshes.
+#[derive(Resource, Default, PartialEq, Eq)]
+pub struct ChunkMeshesBuilt(pub bool);
+
+/// A tile on one of the map's ground layers (dirt, grass, water, shore),
+/// as opposed to a prop standing on them.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Ground;
+
+/// A terrain tile drawn by its chunk's mesh instead of its own sprite.
+///
+/// The tile entity keeps its (hidden) sprite as the source for re-baking,
+/// and its `TileMarker` for the collision map.
+#[derive(Component)]
+pub struct BakedTile {
+    pub chunk: UVec2,
+}
+
+/// One mesh holding every static terrain tile of a chunk.
+#[derive(Component)]
+pub struct ChunkMesh {
+    pub chunk: UVec2,
+}
+
+/// Chunks whose tiles changed since they were last baked.
+#[derive(Resource, Default)]
+pub struct DirtyChunks(HashSet<UVec2>);
+
+pub fn has_dirty_chunks(dirty: Res<DirtyChunks>) -> bool {
+    !dirty.0.is_empty()
+}
+
+/// Chunk that owns a collision map cell (chunks share their border row/column).
+fn chunk_for_cell(cell: IVec2) -> UVec2 {
+    UVec2::new(
+        (cell.x.max(0) as u32 / (GRID_X - 1)).min(CHUNKS_X - 1),
+        (cell.y.max(0) as u32 / (GRID_Y - 1)).min(CHUNKS_Y - 1),
+    )
+}
+
+/// Builds one quad per tile, in world space and flattened to z = 0.
+///
+/// Quads are emitted from the lowest layer up, so within the mesh the
+/// draw order matches the Z order the individual sprites had.
+fn build_chunk_mesh(mut tiles: Vec<(&Sprite, &Transform)>) -> Mesh {
+    tiles.sort_by(|a, b| a.1.translation.z.total_cmp(&b.1.translation.z));
+
+    let atlas_size = TILEMAP.packed_atlas_size().as_vec2();
+    let half = TILEMAP.tile_size().as_vec2() / 2.0;
+
+    let mut positions = Vec::with_capacity(tiles.len() * 4);
+    let mut uvs = Vec::with_capacity(tiles.len() * 4);
+    let mut indices = Vec::with_capacity(tiles.len() * 6);
+
+    for (sprite, transform) in tiles {
+        let Some(atlas) = &sprite.texture_atlas else {
+            continue;
+        };
+        let rect = TILEMAP.sprite_rect(atlas.index);
+        let (mut u0, mut u1) = (rect.min.x as f32 / atlas_size.x, rect.max.x as f32 / atlas_size.x);
+        let (mut v0, mut v1) = (rect.min.y as f32 / atlas_size.y, rect.max.y as f32 / atlas_size.y);
+        if sprite.flip_x {
+            std::mem::swap(&mut u0, &mut u1);
+        }
+        if sprite.flip_y {
+            std::mem::swap(&mut v0, &mut v1);
+        }
+
+        let base = positions.len() as u32;
+        let corners = [
+            (Vec2::new(-half.x, -half.y), [u0, v1]),
+            (Vec2::new(half.x, -half.y), [u1, v1]),
+            (Vec2::new(half.x, half.y), [u1, v0]),
+            (Vec2::new(-half.x, half.y), [u0, v0]),
+        ];
+        for (corner, uv) in corners {
+            let world = transform.transform_point(corner.extend(0.0));
+            positions.push([world.x, world.y, 0.0]);
+            uvs.push(uv);
+        }
+        indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    let normals = vec![[0.0, 0.0, 1.0]; positions.len()];
+    Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::U32(indices))
+}
+
+/// Bakes every generated ground tile into its chunk's mesh and hides its sprite.
+///
+/// Props (trees and their canopies, rocks, pickables, resource nodes) stay
+/// as entities so they keep interleaving with characters and swaying.
+pub fn bake_chunk_meshes(
+    mut commands: Commands,
+    mut built: ResMut<ChunkMeshesBuilt>,
+    handles: Res<TilemapHandles>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    tiles: Query<(Entity, &WorldEntityId, &Sprite, &Transform), With<Ground>>,
+) {
+    let mut by_chunk: HashMap<UVec2, Vec<(&Sprite, &Transform)>> = HashMap::new();
+    let mut baked = 0;
+    for (entity, id, sprite, transform) in tiles.iter() {
+        let Some(chunk) = id.generated_chunk() else {
+            continue;
+        };
+        by_chunk.entry(chunk).or_default().push((sprite, transform));
+        commands
+            .entity(entity)
+            .insert((BakedTile { chunk }, Visibility::Hidden));
+        baked += 1;
+    }
+    if by_chunk.is_empty() {
+        return;
+    }
+
+    let material = materials.add(ColorMaterial::from(handles.image.clone()));
+    let chunk_count = by_chunk.len();
+    for (chunk, chunk_tiles) in by_chunk {
+        commands.spawn((
+            ChunkMesh { chunk },
+            Mesh2d(meshes.add(build_chunk_mesh(chunk_tiles))),
+            MeshMaterial2d(material.clone()),
+            Transform::default(),
+            WorldScoped,
+        ));
+    }
+
+    built.0 = true;
+    info!("Baked {} terrain tiles into {} chunk meshes", baked, chunk_count);
+}
+
+pub fn mark_changed_chunk(changed: On<TileChanged>, mut dirty: ResMut<DirtyChunks>) {
+    let chunk = chunk_for_cell(changed.cell);
+    debug!(
+        "Tile {:?} changed from {:?} to {:?}, re-baking chunk {:?}",
+        changed.cell, changed.from, changed.to, chunk
+    );
+    dirty.0.insert(chunk);
+}
+
+/// Rebuilds the meshes of chunks whose tiles were changed by gameplay.
+pub fn rebake_dirty_chunks(
+    mut dirty: ResMut<DirtyChunks>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    chunks: Query<(&ChunkMesh, &Mesh2d)>,
+    tiles: Query<(&BakedTile, &Sprite, &Transform)>,
+) {
+    for chunk in dirty.0.drain() {
+        let Some((_, mesh)) = chunks.iter().find(|(chunk_mesh, _)| chunk_mesh.chunk == chunk) else {
+            continue;
+        };
+        let chunk_tiles = tiles
+            .iter()
+            .filter(|(baked, _, _)| baked.chunk == chunk)
+            .map(|(_, sprite, transform)| (sprite, transform))
+            .collect();
+        if let Some(existing) = meshes.get_mut(&mesh.0) {
+            *existing = build_chunk_mesh(chunk_tiles);
+        }
+    }
+}
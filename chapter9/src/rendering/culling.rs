@@ -0,0 +1,78 @@
+// src/rendering/culling.rs
+use bevy::prelude::*;
+
+use crate::camera::MainCamera;
+use crate::config::culling::{SLEEP_MARGIN, VISIBLE_MARGIN};
+
+/// How close a world entity is to the camera view.
+///
+/// Every Y-sorted entity gets one. Systems that animate or think about
+/// world entities check it to skip work nobody would see.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CullBand {
+    /// Inside the view (plus a margin): drawn and fully updated
+    #[default]
+    OnScreen,
+    /// Near the view: hidden and not animated, but AI keeps running
+    /// so critters don't freeze just out of sight
+    Nearby,
+    /// Far away: hidden, not animated, and AI sleeps
+    Asleep,
+}
+
+impl CullBand {
+    pub fn is_on_screen(self) -> bool {
+        self == CullBand::OnScreen
+    }
+
+    pub fn is_asleep(self) -> bool {
+        self == CullBand::Asleep
+    }
+}
+
+/// World-space rectangle the main camera currently shows.
+pub fn camera_view(transform: &Transform, projection: &Projection) -> Option<Rect> {
+    let Projection::Orthographic(ortho) = projection else {
+        return None;
+    };
+    let center = transform.translation.truncate();
+    Some(Rect::from_corners(center + ortho.area.min, center + ortho.area.max))
+}
+
+/// Sorts entities into distance bands around the camera view and hides
+/// the ones outside it.
+///
+/// Runs on a timer rather than every frame; the margins are wide enough
+/// that nothing pops in before the next pass.
+pub fn update_cull_bands(
+    camera: Query<(&Transform, &Projection), With<MainCamera>>,
+    mut entities: Query<(&GlobalTransform, &mut CullBand, &mut Visibility)>,
+) {
+    let Some(view) = camera
+        .single()
+        .ok()
+        .and_then(|(transform, projection)| camera_view(transform, projection))
+    else {
+        return;
+    };
+    let visible = view.inflate(VISIBLE_MARGIN);
+    let awake = view.inflate(SLEEP_MARGIN);
+
+    for (transform, mut band, mut visibility) in entities.iter_mut() {
+        let pos = transform.translation().truncate();
+        let new_band = if visible.contains(pos) {
+            CullBand::OnScreen
+        } else if awake.contains(pos) {
+            CullBand::Nearby
+        } else {
+            CullBand::Asleep
+        };
+
+        band.set_if_neq(new_band);
+        visibility.set_if_neq(if new_band.is_on_screen() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+}
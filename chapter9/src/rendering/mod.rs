@@ -0,0 +1,56 @@
+// src/rendering/mod.rs
+mod chunk_mesh;
+mod culling;
+mod ysort;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+
+use crate::map::assets::TilemapHandles;
+use crate::config::culling::CULL_INTERVAL_SECS;
+use crate::map::generate::MapReady;
+use crate::mods::atlas_packed;
+use crate::state::GameState;
+
+pub use chunk_mesh::{BakedTile, ChunkMeshesBuilt, Ground};
+pub use culling::{camera_view, CullBand};
+pub use ysort::YSort;
+
+/// Plugin for render-order systems shared by every entity type, for
+/// batching static terrain into per-chunk meshes, and for culling
+/// entities far from the camera.
+pub struct RenderingPlugin;
+
+impl Plugin for RenderingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChunkMeshesBuilt>()
+            .init_resource::<chunk_mesh::DirtyChunks>()
+            .add_observer(chunk_mesh::mark_changed_chunk)
+            .add_systems(
+                Update,
+                (
+                    chunk_mesh::bake_chunk_meshes
+                        .run_if(resource_exists::<MapReady>)
+                        .run_if(resource_exists::<TilemapHandles>)
+                        // UVs are computed against the atlas with pack sprites in
+                        .run_if(atlas_packed)
+                        .run_if(resource_equals(ChunkMeshesBuilt(false))),
+                    chunk_mesh::rebake_dirty_chunks.run_if(chunk_mesh::has_dirty_chunks),
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                culling::update_cull_bands
+                    .run_if(on_timer(Duration::from_secs_f32(CULL_INTERVAL_SECS)))
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Run after gameplay has moved things, right before transforms propagate
+            .add_systems(
+                PostUpdate,
+                ysort::apply_y_sort.before(TransformSystems::Propagate),
+            );
+    }
+}
@@ -0,0 +1,56 @@
+// src/rendering/ysort.rs
+use bevy::prelude::*;
+
+use super::CullBand;
+use crate::config::map::{NODE_SIZE_Z, PROPS_LAYER, TILE_SIZE, TOTAL_GRID_Y};
+
+/// Vertical offset from a tile's center to its bottom edge.
+/// Props sort by the bottom edge of the tile they stand on.
+pub const TILE_SORT_OFFSET: f32 = -TILE_SIZE / 2.0;
+
+/// Sorts an entity's Z from its Y position so lower entities render in front.
+///
+/// `offset` moves the sort point away from the transform's origin,
+/// e.g. down to a character's feet or to the base of a tree.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(CullBand)]
+pub struct YSort {
+    pub offset: f32,
+}
+
+impl YSort {
+    pub fn new(offset: f32) -> Self {
+        Self { offset }
+    }
+
+    /// Y-sort anchored at the bottom edge of a map tile.
+    pub fn tile() -> Self {
+        Self::new(TILE_SORT_OFFSET)
+    }
+}
+
+/// Z value for a sort point at `world_y`.
+///
+/// Matches the generator's per-row Z offset for the props layer, so
+/// Y-sorted entities interleave correctly with generated trees and rocks.
+pub fn y_sort_z(world_y: f32) -> f32 {
+    let map_height = TILE_SIZE * TOTAL_GRID_Y as f32;
+    let map_y0 = -map_height / 2.0;
+
+    // Normalize Y to [0, 1] across the grid height
+    let t = ((world_y - map_y0) / map_height).clamp(0.0, 1.0);
+
+    // Lower Y (bottom of screen) = higher t = lower Z = rendered in front
+    let layer_base = NODE_SIZE_Z * (PROPS_LAYER as f32 + 0.5);
+    layer_base + NODE_SIZE_Z * (1.0 - t)
+}
+
+/// Assigns Z from Y for every entity with a `YSort`.
+pub fn apply_y_sort(mut query: Query<(&YSort, &mut Transform), Changed<Transform>>) {
+    for (sort, mut transform) in query.iter_mut() {
+        let z = y_sort_z(transform.translation.y + sort.offset);
+        if transform.translation.z != z {
+            transform.translation.z = z;
+        }
+    }
+}
@@ -0,0 +1,187 @@
+// src/replay/data.rs
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::replay::RECORD_PATH;
+use crate::controls::InputAction;
+use crate::save::SaveData;
+use crate::settings::Difficulty;
+
+/// Actions a replay records. Pausing, muting, saving and the event log
+/// don't touch the simulation, and replaying a quick load would swap the
+/// world out from under the recording.
+pub const REPLAYED_ACTIONS: [InputAction; 10] = [
+    InputAction::MoveUp,
+    InputAction::MoveDown,
+    InputAction::MoveLeft,
+    InputAction::MoveRight,
+    InputAction::Run,
+    InputAction::Jump,
+    InputAction::Interact,
+    InputAction::UseTool,
+    InputAction::CycleHotbar,
+    InputAction::CastPower,
+];
+
+/// What `--record` or `--replay` asked for.
+pub enum ReplayArgs {
+    Record(PathBuf),
+    Play(PathBuf),
+}
+
+impl ReplayArgs {
+    /// Reads `--record [path]` or `--replay <path>` from the command line.
+    pub fn from_args() -> Option<Self> {
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--record" => {
+                    let path = args
+                        .next()
+                        .filter(|next| !next.starts_with("--"))
+                        .unwrap_or_else(|| RECORD_PATH.to_string());
+                    return Some(Self::Record(path.into()));
+                }
+                "--replay" => return args.next().map(|path| Self::Play(path.into())),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Actions held for a run of consecutive fixed ticks.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InputSpan {
+    pub ticks: u32,
+    pub held: Vec<InputAction>,
+}
+
+/// A recorded session: the world it ran in and the actions held on every
+/// fixed tick after the player spawned.
+///
+/// Ticks are stored as spans of identical input, so standing still or
+/// holding one direction costs a single entry.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Replay {
+    pub seed: u64,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// The save the session was loaded from, applied before the first tick.
+    /// `None` for a new game
+    #[serde(default)]
+    pub start: Option<SaveData>,
+    pub spans: Vec<InputSpan>,
+}
+
+impl Replay {
+    pub fn new(seed: u64, difficulty: Difficulty, start: Option<SaveData>) -> Self {
+        Self {
+            seed,
+            difficulty,
+            start,
+            spans: Vec::new(),
+        }
+    }
+
+    /// Appends one tick holding `held`.
+    pub fn push(&mut self, held: Vec<InputAction>) {
+        match self.spans.last_mut() {
+            Some(span) if span.held == held => span.ticks += 1,
+            _ => self.spans.push(InputSpan { ticks: 1, held }),
+        }
+    }
+
+    /// Recorded ticks in total.
+    pub fn ticks(&self) -> u32 {
+        self.spans.iter().map(|span| span.ticks).sum()
+    }
+
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        ron::from_str(&text).map_err(|err| err.to_string())
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| err.to_string())?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+        }
+        fs::write(path, text).map_err(|err| err.to_string())
+    }
+}
+
+/// Records held actions while present; written out when the app exits.
+#[derive(Resource)]
+pub struct ReplayRecorder {
+    pub path: PathBuf,
+    pub replay: Replay,
+    /// Whether a frame has run with the player spawned. Ticks before that
+    /// aren't recorded, matching where playback starts.
+    pub(super) started: bool,
+    /// Actions pressed since the last recorded tick
+    pub(super) pressed: Vec<InputAction>,
+}
+
+impl ReplayRecorder {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            replay: Replay::default(),
+            started: false,
+            pressed: Vec::new(),
+        }
+    }
+}
+
+/// Plays a replay back while present, feeding its actions to the keyboard
+/// input as if the player held the bound keys.
+#[derive(Resource)]
+pub struct ReplayPlayback {
+    pub replay: Replay,
+    /// Whether input has been fed yet; ticks only advance after that
+    pub(super) started: bool,
+    /// Actions held on the tick before, to tell presses from holds
+    pub(super) previous: Vec<InputAction>,
+    /// Index into `replay.spans` of the tick being played
+    span: usize,
+    /// Ticks already played of that span
+    elapsed: u32,
+}
+
+impl ReplayPlayback {
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            replay,
+            started: false,
+            previous: Vec::new(),
+            span: 0,
+            elapsed: 0,
+        }
+    }
+
+    /// Actions held on the current tick, or `None` once every tick played.
+    pub fn current(&self) -> Option<&[InputAction]> {
+        self.replay.spans.get(self.span).map(|span| span.held.as_slice())
+    }
+
+    /// Moves on to the next tick.
+    pub fn advance(&mut self) {
+        let Some(span) = self.replay.spans.get(self.span) else {
+            return;
+        };
+        self.elapsed += 1;
+        if self.elapsed >= span.ticks {
+            self.span += 1;
+            self.elapsed = 0;
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.span >= self.replay.spans.len()
+    }
+}
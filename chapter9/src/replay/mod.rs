@@ -0,0 +1,99 @@
+// src/replay/mod.rs
+mod data;
+mod systems;
+
+use std::time::Duration;
+
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::characters::spawn::PlayerSpawned;
+use crate::config::physics::FIXED_TICK_HZ;
+use crate::controls::buffer_presses;
+use crate::map::generate::MapSeed;
+use crate::save::PendingLoad;
+use crate::state::GameState;
+
+use data::{Replay, ReplayArgs, ReplayRecorder};
+
+pub use data::ReplayPlayback;
+
+/// Plugin for recording and replaying player input.
+///
+/// `--record [path]` saves the map seed, difficulty, the save the world was
+/// loaded from (if any) and the actions held on every fixed tick to a file
+/// when the game closes. `--replay <path>` rebuilds that world and feeds the
+/// actions back in, on screen or with `--headless`, to reproduce bugs such as
+/// getting stuck on a shore corner.
+///
+/// Recording runs in real time, logging what was held on each fixed tick.
+/// Playback advances exactly one fixed tick per frame, as headless runs
+/// always do, and feeds each tick's actions in just before it. The
+/// simulation's time-dependent systems run in `FixedUpdate`, so they step
+/// the same way in both and a replay lands on the same world state as its
+/// recording.
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        match ReplayArgs::from_args() {
+            Some(ReplayArgs::Record(path)) => {
+                app.insert_resource(ReplayRecorder::new(path));
+            }
+            Some(ReplayArgs::Play(path)) => match Replay::read(&path) {
+                Ok(replay) => {
+                    if let Some(start) = replay.start.clone() {
+                        app.insert_resource(PendingLoad(start));
+                    }
+                    app.insert_resource(MapSeed(replay.seed))
+                        .insert_resource(replay.difficulty)
+                        .insert_resource(ReplayPlayback::new(replay))
+                        // One fixed tick per frame, so each tick gets its own input
+                        .insert_resource(TimeUpdateStrategy::ManualDuration(
+                            Duration::from_secs_f64(1.0 / FIXED_TICK_HZ),
+                        ))
+                        .add_systems(Startup, systems::start_playback);
+                }
+                Err(err) => error!("Failed to read replay {}: {}", path.display(), err),
+            },
+            None => {}
+        }
+
+        app.add_systems(
+            OnEnter(GameState::Loading),
+            systems::restart_recording.run_if(resource_exists::<ReplayRecorder>),
+        )
+        .add_systems(
+            PreUpdate,
+            (
+                (systems::begin_recording, systems::note_presses)
+                    .run_if(resource_exists::<ReplayRecorder>),
+                systems::play_inputs
+                    .before(buffer_presses)
+                    .run_if(resource_exists::<ReplayPlayback>),
+            )
+                .after(InputSystems)
+                .run_if(resource_equals(PlayerSpawned(true)))
+                .run_if(not(resource_exists::<PendingLoad>))
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            FixedPreUpdate,
+            systems::record_inputs
+                .run_if(resource_exists::<ReplayRecorder>)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            FixedPostUpdate,
+            (systems::advance_playback, systems::finish_playback)
+                .chain()
+                .run_if(resource_exists::<ReplayPlayback>)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Last,
+            systems::write_recording.run_if(resource_exists::<ReplayRecorder>),
+        );
+    }
+}
@@ -0,0 +1,146 @@
+// src/replay/systems.rs
+use bevy::prelude::*;
+
+use super::data::{Replay, ReplayPlayback, ReplayRecorder, REPLAYED_ACTIONS};
+use crate::controls::ControlBindings;
+use crate::map::generate::MapSeed;
+use crate::save::PendingLoad;
+use crate::settings::Difficulty;
+use crate::state::GameState;
+use crate::ui::ShowToast;
+
+/// Starts a fresh recording for each generated world, so the file only
+/// holds input from the world it names. When the world is being rebuilt
+/// from a save, the save goes into the recording too.
+pub fn restart_recording(
+    seed: Res<MapSeed>,
+    difficulty: Res<Difficulty>,
+    pending: Option<Res<PendingLoad>>,
+    mut recorder: ResMut<ReplayRecorder>,
+) {
+    let start = pending.map(|pending| pending.0.clone());
+    recorder.replay = Replay::new(seed.0, *difficulty, start);
+    recorder.started = false;
+    recorder.pressed.clear();
+}
+
+/// Marks the first frame with the player spawned and any save applied. Runs
+/// in `PreUpdate`, where playback starts feeding input, so both begin on the
+/// same tick.
+pub fn begin_recording(mut recorder: ResMut<ReplayRecorder>) {
+    recorder.started = true;
+}
+
+/// Notes presses made since the last fixed tick. Frames can outnumber
+/// ticks, so a quick tap may be released again before the next one.
+pub fn note_presses(
+    input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<ControlBindings>,
+    mut recorder: ResMut<ReplayRecorder>,
+) {
+    for action in REPLAYED_ACTIONS {
+        if bindings.just_pressed(action, &input) && !recorder.pressed.contains(&action) {
+            recorder.pressed.push(action);
+        }
+    }
+}
+
+/// Appends the actions held on this fixed tick, counting any pressed since
+/// the last one as held, so playback presses them on this tick too.
+pub fn record_inputs(
+    input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<ControlBindings>,
+    mut recorder: ResMut<ReplayRecorder>,
+) {
+    if !recorder.started {
+        return;
+    }
+    let pressed = std::mem::take(&mut recorder.pressed);
+    let held = REPLAYED_ACTIONS
+        .into_iter()
+        .filter(|&action| bindings.pressed(action, &input) || pressed.contains(&action))
+        .collect();
+    recorder.replay.push(held);
+}
+
+/// Writes the recording when the game closes.
+pub fn write_recording(mut exit: MessageReader<AppExit>, recorder: Res<ReplayRecorder>) {
+    if exit.read().next().is_none() {
+        return;
+    }
+    match recorder.replay.write(&recorder.path) {
+        Ok(()) => info!(
+            "Recorded {} ticks to {}",
+            recorder.replay.ticks(),
+            recorder.path.display()
+        ),
+        Err(err) => error!("Failed to write replay {}: {}", recorder.path.display(), err),
+    }
+}
+
+/// Skips the main menu so playback generates the replay's world straight
+/// away. Headless runs already start in `Loading`.
+pub fn start_playback(state: Res<State<GameState>>, mut next_state: ResMut<NextState<GameState>>) {
+    if *state.get() == GameState::MainMenu {
+        next_state.set(GameState::Loading);
+    }
+}
+
+/// Holds the keys bound to this tick's actions and lets go of the rest.
+///
+/// Runs before the input buffer reads the keyboard, so buffered actions and
+/// `just_pressed` see replayed presses like real ones. The keys' state is
+/// rebuilt from the recording alone, so touching the keyboard during
+/// playback can't change what the replay does.
+pub fn play_inputs(
+    bindings: Res<ControlBindings>,
+    mut input: ResMut<ButtonInput<KeyCode>>,
+    mut playback: ResMut<ReplayPlayback>,
+) {
+    let held = playback.current().unwrap_or_default().to_vec();
+    for action in REPLAYED_ACTIONS {
+        let (was_held, is_held) = (playback.previous.contains(&action), held.contains(&action));
+        for &key in bindings.keys(action) {
+            input.reset(key);
+            if !was_held && !is_held {
+                continue;
+            }
+            input.press(key);
+            if was_held {
+                input.clear_just_pressed(key);
+            }
+            if !is_held {
+                input.release(key);
+            }
+        }
+    }
+    playback.previous = held;
+    playback.started = true;
+}
+
+/// Moves playback on by one fixed tick.
+pub fn advance_playback(mut playback: ResMut<ReplayPlayback>) {
+    if playback.started {
+        playback.advance();
+    }
+}
+
+/// Lets go of every replayed key once the recording runs out.
+pub fn finish_playback(
+    mut commands: Commands,
+    bindings: Res<ControlBindings>,
+    mut input: ResMut<ButtonInput<KeyCode>>,
+    playback: Res<ReplayPlayback>,
+) {
+    if !playback.is_finished() {
+        return;
+    }
+    for action in REPLAYED_ACTIONS {
+        for &key in bindings.keys(action) {
+            input.release(key);
+        }
+    }
+    info!("Replay finished after {} ticks", playback.replay.ticks());
+    commands.trigger(ShowToast::new("Replay finished"));
+    commands.remove_resource::<ReplayPlayback>();
+}
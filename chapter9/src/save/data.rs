@@ -0,0 +1,71 @@
+// src/save/data.rs
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::farming::CropStage;
+use crate::inventory::Inventory;
+use crate::map::world_id::WorldEntityId;
+use crate::placement::PlaceableKind;
+use crate::settings::Difficulty;
+use crate::stats::Statistics;
+
+/// How a generated entity differs from its freshly generated state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EntityChange {
+    /// A resource node that was harvested and is still regrowing
+    Depleted { regrow_remaining: f32 },
+}
+
+/// Something the player put into the world that generation never spawns.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AddedEntity {
+    /// A campfire, fence, torch or boat put down from the hotbar
+    Placed { kind: PlaceableKind, cell: [i32; 2] },
+    /// A crop planted on tilled soil, `elapsed` seconds into its stage
+    Crop {
+        cell: [i32; 2],
+        stage: CropStage,
+        elapsed: f32,
+    },
+}
+
+/// Changes made to the generated world, keyed by `WorldEntityId`.
+///
+/// The map itself is never saved; it is rebuilt from the seed and this
+/// diff is replayed on top.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorldDiff {
+    /// Entities that no longer exist (e.g. collected pickables)
+    pub removed: HashSet<WorldEntityId>,
+    /// Entities whose state changed; captured from the live world on save
+    pub modified: HashMap<WorldEntityId, EntityChange>,
+    /// Entities the player added; they have no id and are respawned on load.
+    /// Missing from saves made before anything was added
+    #[serde(default)]
+    pub added: Vec<AddedEntity>,
+    /// Cells tilled with the hoe; generation never makes tilled soil
+    #[serde(default)]
+    pub tilled: Vec<[i32; 2]>,
+}
+
+/// Everything written to the save file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveData {
+    pub seed: u64,
+    /// The map is generated from it too; saves from before presets are Normal
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    pub player_position: [f32; 2],
+    pub player_health: f32,
+    pub inventory: Inventory,
+    pub world: WorldDiff,
+    /// Missing from saves made before statistics were tracked
+    #[serde(default)]
+    pub statistics: Statistics,
+}
+
+/// A loaded save waiting for the rebuilt world to finish spawning.
+#[derive(Resource)]
+pub struct PendingLoad(pub SaveData);
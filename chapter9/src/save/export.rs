@@ -0,0 +1,133 @@
+// src/save/export.rs
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::characters::config::CharacterEntry;
+use crate::characters::spawn::spawn_point;
+use crate::collision::CollisionMap;
+use crate::config::save::EXPORT_PATH;
+use crate::enemy::Enemy;
+use crate::harvest::ResourceNode;
+use crate::inventory::Pickable;
+use crate::map::generate::MapSeed;
+use crate::map::world_id::WorldEntityId;
+use crate::settings::Difficulty;
+use crate::ui::ShowToast;
+
+/// Written by `--export-world [path]`: export as soon as the world is ready.
+#[derive(Resource)]
+pub struct ExportWorldOnReady(pub PathBuf);
+
+impl ExportWorldOnReady {
+    /// Reads `--export-world [path]` from the command line.
+    pub fn from_args() -> Option<Self> {
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--export-world" {
+                let path = args
+                    .next()
+                    .filter(|next| !next.starts_with("--"))
+                    .unwrap_or_else(|| EXPORT_PATH.to_string());
+                return Some(Self(path.into()));
+            }
+        }
+        None
+    }
+}
+
+/// A world entity worth knowing about outside the game.
+#[derive(Serialize)]
+struct ExportedEntity {
+    /// `WorldEntityId`, stable for a given seed
+    id: Option<u64>,
+    category: &'static str,
+    kind: String,
+    position: [f32; 2],
+}
+
+/// Everything in the export file.
+#[derive(Serialize)]
+struct WorldExport<'a> {
+    seed: u64,
+    difficulty: Difficulty,
+    spawn_point: [f32; 2],
+    map: &'a CollisionMap,
+    entities: Vec<ExportedEntity>,
+}
+
+fn write_export(path: &Path, export: &WorldExport) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(export).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+/// Dumps the current world to JSON for external tools: the collision grid,
+/// seed and difficulty, spawn point, and every pickable, resource node, and enemy.
+///
+/// Runs on F4, or once after generation when started with `--export-world`.
+pub fn export_world(
+    mut commands: Commands,
+    on_ready: Option<Res<ExportWorldOnReady>>,
+    seed: Res<MapSeed>,
+    difficulty: Res<Difficulty>,
+    map: Option<Res<CollisionMap>>,
+    pickables: Query<(Option<&WorldEntityId>, &GlobalTransform, &Pickable)>,
+    nodes: Query<(Option<&WorldEntityId>, &GlobalTransform, &ResourceNode)>,
+    enemies: Query<(&GlobalTransform, &CharacterEntry), With<Enemy>>,
+) {
+    let Some(map) = map else {
+        warn!("Cannot export before the collision map is built");
+        return;
+    };
+    let path = on_ready.map_or_else(|| PathBuf::from(EXPORT_PATH), |request| request.0.clone());
+    commands.remove_resource::<ExportWorldOnReady>();
+
+    let position = |transform: &GlobalTransform| transform.translation().truncate().to_array();
+    let mut entities: Vec<ExportedEntity> = pickables
+        .iter()
+        .map(|(id, transform, pickable)| ExportedEntity {
+            id: id.map(|id| id.0),
+            category: "pickable",
+            kind: format!("{:?}", pickable.kind),
+            position: position(transform),
+        })
+        .collect();
+    entities.extend(nodes.iter().map(|(id, transform, node)| ExportedEntity {
+        id: id.map(|id| id.0),
+        category: "resource_node",
+        kind: format!("{:?}", node.kind),
+        position: position(transform),
+    }));
+    entities.extend(enemies.iter().map(|(transform, character)| ExportedEntity {
+        id: None,
+        category: "enemy",
+        kind: character.name.clone(),
+        position: position(transform),
+    }));
+
+    let export = WorldExport {
+        seed: seed.0,
+        difficulty: *difficulty,
+        spawn_point: spawn_point(&map).to_array(),
+        map: &map,
+        entities,
+    };
+
+    match write_export(&path, &export) {
+        Ok(()) => {
+            info!(
+                "Exported world (seed {}, {} entities) to {}",
+                export.seed,
+                export.entities.len(),
+                path.display()
+            );
+            commands.trigger(ShowToast::new(format!("World exported to {}", path.display())));
+        }
+        Err(err) => {
+            warn!("Failed to export world: {}", err);
+            commands.trigger(ShowToast::new(format!("Export failed: {}", err)).with_duration(5.0));
+        }
+    }
+}
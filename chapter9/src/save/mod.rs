@@ -0,0 +1,62 @@
+// src/save/mod.rs
+mod data;
+mod export;
+mod snapshot;
+mod systems;
+mod world;
+
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+use crate::characters::spawn::PlayerSpawned;
+use crate::controls::{action_just_pressed, InputAction};
+use crate::harvest::ShoreNodesSpawned;
+use crate::state::GameState;
+
+pub use data::{PendingLoad, SaveData, WorldDiff};
+pub use export::ExportWorldOnReady;
+pub use snapshot::GameSnapshot;
+pub use systems::{queue_load, save_exists};
+pub use world::{WorldChanges, WorldRestorer};
+
+/// Plugin for saving and loading the game.
+///
+/// A save stores the map seed plus a diff of world changes instead of the
+/// world itself; loading regenerates the map and replays the diff.
+///
+/// Also exports the live world as JSON for external tools (F4, or
+/// `--export-world [path]` on the command line).
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        if let Some(request) = ExportWorldOnReady::from_args() {
+            app.insert_resource(request);
+        }
+
+        app.init_resource::<WorldDiff>()
+            .add_observer(systems::record_picked_up)
+            .add_systems(
+                Update,
+                (
+                    systems::save_game_input.run_if(action_just_pressed(InputAction::QuickSave)),
+                    systems::load_game_input.run_if(action_just_pressed(InputAction::QuickLoad)),
+                    // Must not see a save queued this frame against the old world
+                    (systems::restore_world, systems::apply_pending_load)
+                        .chain()
+                        .before(systems::load_game_input)
+                        .run_if(resource_exists::<PendingLoad>)
+                        .run_if(resource_equals(PlayerSpawned(true)))
+                        .run_if(resource_equals(ShoreNodesSpawned(true))),
+                    export::export_world
+                        .run_if(
+                            input_just_pressed(KeyCode::F4)
+                                .or(resource_exists::<ExportWorldOnReady>),
+                        )
+                        .run_if(resource_equals(PlayerSpawned(true)))
+                        .run_if(resource_equals(ShoreNodesSpawned(true))),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
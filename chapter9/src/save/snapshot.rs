@@ -0,0 +1,46 @@
+// src/save/snapshot.rs
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use super::data::SaveData;
+use super::world::WorldChanges;
+use crate::boat::Riding;
+use crate::characters::input::Player;
+use crate::combat::Health;
+use crate::inventory::Inventory;
+use crate::map::generate::MapSeed;
+use crate::settings::Difficulty;
+use crate::stats::Statistics;
+
+/// Everything a `SaveData` is read from.
+#[derive(SystemParam)]
+pub struct GameSnapshot<'w, 's> {
+    seed: Res<'w, MapSeed>,
+    difficulty: Res<'w, Difficulty>,
+    inventory: Res<'w, Inventory>,
+    statistics: Res<'w, Statistics>,
+    changes: WorldChanges<'w, 's>,
+    player: Query<'w, 's, (&'static Transform, &'static Health, Has<Riding>), With<Player>>,
+}
+
+impl GameSnapshot<'_, '_> {
+    /// Whether the player is out on a boat. A ridden boat has left its cell,
+    /// so a snapshot taken now would put the player on foot in the water.
+    pub fn player_riding(&self) -> bool {
+        self.player.single().is_ok_and(|(_, _, riding)| riding)
+    }
+
+    /// The game as it stands, or `None` before the player has spawned.
+    pub fn take(&self) -> Option<SaveData> {
+        let (transform, health, _) = self.player.single().ok()?;
+        Some(SaveData {
+            seed: self.seed.0,
+            difficulty: *self.difficulty,
+            player_position: transform.translation.truncate().to_array(),
+            player_health: health.current,
+            inventory: self.inventory.clone(),
+            world: self.changes.snapshot(),
+            statistics: self.statistics.clone(),
+        })
+    }
+}
@@ -0,0 +1,149 @@
+// src/save/systems.rs
+use std::fs;
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use super::data::{PendingLoad, SaveData, WorldDiff};
+use super::snapshot::GameSnapshot;
+use super::world::WorldRestorer;
+use crate::characters::input::Player;
+use crate::combat::Health;
+use crate::config::save::SAVE_PATH;
+use crate::event_log::{LogCategory, LogEvent};
+use crate::inventory::{Inventory, ItemPickedUp};
+use crate::map::generate::MapSeed;
+use crate::map::world_id::WorldEntityId;
+use crate::state::GameState;
+use crate::stats::Statistics;
+use crate::ui::ShowToast;
+
+/// Observer that remembers generated pickables once they are collected.
+pub fn record_picked_up(
+    picked: On<ItemPickedUp>,
+    ids: Query<&WorldEntityId>,
+    mut diff: ResMut<WorldDiff>,
+) {
+    if let Ok(id) = ids.get(picked.entity) {
+        diff.removed.insert(*id);
+    }
+}
+
+/// Writes the current game to the save file (quick save, F5 by default).
+pub fn save_game_input(mut commands: Commands, snapshot: GameSnapshot) {
+    if snapshot.player_riding() {
+        commands.trigger(ShowToast::new("Can't save while on a boat"));
+        return;
+    }
+    let Some(data) = snapshot.take() else {
+        warn!("Cannot save without a player");
+        return;
+    };
+
+    match write_save(&data) {
+        Ok(()) => {
+            commands.trigger(LogEvent::new(
+                LogCategory::Save,
+                format!(
+                    "Game saved ({} removed, {} modified, {} added)",
+                    data.world.removed.len(),
+                    data.world.modified.len(),
+                    data.world.added.len()
+                ),
+            ));
+            commands.trigger(ShowToast::new("Game saved"));
+        }
+        Err(err) => {
+            warn!("Failed to save game: {}", err);
+            commands.trigger(ShowToast::new(format!("Save failed: {}", err)).with_duration(5.0));
+        }
+    }
+}
+
+/// Loads the save file (quick load, F9 by default); the map is regenerated from its seed.
+pub fn load_game_input(
+    mut commands: Commands,
+    mut seed: ResMut<MapSeed>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    queue_load(&mut commands, &mut seed, &mut next_state);
+}
+
+/// Reads the save file and switches to `Loading` so the world is rebuilt from it.
+/// Returns false (and stays in the current state) if there is no readable save.
+pub fn queue_load(
+    commands: &mut Commands,
+    seed: &mut MapSeed,
+    next_state: &mut NextState<GameState>,
+) -> bool {
+    let data = match read_save() {
+        Ok(data) => data,
+        Err(err) => {
+            warn!("Failed to load game: {}", err);
+            commands.trigger(ShowToast::new("No save to load").with_duration(5.0));
+            return false;
+        }
+    };
+
+    info!(
+        "Loading save, regenerating map with seed {} on {}",
+        data.seed,
+        data.difficulty.label()
+    );
+    seed.0 = data.seed;
+    commands.insert_resource(data.difficulty);
+    commands.insert_resource(PendingLoad(data));
+    next_state.set(GameState::Loading);
+    true
+}
+
+/// Whether a save file exists to continue from.
+pub fn save_exists() -> bool {
+    Path::new(SAVE_PATH).exists()
+}
+
+/// Respawns what the player had added to the world when the save was made
+/// and removes what they had taken from it.
+pub fn restore_world(pending: Res<PendingLoad>, mut restorer: WorldRestorer) {
+    restorer.restore(&pending.0.world);
+}
+
+/// Puts the player and their inventory back as the save left them.
+pub fn apply_pending_load(
+    mut commands: Commands,
+    pending: Res<PendingLoad>,
+    mut diff: ResMut<WorldDiff>,
+    mut inventory: ResMut<Inventory>,
+    mut statistics: ResMut<Statistics>,
+    mut player: Query<(&mut Transform, &mut Health), With<Player>>,
+) {
+    let data = &pending.0;
+
+    if let Ok((mut transform, mut health)) = player.single_mut() {
+        let [x, y] = data.player_position;
+        transform.translation.x = x;
+        transform.translation.y = y;
+        health.current = data.player_health.min(health.max);
+    }
+
+    *inventory = data.inventory.clone();
+    *diff = data.world.clone();
+    *statistics = data.statistics.clone();
+    commands.remove_resource::<PendingLoad>();
+    commands.trigger(LogEvent::new(LogCategory::Save, "Game loaded"));
+    commands.trigger(ShowToast::new("Game loaded"));
+}
+
+fn write_save(data: &SaveData) -> Result<(), String> {
+    let text = ron::ser::to_string_pretty(data, ron::ser::PrettyConfig::default())
+        .map_err(|err| err.to_string())?;
+    if let Some(dir) = Path::new(SAVE_PATH).parent() {
+        fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+    }
+    fs::write(SAVE_PATH, text).map_err(|err| err.to_string())
+}
+
+fn read_save() -> Result<SaveData, String> {
+    let text = fs::read_to_string(SAVE_PATH).map_err(|err| err.to_string())?;
+    ron::from_str(&text).map_err(|err| err.to_string())
+}
@@ -0,0 +1,160 @@
+// src/save/world.rs
+//! Reading the world's changes off the live game and replaying them onto a
+//! freshly generated one. Saves use both; so does a co-op host handing its
+//! world to a joining player.
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use super::data::{AddedEntity, EntityChange, WorldDiff};
+use crate::collision::{CollisionMap, TileMarker, TileType};
+use crate::farming::{spawn_crop, till_tile, Crop};
+use crate::harvest::ResourceNode;
+use crate::map::assets::TilemapHandles;
+use crate::map::world_id::WorldEntityId;
+use crate::map::TileChanged;
+use crate::placement::{spawn_placed, Placed, PlacedObjects};
+use crate::rendering::YSort;
+
+/// Everything needed to describe how the live world differs from its
+/// generated state.
+#[derive(SystemParam)]
+pub struct WorldChanges<'w, 's> {
+    diff: Res<'w, WorldDiff>,
+    nodes: Query<'w, 's, (&'static WorldEntityId, &'static ResourceNode)>,
+    placed_objects: Query<'w, 's, &'static Placed>,
+    crops: Query<'w, 's, &'static Crop>,
+    collision_map: Option<Res<'w, CollisionMap>>,
+}
+
+impl WorldChanges<'_, '_> {
+    /// The recorded removals plus node, placement, crop and tilling state
+    /// read from the live world.
+    pub fn snapshot(&self) -> WorldDiff {
+        let mut world = self.diff.clone();
+        world.modified = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| node.depleted)
+            .map(|(id, node)| {
+                let change = EntityChange::Depleted {
+                    regrow_remaining: node.regrow_remaining(),
+                };
+                (*id, change)
+            })
+            .collect();
+        world.added = self
+            .placed_objects
+            .iter()
+            .map(|placed| AddedEntity::Placed {
+                kind: placed.kind,
+                cell: placed.cell.to_array(),
+            })
+            .chain(self.crops.iter().map(|crop| AddedEntity::Crop {
+                cell: crop.cell.to_array(),
+                stage: crop.stage,
+                elapsed: crop.growth.elapsed_secs(),
+            }))
+            .collect();
+        world.tilled = self
+            .collision_map
+            .as_deref()
+            .map_or_else(Vec::new, tilled_cells);
+        world
+    }
+}
+
+fn tilled_cells(map: &CollisionMap) -> Vec<[i32; 2]> {
+    (0..map.height())
+        .flat_map(|y| (0..map.width()).map(move |x| [x, y]))
+        .filter(|&[x, y]| map.get_tile(x, y) == Some(TileType::TilledSoil))
+        .collect()
+}
+
+/// Everything needed to replay a `WorldDiff` onto a freshly generated world.
+#[derive(SystemParam)]
+pub struct WorldRestorer<'w, 's> {
+    commands: Commands<'w, 's>,
+    collision_map: Option<ResMut<'w, CollisionMap>>,
+    tilemap_handles: Option<Res<'w, TilemapHandles>>,
+    placed: ResMut<'w, PlacedObjects>,
+    ground_tiles: Query<
+        'w,
+        's,
+        (
+            &'static mut TileMarker,
+            &'static mut Sprite,
+            &'static Transform,
+        ),
+        Without<YSort>,
+    >,
+    world_entities: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static WorldEntityId,
+            Option<&'static mut ResourceNode>,
+        ),
+    >,
+}
+
+impl WorldRestorer<'_, '_> {
+    /// Tills the soil, respawns what the player added (crops go back onto
+    /// their farmland) and removes or changes generated entities as `world`
+    /// records. Returns false, having done nothing, before the map is built.
+    pub fn restore(&mut self, world: &WorldDiff) -> bool {
+        let (Some(map), Some(handles)) = (
+            self.collision_map.as_deref_mut(),
+            self.tilemap_handles.as_deref(),
+        ) else {
+            return false;
+        };
+
+        for &[x, y] in &world.tilled {
+            let cell = IVec2::new(x, y);
+            if let Some(from) = till_tile(map, cell, &mut self.ground_tiles) {
+                self.commands.trigger(TileChanged {
+                    cell,
+                    from,
+                    to: TileType::TilledSoil,
+                });
+            }
+        }
+
+        for added in &world.added {
+            match *added {
+                AddedEntity::Placed { kind, cell: [x, y] } => {
+                    let cell = IVec2::new(x, y);
+                    if !self.placed.is_occupied(cell) {
+                        spawn_placed(&mut self.commands, map, &mut self.placed, kind, cell);
+                    }
+                }
+                AddedEntity::Crop {
+                    cell: [x, y],
+                    stage,
+                    elapsed,
+                } => spawn_crop(
+                    &mut self.commands,
+                    map,
+                    handles,
+                    IVec2::new(x, y),
+                    stage,
+                    elapsed,
+                ),
+            }
+        }
+
+        for (entity, id, node) in self.world_entities.iter_mut() {
+            if world.removed.contains(id) {
+                self.commands.entity(entity).despawn();
+                continue;
+            }
+            if let (Some(EntityChange::Depleted { regrow_remaining }), Some(mut node)) =
+                (world.modified.get(id), node)
+            {
+                node.restore_depleted(*regrow_remaining);
+            }
+        }
+        true
+    }
+}
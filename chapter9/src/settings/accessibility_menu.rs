@@ -0,0 +1,156 @@
+// src/settings/accessibility_menu.rs
+use bevy::ecs::relationship::RelatedSpawnerCommands;
+use bevy::prelude::*;
+
+use super::game_settings::{FogPalette, GameSettings};
+use crate::config::ui::UI_SCALE_STEPS;
+
+const OPTION_BUTTON_COLOR: Color = Color::srgb(0.25, 0.25, 0.3);
+
+/// Accessibility settings that a button cycles through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessibilityOption {
+    FogTint,
+    FogEdge,
+    UiScale,
+}
+
+impl AccessibilityOption {
+    const ALL: [AccessibilityOption; 3] = [
+        AccessibilityOption::FogTint,
+        AccessibilityOption::FogEdge,
+        AccessibilityOption::UiScale,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            AccessibilityOption::FogTint => "Fog tint",
+            AccessibilityOption::FogEdge => "Fog edge",
+            AccessibilityOption::UiScale => "UI scale",
+        }
+    }
+
+    fn value_text(&self, settings: &GameSettings) -> String {
+        match self {
+            AccessibilityOption::FogTint => settings.fog_palette.label().to_string(),
+            AccessibilityOption::FogEdge => {
+                if settings.hard_fog_edge { "Hard outline" } else { "Soft fade" }.to_string()
+            }
+            AccessibilityOption::UiScale => format!("{:.0}%", settings.ui_scale * 100.0),
+        }
+    }
+
+    /// Moves the setting to its next value, wrapping around.
+    fn cycle(&self, settings: &mut GameSettings) {
+        match self {
+            AccessibilityOption::FogTint => {
+                let index = FogPalette::ALL
+                    .iter()
+                    .position(|palette| *palette == settings.fog_palette)
+                    .unwrap_or(0);
+                settings.fog_palette = FogPalette::ALL[(index + 1) % FogPalette::ALL.len()];
+            }
+            AccessibilityOption::FogEdge => settings.hard_fog_edge = !settings.hard_fog_edge,
+            AccessibilityOption::UiScale => {
+                // A hand-edited scale between steps moves to the next step up
+                settings.ui_scale = UI_SCALE_STEPS
+                    .into_iter()
+                    .find(|step| *step > settings.ui_scale + f32::EPSILON)
+                    .unwrap_or(UI_SCALE_STEPS[0]);
+            }
+        }
+    }
+}
+
+/// Button that cycles an accessibility setting.
+#[derive(Component)]
+pub struct AccessibilityButton(pub AccessibilityOption);
+
+/// Text inside an accessibility button showing the current value.
+#[derive(Component)]
+pub struct AccessibilityLabel(pub AccessibilityOption);
+
+/// Adds one row per `AccessibilityOption` under `parent`.
+pub fn spawn_accessibility_options(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    settings: &GameSettings,
+) {
+    let font = TextFont {
+        font_size: 18.0,
+        ..default()
+    };
+
+    for option in AccessibilityOption::ALL {
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                margin: UiRect::top(Val::Px(6.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new(option.label()),
+                    font.clone(),
+                    TextColor(Color::WHITE),
+                    Node {
+                        width: Val::Px(140.0),
+                        ..default()
+                    },
+                ));
+                row.spawn((
+                    AccessibilityButton(option),
+                    Button,
+                    Node {
+                        width: Val::Px(220.0),
+                        height: Val::Px(28.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(OPTION_BUTTON_COLOR),
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        AccessibilityLabel(option),
+                        Text::new(option.value_text(settings)),
+                        font.clone(),
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            });
+    }
+}
+
+pub fn cycle_accessibility_options(
+    buttons: Query<(&Interaction, &AccessibilityButton), Changed<Interaction>>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            button.0.cycle(&mut settings);
+        }
+    }
+}
+
+/// Redraws the option values after the settings change.
+pub fn refresh_accessibility_labels(
+    settings: Res<GameSettings>,
+    mut labels: Query<(&AccessibilityLabel, &mut Text)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (label, mut text) in labels.iter_mut() {
+        **text = label.0.value_text(&settings);
+    }
+}
+
+/// Scales every UI node by the player's chosen factor.
+pub fn apply_ui_scale(settings: Res<GameSettings>, mut ui_scale: ResMut<UiScale>) {
+    let scale = settings.ui_scale.clamp(UI_SCALE_STEPS[0], UI_SCALE_STEPS[UI_SCALE_STEPS.len() - 1]);
+    if ui_scale.0 != scale {
+        ui_scale.0 = scale;
+    }
+}
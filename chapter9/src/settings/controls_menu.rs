@@ -0,0 +1,154 @@
+// src/settings/controls_menu.rs
+use bevy::ecs::relationship::RelatedSpawnerCommands;
+use bevy::prelude::*;
+
+use crate::controls::{key_name, ControlBindings, InputAction};
+use crate::ui::ShowToast;
+
+const REBIND_BUTTON_COLOR: Color = Color::srgb(0.25, 0.25, 0.3);
+const WAITING_BUTTON_COLOR: Color = Color::srgb(0.45, 0.38, 0.15);
+
+/// The action whose key is being captured, if a rebind is in progress.
+#[derive(Resource, Default)]
+pub struct AwaitingRebind(pub Option<InputAction>);
+
+/// Button that starts rebinding an action.
+#[derive(Component)]
+pub struct RebindButton(pub InputAction);
+
+/// Text inside a rebind button showing the current keys.
+#[derive(Component)]
+pub struct RebindLabel(pub InputAction);
+
+/// Adds one row per `InputAction` with a button to rebind it under `parent`.
+pub fn spawn_controls_options(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    bindings: &ControlBindings,
+) {
+    let font = TextFont {
+        font_size: 18.0,
+        ..default()
+    };
+
+    for action in InputAction::ALL {
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                margin: UiRect::top(Val::Px(4.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new(action.label()),
+                    font.clone(),
+                    TextColor(Color::WHITE),
+                    Node {
+                        width: Val::Px(140.0),
+                        ..default()
+                    },
+                ));
+                row.spawn((
+                    RebindButton(action),
+                    Button,
+                    Node {
+                        width: Val::Px(220.0),
+                        height: Val::Px(28.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(REBIND_BUTTON_COLOR),
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        RebindLabel(action),
+                        Text::new(bindings.describe(action)),
+                        font.clone(),
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            });
+    }
+}
+
+pub fn start_rebind(
+    buttons: Query<(&Interaction, &RebindButton), Changed<Interaction>>,
+    mut awaiting: ResMut<AwaitingRebind>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            awaiting.0 = Some(button.0);
+        }
+    }
+}
+
+/// Binds the next key pressed to the awaited action; Escape cancels.
+///
+/// Runs right after input is collected and clears the key, so the press
+/// doesn't also reach gameplay or menus (e.g. Escape closing the pause menu).
+pub fn capture_rebind(
+    mut commands: Commands,
+    mut input: ResMut<ButtonInput<KeyCode>>,
+    mut awaiting: ResMut<AwaitingRebind>,
+    mut bindings: ResMut<ControlBindings>,
+) {
+    let Some(action) = awaiting.0 else {
+        return;
+    };
+    let Some(key) = input.get_just_pressed().next().copied() else {
+        return;
+    };
+    input.clear_just_pressed(key);
+    awaiting.0 = None;
+
+    if key == KeyCode::Escape {
+        return;
+    }
+
+    if let Some(other) = bindings.rebind(action, key) {
+        commands.trigger(ShowToast::new(format!(
+            "{} was used by {}, which now uses {}",
+            key_name(key),
+            other.label(),
+            bindings.describe(other)
+        )));
+    }
+    info!("Bound {} to {}", action.label(), bindings.describe(action));
+
+    if let Err(err) = bindings.save() {
+        warn!("Failed to save controls: {}", err);
+    }
+}
+
+pub fn cancel_rebind(mut awaiting: ResMut<AwaitingRebind>) {
+    awaiting.0 = None;
+}
+
+/// Redraws key labels after a rebind starts, finishes, or is cancelled.
+pub fn refresh_rebind_labels(
+    bindings: Res<ControlBindings>,
+    awaiting: Res<AwaitingRebind>,
+    mut labels: Query<(&RebindLabel, &mut Text)>,
+    mut buttons: Query<(&RebindButton, &mut BackgroundColor)>,
+) {
+    if !bindings.is_changed() && !awaiting.is_changed() {
+        return;
+    }
+
+    for (label, mut text) in labels.iter_mut() {
+        **text = if awaiting.0 == Some(label.0) {
+            "Press a key (Esc cancels)".to_string()
+        } else {
+            bindings.describe(label.0)
+        };
+    }
+    for (button, mut color) in buttons.iter_mut() {
+        color.0 = if awaiting.0 == Some(button.0) {
+            WAITING_BUTTON_COLOR
+        } else {
+            REBIND_BUTTON_COLOR
+        };
+    }
+}
@@ -0,0 +1,75 @@
+// src/settings/difficulty.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::difficulty::{
+    ENEMY_BUDGET, NIGHT_VISION, OBSTACLE_DENSITY, PICKUP_ABUNDANCE, STAMINA_DRAIN, WATER_DENSITY,
+};
+
+/// How forgiving the world is, picked on the main menu for a new game and
+/// kept in its save.
+///
+/// Generation and survival systems read their scale factors from here
+/// instead of branching on the preset, so every difficulty runs the same
+/// code. `Normal` scales everything by 1.
+#[derive(
+    Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect,
+)]
+#[reflect(Resource)]
+pub enum Difficulty {
+    Casual,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Casual, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Difficulty::Casual => "Casual",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// The preset after this one, wrapping around.
+    pub fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Scale on the weight of lakes and ponds in generation
+    pub fn water_density(self) -> f32 {
+        WATER_DENSITY[self.index()]
+    }
+
+    /// Scale on the weight of trees, rocks and stumps in generation
+    pub fn obstacle_density(self) -> f32 {
+        OBSTACLE_DENSITY[self.index()]
+    }
+
+    /// Scale on the weight of pickable plants and berry bushes in generation
+    pub fn pickup_abundance(self) -> f32 {
+        PICKUP_ABUNDANCE[self.index()]
+    }
+
+    /// Scale on stamina spent while running
+    pub fn stamina_drain(self) -> f32 {
+        STAMINA_DRAIN[self.index()]
+    }
+
+    /// Scale on the vision radius at full night
+    pub fn night_vision(self) -> f32 {
+        NIGHT_VISION[self.index()]
+    }
+
+    /// How many enemies spawn into a new world
+    pub fn enemy_budget(self) -> usize {
+        ENEMY_BUDGET[self.index()]
+    }
+}
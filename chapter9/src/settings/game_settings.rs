@@ -0,0 +1,114 @@
+// src/settings/game_settings.rs
+use std::fs;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::fog::{FOG_COLOR, FOG_COLOR_BLUE, FOG_COLOR_PURPLE};
+use crate::config::settings::SETTINGS_PATH;
+
+/// Fog tints; the alternatives stay distinct from the green and brown
+/// terrain for the common kinds of colour blindness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum FogPalette {
+    #[default]
+    Standard,
+    Blue,
+    Purple,
+}
+
+impl FogPalette {
+    pub const ALL: [FogPalette; 3] = [FogPalette::Standard, FogPalette::Blue, FogPalette::Purple];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FogPalette::Standard => "Standard",
+            FogPalette::Blue => "Blue",
+            FogPalette::Purple => "Purple",
+        }
+    }
+
+    pub fn fog_color(&self) -> Color {
+        match self {
+            FogPalette::Standard => FOG_COLOR,
+            FogPalette::Blue => FOG_COLOR_BLUE,
+            FogPalette::Purple => FOG_COLOR_PURPLE,
+        }
+    }
+}
+
+/// Player-adjustable settings read by the rest of the game.
+///
+/// Volumes range from 0.0 (silent) to 1.0, as does `rumble_intensity`,
+/// which scales gamepad rumble when `rumble` is on. `ui_scale` multiplies
+/// the size of every HUD element and menu. Stored in `SETTINGS_PATH` so
+/// they carry over between sessions.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct GameSettings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub muted: bool,
+    pub rumble: bool,
+    pub rumble_intensity: f32,
+    pub fog_palette: FogPalette,
+    /// Replace the fog's soft fade with a sharp, outlined boundary
+    pub hard_fog_edge: bool,
+    pub ui_scale: f32,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 0.6,
+            sfx_volume: 0.8,
+            muted: false,
+            rumble: true,
+            rumble_intensity: 1.0,
+            fog_palette: FogPalette::default(),
+            hard_fog_edge: false,
+            ui_scale: 1.0,
+        }
+    }
+}
+
+impl GameSettings {
+    /// Effective music volume after master volume and mute
+    pub fn music_gain(&self) -> f32 {
+        self.effective(self.music_volume)
+    }
+
+    /// Effective sound effect (and ambient) volume after master volume and mute
+    pub fn sfx_gain(&self) -> f32 {
+        self.effective(self.sfx_volume)
+    }
+
+    /// Scale applied to gamepad rumble; 0.0 when rumble is switched off
+    pub fn rumble_gain(&self) -> f32 {
+        if self.rumble { self.rumble_intensity.clamp(0.0, 1.0) } else { 0.0 }
+    }
+
+    fn effective(&self, volume: f32) -> f32 {
+        if self.muted { 0.0 } else { self.master_volume * volume }
+    }
+
+    /// Read settings from disk, falling back to defaults if missing or invalid.
+    pub fn load() -> Self {
+        let Ok(text) = fs::read_to_string(SETTINGS_PATH) else {
+            return Self::default();
+        };
+        ron::from_str(&text).unwrap_or_else(|err| {
+            warn!("Ignoring invalid {}: {}", SETTINGS_PATH, err);
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|err| err.to_string())?;
+        fs::write(SETTINGS_PATH, text).map_err(|err| err.to_string())
+    }
+}
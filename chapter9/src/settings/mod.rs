@@ -0,0 +1,60 @@
+// src/settings/mod.rs
+mod accessibility_menu;
+mod controls_menu;
+mod difficulty;
+mod game_settings;
+mod options_menu;
+mod systems;
+
+use bevy::input::InputSystems;
+use bevy::prelude::*;
+use crate::state::GameState;
+
+pub use difficulty::Difficulty;
+pub use game_settings::GameSettings;
+pub use options_menu::spawn_options_menu;
+
+/// Plugin owning player-facing settings: loading and saving them, the mute
+/// hotkey, and the audio, controls and accessibility options shown in the
+/// pause and main menus.
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        let in_menu = in_state(GameState::Paused).or(in_state(GameState::MainMenu));
+
+        app.insert_resource(GameSettings::load())
+            .init_resource::<Difficulty>()
+            .init_resource::<systems::PendingSettingsSave>()
+            .init_resource::<controls_menu::AwaitingRebind>()
+            .add_systems(Update, (systems::toggle_mute, systems::persist_settings).chain())
+            .add_systems(
+                Update,
+                accessibility_menu::apply_ui_scale.run_if(resource_changed::<GameSettings>),
+            )
+            .add_systems(
+                Update,
+                (
+                    options_menu::switch_options_tab,
+                    (options_menu::drag_volume_sliders, options_menu::refresh_volume_options)
+                        .chain(),
+                    (controls_menu::start_rebind, controls_menu::refresh_rebind_labels).chain(),
+                    (
+                        accessibility_menu::cycle_accessibility_options,
+                        accessibility_menu::refresh_accessibility_labels,
+                    )
+                        .chain(),
+                )
+                    .run_if(in_menu.clone()),
+            )
+            // Captures before anything in Update can react to the key
+            .add_systems(
+                PreUpdate,
+                controls_menu::capture_rebind
+                    .after(InputSystems)
+                    .run_if(in_menu),
+            )
+            .add_systems(OnExit(GameState::Paused), controls_menu::cancel_rebind)
+            .add_systems(OnExit(GameState::MainMenu), controls_menu::cancel_rebind);
+    }
+}
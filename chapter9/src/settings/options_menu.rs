@@ -0,0 +1,291 @@
+// src/settings/options_menu.rs
+use bevy::ecs::relationship::RelatedSpawnerCommands;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use super::accessibility_menu::spawn_accessibility_options;
+use super::controls_menu::spawn_controls_options;
+use super::game_settings::GameSettings;
+use crate::controls::ControlBindings;
+
+const SLIDER_WIDTH: f32 = 220.0;
+const SLIDER_HEIGHT: f32 = 14.0;
+const TAB_COLOR: Color = Color::srgb(0.18, 0.18, 0.22);
+const ACTIVE_TAB_COLOR: Color = Color::srgb(0.45, 0.38, 0.15);
+
+/// Pages of the options menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionsTab {
+    Audio,
+    Controls,
+    Accessibility,
+}
+
+impl OptionsTab {
+    const ALL: [OptionsTab; 3] = [OptionsTab::Audio, OptionsTab::Controls, OptionsTab::Accessibility];
+
+    fn label(&self) -> &'static str {
+        match self {
+            OptionsTab::Audio => "Audio",
+            OptionsTab::Controls => "Controls",
+            OptionsTab::Accessibility => "Accessibility",
+        }
+    }
+}
+
+/// Button that switches the options menu to its tab.
+#[derive(Component)]
+pub struct OptionsTabButton(pub OptionsTab);
+
+/// Content of one options tab; only the selected one is displayed.
+#[derive(Component)]
+pub struct OptionsTabPanel(pub OptionsTab);
+
+/// Which volume a slider edits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeChannel {
+    Master,
+    Music,
+    Sfx,
+}
+
+impl VolumeChannel {
+    const ALL: [VolumeChannel; 3] = [VolumeChannel::Master, VolumeChannel::Music, VolumeChannel::Sfx];
+
+    fn label(&self) -> &'static str {
+        match self {
+            VolumeChannel::Master => "Master",
+            VolumeChannel::Music => "Music",
+            VolumeChannel::Sfx => "Effects",
+        }
+    }
+
+    fn value_mut<'a>(&self, settings: &'a mut GameSettings) -> &'a mut f32 {
+        match self {
+            VolumeChannel::Master => &mut settings.master_volume,
+            VolumeChannel::Music => &mut settings.music_volume,
+            VolumeChannel::Sfx => &mut settings.sfx_volume,
+        }
+    }
+
+    fn value(&self, settings: &GameSettings) -> f32 {
+        match self {
+            VolumeChannel::Master => settings.master_volume,
+            VolumeChannel::Music => settings.music_volume,
+            VolumeChannel::Sfx => settings.sfx_volume,
+        }
+    }
+}
+
+/// Clickable track of a volume slider.
+#[derive(Component)]
+pub struct VolumeSlider(pub VolumeChannel);
+
+/// Filled part of a volume slider.
+#[derive(Component)]
+pub struct VolumeSliderFill(pub VolumeChannel);
+
+/// Percentage label next to a volume slider.
+#[derive(Component)]
+pub struct VolumeSliderLabel(pub VolumeChannel);
+
+/// Line showing whether audio is muted.
+#[derive(Component)]
+pub struct MuteLabel;
+
+fn mute_text(settings: &GameSettings) -> String {
+    format!("Muted: {}  (M to toggle)", if settings.muted { "yes" } else { "no" })
+}
+
+/// Adds the tabbed options menu (audio, controls and accessibility) under `parent`.
+pub fn spawn_options_menu(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    settings: &GameSettings,
+    bindings: &ControlBindings,
+) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(8.0),
+            margin: UiRect::bottom(Val::Px(8.0)),
+            ..default()
+        })
+        .with_children(|tabs| {
+            for tab in OptionsTab::ALL {
+                tabs.spawn((
+                    OptionsTabButton(tab),
+                    Button,
+                    Node {
+                        padding: UiRect::axes(Val::Px(16.0), Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(if tab == OptionsTab::Audio { ACTIVE_TAB_COLOR } else { TAB_COLOR }),
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new(tab.label()),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+
+    for tab in OptionsTab::ALL {
+        parent
+            .spawn((
+                OptionsTabPanel(tab),
+                Node {
+                    display: if tab == OptionsTab::Audio { Display::Flex } else { Display::None },
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+            ))
+            .with_children(|panel| match tab {
+                OptionsTab::Audio => spawn_volume_options(panel, settings),
+                OptionsTab::Controls => spawn_controls_options(panel, bindings),
+                OptionsTab::Accessibility => spawn_accessibility_options(panel, settings),
+            });
+    }
+}
+
+pub fn switch_options_tab(
+    buttons: Query<(&Interaction, &OptionsTabButton), Changed<Interaction>>,
+    mut tab_colors: Query<(&OptionsTabButton, &mut BackgroundColor)>,
+    mut panels: Query<(&OptionsTabPanel, &mut Node)>,
+) {
+    let Some(selected) = buttons
+        .iter()
+        .find(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, button)| button.0)
+    else {
+        return;
+    };
+
+    for (button, mut color) in tab_colors.iter_mut() {
+        color.0 = if button.0 == selected { ACTIVE_TAB_COLOR } else { TAB_COLOR };
+    }
+    for (panel, mut node) in panels.iter_mut() {
+        node.display = if panel.0 == selected { Display::Flex } else { Display::None };
+    }
+}
+
+/// Adds the audio options (one slider per volume) under `parent`.
+fn spawn_volume_options(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    settings: &GameSettings,
+) {
+    let font = TextFont {
+        font_size: 20.0,
+        ..default()
+    };
+
+    for channel in VolumeChannel::ALL {
+        let value = channel.value(settings);
+        parent
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new(channel.label()),
+                    font.clone(),
+                    TextColor(Color::WHITE),
+                    Node {
+                        width: Val::Px(90.0),
+                        ..default()
+                    },
+                ));
+                row.spawn((
+                    VolumeSlider(channel),
+                    Interaction::default(),
+                    RelativeCursorPosition::default(),
+                    Node {
+                        width: Val::Px(SLIDER_WIDTH),
+                        height: Val::Px(SLIDER_HEIGHT),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.25, 0.25, 0.3)),
+                ))
+                .with_children(|track| {
+                    track.spawn((
+                        VolumeSliderFill(channel),
+                        Node {
+                            width: Val::Percent(value * 100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.85, 0.75, 0.35)),
+                    ));
+                });
+                row.spawn((
+                    VolumeSliderLabel(channel),
+                    Text::new(format!("{:>3.0}%", value * 100.0)),
+                    font.clone(),
+                    TextColor(Color::WHITE),
+                ));
+            });
+    }
+
+    parent.spawn((
+        MuteLabel,
+        Text::new(mute_text(settings)),
+        font,
+        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+        Node {
+            margin: UiRect::top(Val::Px(12.0)),
+            ..default()
+        },
+    ));
+}
+
+/// Sets a volume from where its slider is pressed; holding and dragging works too.
+pub fn drag_volume_sliders(
+    sliders: Query<(&VolumeSlider, &Interaction, &RelativeCursorPosition)>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (slider, interaction, cursor) in sliders.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(normalized) = cursor.normalized else {
+            continue;
+        };
+        // Normalized coordinates run from -0.5 (left) to 0.5 (right)
+        let value = (normalized.x + 0.5).clamp(0.0, 1.0);
+        let current = slider.0.value_mut(&mut settings);
+        if (*current - value).abs() > f32::EPSILON {
+            *current = value;
+        }
+    }
+}
+
+/// Redraws slider fills and labels after the settings change.
+pub fn refresh_volume_options(
+    settings: Res<GameSettings>,
+    mut fills: Query<(&VolumeSliderFill, &mut Node)>,
+    mut labels: Query<(&VolumeSliderLabel, &mut Text), Without<MuteLabel>>,
+    mut mute_labels: Query<&mut Text, With<MuteLabel>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    for (fill, mut node) in fills.iter_mut() {
+        node.width = Val::Percent(fill.0.value(&settings) * 100.0);
+    }
+    for (label, mut text) in labels.iter_mut() {
+        **text = format!("{:>3.0}%", label.0.value(&settings) * 100.0);
+    }
+    for mut text in mute_labels.iter_mut() {
+        **text = mute_text(&settings);
+    }
+}
@@ -0,0 +1,52 @@
+// src/settings/systems.rs
+use bevy::prelude::*;
+
+use super::game_settings::GameSettings;
+use crate::config::settings::SAVE_DELAY_SECS;
+use crate::controls::{ControlBindings, InputAction};
+
+/// Press the mute key (M) to toggle mute.
+pub fn toggle_mute(
+    input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<ControlBindings>,
+    mut settings: ResMut<GameSettings>,
+) {
+    if bindings.just_pressed(InputAction::Mute, &input) {
+        settings.muted = !settings.muted;
+        info!("Audio {}", if settings.muted { "muted" } else { "unmuted" });
+    }
+}
+
+/// Waits for settings to stop changing (e.g. a slider drag) before writing them out.
+#[derive(Resource)]
+pub struct PendingSettingsSave(Timer);
+
+impl Default for PendingSettingsSave {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(SAVE_DELAY_SECS, TimerMode::Once);
+        // Start finished so nothing is written until the first change
+        timer.tick(timer.duration());
+        Self(timer)
+    }
+}
+
+pub fn persist_settings(
+    time: Res<Time<Real>>,
+    settings: Res<GameSettings>,
+    mut pending: ResMut<PendingSettingsSave>,
+) {
+    if settings.is_changed() && !settings.is_added() {
+        pending.0.reset();
+        return;
+    }
+    if pending.0.is_finished() {
+        return;
+    }
+
+    pending.0.tick(time.delta());
+    if pending.0.just_finished()
+        && let Err(err) = settings.save()
+    {
+        warn!("Failed to save settings: {}", err);
+    }
+}
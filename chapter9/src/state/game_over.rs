@@ -3,9 +3,10 @@ use bevy::prelude::*;
 
 use crate::characters::spawn::PlayerSpawned;
 use crate::combat::healthbar::HealthBarOwner;
-use crate::combat::systems::{Projectile, ProjectileEffect};
+use crate::combat::systems::ProjectileEffect;
 use crate::enemy::{spawn::EnemiesSpawned, Enemy};
 use crate::particles::components::{Particle, ParticleEmitter};
+use crate::projectile::Projectile;
 
 use super::GameState;
 
@@ -3,6 +3,7 @@ use bevy::prelude::*;
 #[derive(States, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum GameState {
     #[default]
+    MainMenu,
     Loading,
     Playing,
     Paused,
@@ -2,11 +2,23 @@ use std::sync::atomic::Ordering;
 
 use bevy::prelude::*;
 
+use crate::audio::AmbientEmittersSpawned;
+use crate::collision::{CollisionMap, CollisionMapBuilt};
+use crate::harvest::ShoreNodesSpawned;
 use crate::map::generate::{MapGenProgress, MapReady};
+use crate::map::regions::Regions;
+use crate::map::world_id::WorldEntityId;
+use crate::map::Structures;
+use crate::rendering::ChunkMeshesBuilt;
 
 #[derive(Component)]
 pub struct LoadingScreen;
 
+/// Marks entities that belong to the current world without being generated
+/// with it (the player, crops, emitters...), so reloading clears them too.
+#[derive(Component, Default)]
+pub struct WorldScoped;
+
 #[derive(Component)]
 pub struct LoadingText;
 
@@ -65,4 +77,31 @@ pub fn despawn_loading_screen(
     }
     
     info!("Loading screen despawned");
-}
\ No newline at end of file
+}
+
+/// Clears a previously generated world so the map can be rebuilt.
+///
+/// A no-op on first launch; when loading a save it removes the old map
+/// and every `WorldScoped` entity.
+pub fn despawn_generated_world(
+    mut commands: Commands,
+    generated: Query<Entity, With<WorldEntityId>>,
+    scoped: Query<Entity, With<WorldScoped>>,
+    mut collision_built: ResMut<CollisionMapBuilt>,
+    mut shore_spawned: ResMut<ShoreNodesSpawned>,
+    mut emitters_spawned: ResMut<AmbientEmittersSpawned>,
+    mut chunk_meshes_built: ResMut<ChunkMeshesBuilt>,
+) {
+    for entity in generated.iter().chain(scoped.iter()) {
+        commands.entity(entity).despawn();
+    }
+
+    commands.remove_resource::<MapReady>();
+    commands.remove_resource::<CollisionMap>();
+    commands.remove_resource::<Regions>();
+    commands.remove_resource::<Structures>();
+    collision_built.0 = false;
+    shore_spawned.0 = false;
+    emitters_spawned.0 = false;
+    chunk_meshes_built.0 = false;
+}
@@ -0,0 +1,282 @@
+// src/state/main_menu.rs
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+
+use super::GameState;
+use crate::audio::{PlaySfx, SfxId};
+use crate::map::generate::MapSeed;
+use crate::save::{queue_load, save_exists};
+use crate::controls::ControlBindings;
+use crate::settings::{spawn_options_menu, Difficulty, GameSettings};
+
+const BUTTON_COLOR: Color = Color::srgb(0.18, 0.18, 0.22);
+const HOVERED_BUTTON_COLOR: Color = Color::srgb(0.28, 0.28, 0.34);
+const PRESSED_BUTTON_COLOR: Color = Color::srgb(0.45, 0.38, 0.15);
+const DISABLED_TEXT_COLOR: Color = Color::srgb(0.45, 0.45, 0.45);
+
+/// Longest seed that always fits in a `u64`.
+const MAX_SEED_DIGITS: usize = 19;
+
+#[derive(Component)]
+pub struct MainMenu;
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum MenuButton {
+    NewGame,
+    /// Cycles the difficulty the next new game starts on
+    Difficulty,
+    Continue,
+    Options,
+    Quit,
+}
+
+impl MenuButton {
+    fn label(&self) -> &'static str {
+        match self {
+            MenuButton::NewGame => "New Game",
+            MenuButton::Difficulty => "Difficulty",
+            MenuButton::Continue => "Continue",
+            MenuButton::Options => "Options",
+            MenuButton::Quit => "Quit",
+        }
+    }
+}
+
+/// Digits typed on the main menu; empty means a random seed.
+#[derive(Resource, Default)]
+pub struct SeedEntry(pub String);
+
+#[derive(Component)]
+pub struct SeedEntryText;
+
+/// Label of the difficulty button, showing the current preset.
+#[derive(Component)]
+pub struct DifficultyText;
+
+fn difficulty_label(difficulty: Difficulty) -> String {
+    format!("Difficulty: {}", difficulty.label())
+}
+
+/// The options panel, hidden until the Options button is pressed.
+#[derive(Component)]
+pub struct MenuOptionsPanel;
+
+fn seed_entry_label(entry: &SeedEntry) -> String {
+    if entry.0.is_empty() {
+        "Seed: random (type digits to choose)".to_string()
+    } else {
+        format!("Seed: {}", entry.0)
+    }
+}
+
+pub fn spawn_main_menu(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    bindings: Res<ControlBindings>,
+    difficulty: Res<Difficulty>,
+) {
+    commands.insert_resource(SeedEntry::default());
+    let can_continue = save_exists();
+
+    commands
+        .spawn((
+            MainMenu,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.1, 0.1, 0.15)),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Bevy Game"),
+                TextFont {
+                    font_size: 64.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::bottom(Val::Px(24.0)),
+                    ..default()
+                },
+            ));
+
+            for button in [
+                MenuButton::NewGame,
+                MenuButton::Difficulty,
+                MenuButton::Continue,
+                MenuButton::Options,
+                MenuButton::Quit,
+            ] {
+                let disabled = button == MenuButton::Continue && !can_continue;
+                let mut entity = parent.spawn((
+                    button,
+                    Node {
+                        width: Val::Px(260.0),
+                        height: Val::Px(52.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(BUTTON_COLOR),
+                ));
+                // Disabled buttons (Continue without a save) never get an Interaction
+                if !disabled {
+                    entity.insert(Button);
+                }
+                let caption = match button {
+                    MenuButton::Difficulty => difficulty_label(*difficulty),
+                    _ => button.label().to_string(),
+                };
+                entity.with_children(|label| {
+                    let mut text = label.spawn((
+                        Text::new(caption),
+                        TextFont {
+                            font_size: 28.0,
+                            ..default()
+                        },
+                        TextColor(if disabled { DISABLED_TEXT_COLOR } else { Color::WHITE }),
+                    ));
+                    if button == MenuButton::Difficulty {
+                        text.insert(DifficultyText);
+                    }
+                });
+            }
+
+            parent.spawn((
+                SeedEntryText,
+                Text::new(seed_entry_label(&SeedEntry::default())),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.75, 0.75, 0.75)),
+                Node {
+                    margin: UiRect::top(Val::Px(12.0)),
+                    ..default()
+                },
+            ));
+
+            parent
+                .spawn((
+                    MenuOptionsPanel,
+                    Node {
+                        display: Display::None,
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::top(Val::Px(16.0)),
+                        ..default()
+                    },
+                ))
+                .with_children(|panel| spawn_options_menu(panel, &settings, &bindings));
+        });
+
+    info!("Main menu spawned");
+}
+
+pub fn despawn_main_menu(mut commands: Commands, query: Query<Entity, With<MainMenu>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+    commands.remove_resource::<SeedEntry>();
+
+    info!("Main menu despawned");
+}
+
+/// Collects typed digits into the seed entry; Backspace deletes.
+pub fn type_seed(
+    mut keys: MessageReader<KeyboardInput>,
+    mut entry: ResMut<SeedEntry>,
+    mut texts: Query<&mut Text, With<SeedEntryText>>,
+) {
+    let mut changed = false;
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed {
+            continue;
+        }
+        match &key.logical_key {
+            Key::Character(chars) => {
+                for c in chars.chars().filter(char::is_ascii_digit) {
+                    if entry.0.len() < MAX_SEED_DIGITS {
+                        entry.0.push(c);
+                        changed = true;
+                    }
+                }
+            }
+            Key::Backspace => changed |= entry.0.pop().is_some(),
+            _ => {}
+        }
+    }
+
+    if changed {
+        for mut text in texts.iter_mut() {
+            **text = seed_entry_label(&entry);
+        }
+    }
+}
+
+pub fn style_menu_buttons(
+    mut buttons: Query<(&Interaction, &mut BackgroundColor), With<MenuButton>>,
+) {
+    for (interaction, mut color) in buttons.iter_mut() {
+        color.set_if_neq(BackgroundColor(match interaction {
+            Interaction::Pressed => PRESSED_BUTTON_COLOR,
+            Interaction::Hovered => HOVERED_BUTTON_COLOR,
+            Interaction::None => BUTTON_COLOR,
+        }));
+    }
+}
+
+pub fn handle_menu_buttons(
+    mut commands: Commands,
+    buttons: Query<(&Interaction, &MenuButton), Changed<Interaction>>,
+    entry: Res<SeedEntry>,
+    mut seed: ResMut<MapSeed>,
+    mut difficulty: ResMut<Difficulty>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut options: Query<&mut Node, With<MenuOptionsPanel>>,
+    mut difficulty_texts: Query<&mut Text, With<DifficultyText>>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    for (interaction, button) in buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        commands.trigger(PlaySfx(SfxId::UiClick));
+
+        match button {
+            MenuButton::NewGame => {
+                // Digits are capped so the parse only fails on overflow
+                seed.0 = entry.0.parse().unwrap_or_else(|_| rand::random());
+                info!("Starting new game with seed {} on {}", seed.0, difficulty.label());
+                next_state.set(GameState::Loading);
+            }
+            MenuButton::Difficulty => {
+                *difficulty = difficulty.next();
+                for mut text in difficulty_texts.iter_mut() {
+                    **text = difficulty_label(*difficulty);
+                }
+            }
+            MenuButton::Continue => {
+                queue_load(&mut commands, &mut seed, &mut next_state);
+            }
+            MenuButton::Options => {
+                for mut node in options.iter_mut() {
+                    node.display = match node.display {
+                        Display::None => Display::Flex,
+                        _ => Display::None,
+                    };
+                }
+            }
+            MenuButton::Quit => {
+                exit.write(AppExit::Success);
+            }
+        }
+    }
+}
@@ -1,14 +1,18 @@
 mod game_over;
 mod game_state;
 mod loading;
+mod main_menu;
 mod pause;
 
 use bevy::prelude::*;
 use crate::characters::spawn::CharactersListResource;
 use crate::characters::config::CharactersList;
 use crate::map::generate::MapReady;
+use crate::audio::{PlaySfx, SfxId};
+use crate::controls::{ControlBindings, InputAction};
 
 pub use game_state::GameState;
+pub use loading::WorldScoped;
 
 pub struct StatePlugin;
 
@@ -16,9 +20,22 @@ impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
         app
             .init_state::<GameState>()
+
+            // Main menu systems
+            .add_systems(OnEnter(GameState::MainMenu), main_menu::spawn_main_menu)
+            .add_systems(OnExit(GameState::MainMenu), main_menu::despawn_main_menu)
+            .add_systems(Update, (
+                main_menu::type_seed,
+                main_menu::style_menu_buttons,
+                main_menu::handle_menu_buttons,
+            ).run_if(in_state(GameState::MainMenu)))
             
             // Loading state systems
-            .add_systems(OnEnter(GameState::Loading), loading::spawn_loading_screen)
+            .add_systems(OnEnter(GameState::Loading), (
+                loading::spawn_loading_screen,
+                loading::despawn_generated_world,
+                game_over::cleanup_game_world,
+            ))
             .add_systems(Update, (
                 check_assets_loaded,
                 loading::animate_loading,
@@ -46,7 +63,7 @@ impl Plugin for StatePlugin {
     }
 }
 
-fn check_assets_loaded(
+pub(crate) fn check_assets_loaded(
     characters_list_res: Option<Res<CharactersListResource>>,
     characters_lists: Res<Assets<CharactersList>>,
     map_ready: Option<Res<MapReady>>,
@@ -63,11 +80,14 @@ fn check_assets_loaded(
 }
 
 fn toggle_pause(
+    mut commands: Commands,
     input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<ControlBindings>,
     current_state: Res<State<GameState>>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    if input.just_pressed(KeyCode::Escape) {
+    if bindings.just_pressed(InputAction::Pause, &input) {
+        commands.trigger(PlaySfx(SfxId::UiClick));
         match current_state.get() {
             GameState::Playing => {
                 info!("Game paused");
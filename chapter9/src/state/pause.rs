@@ -1,14 +1,24 @@
 use bevy::prelude::*;
 
+use crate::controls::{ControlBindings, InputAction};
+use crate::settings::{spawn_options_menu, GameSettings};
+use crate::stats::{spawn_stats_screen, Statistics};
+
 #[derive(Component)]
 pub struct PauseMenu;
 
-pub fn spawn_pause_menu(mut commands: Commands) {
+pub fn spawn_pause_menu(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    bindings: Res<ControlBindings>,
+    statistics: Res<Statistics>,
+) {
     commands.spawn((
         PauseMenu,
         Node {
             width: Val::Percent(100.0),
             height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
             justify_content: JustifyContent::Center,
             align_items: AlignItems::Center,
             ..default()
@@ -16,14 +26,23 @@ pub fn spawn_pause_menu(mut commands: Commands) {
         BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
     )).with_children(|parent| {
         parent.spawn((
-            Text::new("PAUSED\n\nPress ESC to resume"),
+            Text::new(format!(
+                "PAUSED\n\nPress {} to resume",
+                bindings.describe(InputAction::Pause)
+            )),
             TextFont {
                 font_size: 36.0,
                 ..default()
             },
             TextColor(Color::WHITE),
             TextLayout::new_with_justify(Justify::Center),
+            Node {
+                margin: UiRect::bottom(Val::Px(24.0)),
+                ..default()
+            },
         ));
+        spawn_options_menu(parent, &settings, &bindings);
+        spawn_stats_screen(parent, &statistics);
     });
     
     info!("Pause menu spawned");
@@ -0,0 +1,85 @@
+// src/stats/achievements.rs
+use super::statistics::Statistics;
+use crate::collision::TileType;
+use crate::inventory::ItemKind;
+
+/// A goal checked against the player's `Statistics`.
+pub struct Achievement {
+    /// Stable key stored in saves
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub is_met: fn(&Statistics) -> bool,
+}
+
+impl Achievement {
+    pub fn is_unlocked(&self, stats: &Statistics) -> bool {
+        stats.unlocked.contains(self.id)
+    }
+}
+
+const FISH: [ItemKind; 3] = [ItemKind::Trout, ItemKind::Carp, ItemKind::Pike];
+
+/// Every achievement, in the order the stats screen lists them.
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "first_steps",
+        name: "First Steps",
+        description: "Walk 100 tiles",
+        is_met: |stats| stats.total_walked() >= 100.0,
+    },
+    Achievement {
+        id: "wanderer",
+        name: "Wanderer",
+        description: "Walk 2000 tiles",
+        is_met: |stats| stats.total_walked() >= 2000.0,
+    },
+    Achievement {
+        id: "beachcomber",
+        name: "Beachcomber",
+        description: "Walk 200 tiles along the shore",
+        is_met: |stats| stats.walked_on(TileType::Shore) >= 200.0,
+    },
+    Achievement {
+        id: "gatherer",
+        name: "Gatherer",
+        description: "Collect 25 items",
+        is_met: |stats| stats.total_collected() >= 25,
+    },
+    Achievement {
+        id: "hoarder",
+        name: "Hoarder",
+        description: "Collect 250 items",
+        is_met: |stats| stats.total_collected() >= 250,
+    },
+    Achievement {
+        id: "angler",
+        name: "Angler",
+        description: "Catch 10 fish",
+        is_met: |stats| stats.collected_of(&FISH) >= 10,
+    },
+    Achievement {
+        id: "green_thumb",
+        name: "Green Thumb",
+        description: "Reap 20 wheat",
+        is_met: |stats| stats.collected_of(&[ItemKind::Wheat]) >= 20,
+    },
+    Achievement {
+        id: "forager",
+        name: "Forager",
+        description: "Harvest 15 bushes or reed beds",
+        is_met: |stats| stats.total_harvested() >= 15,
+    },
+    Achievement {
+        id: "survivor",
+        name: "Survivor",
+        description: "Survive 3 days",
+        is_met: |stats| stats.days_survived >= 3,
+    },
+    Achievement {
+        id: "seasoned",
+        name: "Seasoned",
+        description: "Survive 7 days",
+        is_met: |stats| stats.days_survived >= 7,
+    },
+];
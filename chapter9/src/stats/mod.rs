@@ -0,0 +1,36 @@
+// src/stats/mod.rs
+mod achievements;
+mod screen;
+mod statistics;
+mod systems;
+
+use bevy::prelude::*;
+use crate::state::GameState;
+
+pub use screen::spawn_stats_screen;
+pub use statistics::Statistics;
+
+/// Plugin for gameplay statistics and the achievements unlocked from them.
+///
+/// Statistics are counted from existing gameplay events and saved with the
+/// game; the pause menu shows them alongside every achievement.
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Statistics>()
+            .add_observer(systems::count_pickup)
+            .add_observer(systems::count_collected)
+            .add_observer(systems::count_harvest)
+            .add_systems(
+                Update,
+                (
+                    systems::track_distance_walked,
+                    systems::count_days_survived,
+                    systems::check_achievements.run_if(resource_changed::<Statistics>),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
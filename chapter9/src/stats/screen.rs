@@ -0,0 +1,89 @@
+// src/stats/screen.rs
+use bevy::ecs::relationship::RelatedSpawnerCommands;
+use bevy::prelude::*;
+
+use super::achievements::ACHIEVEMENTS;
+use super::statistics::Statistics;
+
+const HEADING_COLOR: Color = Color::srgb(0.95, 0.8, 0.35);
+const LOCKED_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+
+/// Adds the statistics and achievements panel under `parent`.
+///
+/// It's a snapshot; the pause menu respawns it each time it opens.
+pub fn spawn_stats_screen(parent: &mut RelatedSpawnerCommands<ChildOf>, stats: &Statistics) {
+    parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(24.0),
+                right: Val::Px(24.0),
+                width: Val::Px(320.0),
+                max_height: Val::Percent(90.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(3.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                overflow: Overflow::clip_y(),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.9)),
+        ))
+        .with_children(|panel| {
+            heading(panel, "Statistics");
+            line(panel, format!("Days survived: {}", stats.days_survived), Color::WHITE);
+            line(panel, format!("Distance walked: {:.0} tiles", stats.total_walked()), Color::WHITE);
+            let mut walked: Vec<_> = stats.walked.iter().collect();
+            walked.sort_by(|a, b| b.1.total_cmp(a.1));
+            for (tile, tiles) in walked {
+                line(panel, format!("    {:?}: {:.0}", tile, tiles), LOCKED_COLOR);
+            }
+            line(panel, format!("Items collected: {}", stats.total_collected()), Color::WHITE);
+            let mut collected: Vec<_> = stats.collected.iter().collect();
+            collected.sort_by(|a, b| b.1.cmp(a.1));
+            for (kind, count) in collected {
+                line(panel, format!("    {}: {}", kind, count), LOCKED_COLOR);
+            }
+            line(panel, format!("Nodes harvested: {}", stats.total_harvested()), Color::WHITE);
+
+            let unlocked = ACHIEVEMENTS.iter().filter(|a| a.is_unlocked(stats)).count();
+            heading(panel, &format!("Achievements {}/{}", unlocked, ACHIEVEMENTS.len()));
+            for achievement in ACHIEVEMENTS {
+                let (mark, color) = if achievement.is_unlocked(stats) {
+                    ("*", HEADING_COLOR)
+                } else {
+                    ("-", LOCKED_COLOR)
+                };
+                line(
+                    panel,
+                    format!("{} {}: {}", mark, achievement.name, achievement.description),
+                    color,
+                );
+            }
+        });
+}
+
+fn heading(panel: &mut RelatedSpawnerCommands<ChildOf>, text: &str) {
+    panel.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: 22.0,
+            ..default()
+        },
+        TextColor(HEADING_COLOR),
+        Node {
+            margin: UiRect::vertical(Val::Px(4.0)),
+            ..default()
+        },
+    ));
+}
+
+fn line(panel: &mut RelatedSpawnerCommands<ChildOf>, text: String, color: Color) {
+    panel.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: 15.0,
+            ..default()
+        },
+        TextColor(color),
+    ));
+}
@@ -0,0 +1,54 @@
+// src/stats/statistics.rs
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::collision::TileType;
+use crate::harvest::ResourceNodeKind;
+use crate::inventory::ItemKind;
+
+/// Running totals for the current game, saved with it.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Statistics {
+    /// Tiles walked, by the terrain under the player's feet
+    pub walked: HashMap<TileType, f32>,
+    /// Items gathered from the world (not bought)
+    pub collected: HashMap<ItemKind, u32>,
+    /// Times each kind of resource node was harvested
+    pub harvested: HashMap<ResourceNodeKind, u32>,
+    /// In-game midnights passed
+    pub days_survived: u32,
+    /// Ids of achievements already unlocked, so each is announced once
+    pub unlocked: HashSet<String>,
+}
+
+impl Statistics {
+    pub fn total_walked(&self) -> f32 {
+        self.walked.values().sum()
+    }
+
+    pub fn walked_on(&self, tile: TileType) -> f32 {
+        self.walked.get(&tile).copied().unwrap_or(0.0)
+    }
+
+    pub fn total_collected(&self) -> u32 {
+        self.collected.values().sum()
+    }
+
+    /// Items gathered of any of the given kinds.
+    pub fn collected_of(&self, kinds: &[ItemKind]) -> u32 {
+        kinds
+            .iter()
+            .filter_map(|kind| self.collected.get(kind))
+            .sum()
+    }
+
+    pub fn total_harvested(&self) -> u32 {
+        self.harvested.values().sum()
+    }
+
+    pub fn add_collected(&mut self, kind: ItemKind, amount: u32) {
+        *self.collected.entry(kind).or_insert(0) += amount;
+    }
+}
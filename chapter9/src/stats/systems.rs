@@ -0,0 +1,90 @@
+// src/stats/systems.rs
+use bevy::prelude::*;
+
+use super::achievements::ACHIEVEMENTS;
+use super::statistics::Statistics;
+use crate::boat::Riding;
+use crate::characters::collider::Collider;
+use crate::characters::input::Player;
+use crate::clock::TimeOfDay;
+use crate::collision::CollisionMap;
+use crate::config::stats::MAX_STEP_TILES;
+use crate::event_log::{LogCategory, LogEvent};
+use crate::harvest::ResourceHarvested;
+use crate::inventory::{ItemPickedUp, ItemsCollected};
+use crate::ui::ShowToast;
+
+pub fn count_pickup(picked: On<ItemPickedUp>, mut stats: ResMut<Statistics>) {
+    stats.add_collected(picked.kind, 1);
+}
+
+pub fn count_collected(collected: On<ItemsCollected>, mut stats: ResMut<Statistics>) {
+    stats.add_collected(collected.kind, collected.amount);
+}
+
+pub fn count_harvest(harvested: On<ResourceHarvested>, mut stats: ResMut<Statistics>) {
+    *stats.harvested.entry(harvested.kind).or_insert(0) += 1;
+}
+
+/// Adds the distance the player walked this frame to the tile under their feet.
+///
+/// Boat trips don't count, and neither do jumps longer than `MAX_STEP_TILES`
+/// (teleports, loading a save).
+pub fn track_distance_walked(
+    collision_map: Option<Res<CollisionMap>>,
+    mut last: Local<Option<Vec2>>,
+    mut stats: ResMut<Statistics>,
+    player_query: Query<(&Transform, &Collider, Has<Riding>), With<Player>>,
+) {
+    let Some(map) = collision_map else {
+        return;
+    };
+    let Ok((transform, collider, riding)) = player_query.single() else {
+        *last = None;
+        return;
+    };
+
+    let feet = collider.world_position(transform);
+    let Some(previous) = last.replace(feet) else {
+        return;
+    };
+    let tiles = previous.distance(feet) / map.tile_size();
+    if riding || tiles == 0.0 || tiles > MAX_STEP_TILES {
+        return;
+    }
+    let cell = map.world_to_grid(feet);
+    if let Some(tile) = map.get_tile(cell.x, cell.y) {
+        *stats.walked.entry(tile).or_insert(0.0) += tiles;
+    }
+}
+
+/// Counts a survived day each time the clock wraps past midnight.
+pub fn count_days_survived(
+    clock: Res<TimeOfDay>,
+    mut last_hours: Local<Option<f32>>,
+    mut stats: ResMut<Statistics>,
+) {
+    if let Some(previous) = last_hours.replace(clock.hours)
+        && clock.hours < previous
+    {
+        stats.days_survived += 1;
+    }
+}
+
+/// Unlocks any achievement the statistics now meet and announces it.
+pub fn check_achievements(mut commands: Commands, mut stats: ResMut<Statistics>) {
+    for achievement in ACHIEVEMENTS {
+        if achievement.is_unlocked(&stats) || !(achievement.is_met)(&stats) {
+            continue;
+        }
+        stats.unlocked.insert(achievement.id.to_string());
+        commands.trigger(
+            ShowToast::new(format!("Achievement unlocked: {}", achievement.name))
+                .with_duration(5.0),
+        );
+        commands.trigger(LogEvent::new(
+            LogCategory::Achievement,
+            format!("Unlocked {} ({})", achievement.name, achievement.description),
+        ));
+    }
+}
@@ -0,0 +1,152 @@
+// src/status/components.rs
+use bevy::prelude::*;
+
+use crate::config::status::{
+    MAX_STACKS, MIRED_SPEED, SLOWED_SPEED, WARM_STAMINA_REGEN, WARM_VISION, WET_SPEED,
+    WET_STAMINA_REGEN,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// Wading through yellow grass
+    Slowed,
+    /// Soaked from the water; dries off over time or by a campfire
+    Wet,
+    /// Standing by a campfire
+    Warm,
+    /// Wading through deep mud
+    Mired,
+}
+
+impl StatusKind {
+    pub const ALL: [StatusKind; 4] =
+        [StatusKind::Slowed, StatusKind::Wet, StatusKind::Warm, StatusKind::Mired];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StatusKind::Slowed => "Slowed",
+            StatusKind::Wet => "Wet",
+            StatusKind::Warm => "Warm",
+            StatusKind::Mired => "Mired",
+        }
+    }
+
+    /// Background color of the effect's HUD icon.
+    pub fn color(self) -> Color {
+        match self {
+            StatusKind::Slowed => Color::srgb(0.75, 0.65, 0.2),
+            StatusKind::Wet => Color::srgb(0.2, 0.45, 0.8),
+            StatusKind::Warm => Color::srgb(0.85, 0.4, 0.15),
+            StatusKind::Mired => Color::srgb(0.4, 0.28, 0.15),
+        }
+    }
+
+    /// What a single stack of this effect does.
+    fn per_stack(self) -> StatusModifiers {
+        match self {
+            StatusKind::Slowed => StatusModifiers {
+                speed: SLOWED_SPEED,
+                ..StatusModifiers::NONE
+            },
+            StatusKind::Wet => StatusModifiers {
+                speed: WET_SPEED,
+                stamina_regen: WET_STAMINA_REGEN,
+                ..StatusModifiers::NONE
+            },
+            StatusKind::Warm => StatusModifiers {
+                stamina_regen: WARM_STAMINA_REGEN,
+                vision: WARM_VISION,
+                ..StatusModifiers::NONE
+            },
+            StatusKind::Mired => StatusModifiers {
+                speed: MIRED_SPEED,
+                ..StatusModifiers::NONE
+            },
+        }
+    }
+}
+
+/// Multipliers the active effects put on the player's stats.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatusModifiers {
+    pub speed: f32,
+    pub stamina_regen: f32,
+    pub vision: f32,
+}
+
+impl StatusModifiers {
+    pub const NONE: Self = Self {
+        speed: 1.0,
+        stamina_regen: 1.0,
+        vision: 1.0,
+    };
+
+    /// Combines with `stacks` stacks of `other`; each stack multiplies again.
+    fn stacked(self, other: Self, stacks: u32) -> Self {
+        let stacks = stacks as i32;
+        Self {
+            speed: self.speed * other.speed.powi(stacks),
+            stamina_regen: self.stamina_regen * other.stamina_regen.powi(stacks),
+            vision: self.vision * other.vision.powi(stacks),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub stacks: u32,
+    /// Seconds until the effect wears off
+    pub remaining: f32,
+}
+
+/// Timed effects on the player. Applying an active effect again adds a
+/// stack (up to `MAX_STACKS`) and refreshes its duration.
+#[derive(Component, Debug, Default)]
+pub struct StatusEffects {
+    effects: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    pub fn apply(&mut self, kind: StatusKind, duration: f32) {
+        match self.effects.iter_mut().find(|effect| effect.kind == kind) {
+            Some(effect) => {
+                effect.stacks = (effect.stacks + 1).min(MAX_STACKS);
+                effect.remaining = effect.remaining.max(duration);
+            }
+            None => self.effects.push(StatusEffect {
+                kind,
+                stacks: 1,
+                remaining: duration,
+            }),
+        }
+    }
+
+    /// Takes one stack off an effect, ending it when none are left.
+    pub fn remove_stack(&mut self, kind: StatusKind) {
+        if let Some(effect) = self.effects.iter_mut().find(|effect| effect.kind == kind) {
+            effect.stacks -= 1;
+        }
+        self.effects.retain(|effect| effect.stacks > 0);
+    }
+
+    /// Counts down every effect and drops the ones that ran out.
+    pub fn tick(&mut self, dt: f32) {
+        for effect in self.effects.iter_mut() {
+            effect.remaining -= dt;
+        }
+        self.effects.retain(|effect| effect.remaining > 0.0);
+    }
+
+    pub fn get(&self, kind: StatusKind) -> Option<&StatusEffect> {
+        self.effects.iter().find(|effect| effect.kind == kind)
+    }
+
+    pub fn modifiers(&self) -> StatusModifiers {
+        self.effects
+            .iter()
+            .fold(StatusModifiers::NONE, |modifiers, effect| {
+                modifiers.stacked(effect.kind.per_stack(), effect.stacks)
+            })
+    }
+}
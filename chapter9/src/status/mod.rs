@@ -0,0 +1,36 @@
+// src/status/mod.rs
+mod components;
+mod systems;
+
+use bevy::prelude::*;
+use crate::characters::physics::apply_velocity;
+use crate::state::GameState;
+
+pub use components::{StatusEffects, StatusKind};
+
+/// Plugin for timed status effects on the player, and hazard tiles.
+///
+/// Yellow grass slows, water soaks, campfires warm (and dry), mud bogs down
+/// and thorns hurt. Movement, stamina regen and vision read the combined
+/// multipliers from `StatusEffects`.
+pub struct StatusPlugin;
+
+impl Plugin for StatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            (
+                systems::tick_status_effects,
+                systems::apply_exposure,
+                systems::apply_hazards,
+            )
+                .chain()
+                .after(apply_velocity)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            systems::apply_vision_modifier.run_if(in_state(GameState::Playing)),
+        );
+    }
+}
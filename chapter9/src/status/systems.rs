@@ -0,0 +1,117 @@
+// src/status/systems.rs
+use bevy::prelude::*;
+
+use super::components::{StatusEffects, StatusKind};
+use crate::boat::Riding;
+use crate::characters::collider::Collider;
+use crate::characters::input::Player;
+use crate::clock::{GameClock, TimeOfDay};
+use crate::collision::{CollisionMap, Hazard, TileType};
+use crate::combat::{DamageEvent, Health};
+use crate::config::fog::VISION_RADIUS;
+use crate::config::status::{
+    EXPOSURE_TICK_SECS, MIRED_SECS, SLOWED_SECS, THORN_DAMAGE, WARM_SECS, WARMTH_RADIUS, WET_SECS,
+};
+use crate::fog::VisionRadius;
+use crate::placement::{PlaceableKind, Placed};
+use crate::settings::Difficulty;
+
+pub fn tick_status_effects(clock: Res<GameClock>, mut query: Query<&mut StatusEffects>) {
+    let dt = clock.delta_secs();
+    for mut effects in query.iter_mut() {
+        effects.tick(dt);
+    }
+}
+
+/// Applies the effects of the player's surroundings once per exposure tick,
+/// so standing in them longer builds up stacks.
+pub fn apply_exposure(
+    clock: Res<GameClock>,
+    mut since_tick: Local<f32>,
+    collision_map: Option<Res<CollisionMap>>,
+    campfires: Query<(&Placed, &Transform)>,
+    mut player_query: Query<(&Transform, &Collider, &mut StatusEffects, Has<Riding>), With<Player>>,
+) {
+    *since_tick += clock.delta_secs();
+    if *since_tick < EXPOSURE_TICK_SECS {
+        return;
+    }
+    *since_tick = 0.0;
+
+    let Some(map) = collision_map else {
+        return;
+    };
+    let Ok((transform, collider, mut effects, riding)) = player_query.single_mut() else {
+        return;
+    };
+
+    let feet = collider.world_position(transform);
+    let cell = map.world_to_grid(feet);
+    match map.get_tile(cell.x, cell.y) {
+        // A boat keeps the player dry
+        _ if riding => {}
+        Some(TileType::YellowGrass) => effects.apply(StatusKind::Slowed, SLOWED_SECS),
+        // No swimming yet: shallows and noclip into open water both soak
+        Some(TileType::Shore | TileType::Water) => effects.apply(StatusKind::Wet, WET_SECS),
+        _ => {}
+    }
+
+    let by_campfire = campfires.iter().any(|(placed, fire)| {
+        placed.kind == PlaceableKind::Campfire
+            && fire.translation.truncate().distance(feet) <= WARMTH_RADIUS
+    });
+    if by_campfire {
+        effects.apply(StatusKind::Warm, WARM_SECS);
+        effects.remove_stack(StatusKind::Wet);
+    }
+}
+
+/// Hurts or slows every entity standing on a hazard tile, once per exposure
+/// tick. Thorns damage anything with `Health`; mud only slows entities that
+/// carry `StatusEffects`.
+pub fn apply_hazards(
+    mut commands: Commands,
+    clock: Res<GameClock>,
+    mut since_tick: Local<f32>,
+    collision_map: Option<Res<CollisionMap>>,
+    mut entities: Query<(Entity, &Transform, &Collider, Option<&mut StatusEffects>), With<Health>>,
+) {
+    *since_tick += clock.delta_secs();
+    if *since_tick < EXPOSURE_TICK_SECS {
+        return;
+    }
+    *since_tick = 0.0;
+
+    let Some(map) = collision_map else {
+        return;
+    };
+    for (entity, transform, collider, effects) in entities.iter_mut() {
+        let cell = map.world_to_grid(collider.world_position(transform));
+        match map.get_tile(cell.x, cell.y).and_then(|tile| tile.hazard()) {
+            Some(Hazard::Thorns) => commands.trigger(DamageEvent {
+                target: entity,
+                amount: THORN_DAMAGE,
+                source: "thorns",
+            }),
+            Some(Hazard::Mud) => {
+                if let Some(mut effects) = effects {
+                    effects.apply(StatusKind::Mired, MIRED_SECS);
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+/// Scales the player's vision radius by their status effects, and at night
+/// by the difficulty's night vision.
+pub fn apply_vision_modifier(
+    clock: Res<TimeOfDay>,
+    difficulty: Res<Difficulty>,
+    mut player_query: Query<(&StatusEffects, &mut VisionRadius), With<Player>>,
+) {
+    let night = 1.0 + (difficulty.night_vision() - 1.0) * clock.darkness();
+    for (effects, mut vision) in player_query.iter_mut() {
+        vision.set_if_neq(VisionRadius(VISION_RADIUS * effects.modifiers().vision * night));
+    }
+}
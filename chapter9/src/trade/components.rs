@@ -0,0 +1,72 @@
+// src/trade/components.rs
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::inventory::ItemKind;
+
+/// What a trader has for sale after each restock.
+pub const TRADER_STOCK: &[(ItemKind, u32)] = &[
+    (ItemKind::Seeds, 10),
+    (ItemKind::Berry, 6),
+    (ItemKind::Wheat, 4),
+    (ItemKind::Trout, 2),
+    (ItemKind::Fence, 12),
+    (ItemKind::Torch, 4),
+    (ItemKind::Campfire, 1),
+    (ItemKind::Boat, 1),
+];
+
+/// A neutral NPC that sells its stock for coins and buys anything with a price.
+#[derive(Component, Debug)]
+pub struct Trader {
+    stock: HashMap<ItemKind, u32>,
+}
+
+impl Default for Trader {
+    fn default() -> Self {
+        let mut trader = Self {
+            stock: HashMap::new(),
+        };
+        trader.restock();
+        trader
+    }
+}
+
+impl Trader {
+    /// Resets the stock to `TRADER_STOCK`, dropping anything bought from the player.
+    pub fn restock(&mut self) {
+        self.stock = TRADER_STOCK.iter().copied().collect();
+    }
+
+    /// Stocked items sorted by display name.
+    pub fn sorted_stock(&self) -> Vec<(ItemKind, u32)> {
+        let mut items: Vec<(ItemKind, u32)> = self
+            .stock
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(kind, count)| (*kind, *count))
+            .collect();
+        items.sort_by_key(|(kind, _)| kind.display_name());
+        items
+    }
+
+    /// Takes one item out of stock, returns false if there is none left.
+    pub fn take(&mut self, kind: ItemKind) -> bool {
+        match self.stock.get_mut(&kind) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Puts an item the player sold up for sale.
+    pub fn add(&mut self, kind: ItemKind) {
+        *self.stock.entry(kind).or_insert(0) += 1;
+    }
+}
+
+/// The trader whose shop is open, if any.
+#[derive(Resource, Default, PartialEq, Eq)]
+pub struct OpenShop(pub Option<Entity>);
@@ -0,0 +1,20 @@
+// src/trade/events.rs
+use bevy::prelude::*;
+
+use crate::inventory::ItemKind;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeAction {
+    /// The player buys one item from the trader
+    Buy,
+    /// The player sells one item to the trader
+    Sell,
+}
+
+/// Trigger to trade a single item with a trader, e.g. from a shop button.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Trade {
+    pub trader: Entity,
+    pub kind: ItemKind,
+    pub action: TradeAction,
+}
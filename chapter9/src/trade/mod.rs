@@ -0,0 +1,40 @@
+// src/trade/mod.rs
+mod components;
+mod events;
+mod spawn;
+mod systems;
+
+use bevy::prelude::*;
+use crate::collision::CollisionMapBuilt;
+use crate::state::GameState;
+
+pub use components::{OpenShop, Trader};
+pub use events::{Trade, TradeAction};
+pub use spawn::TraderSpawned;
+
+/// Plugin for the trader NPC, who buys and sells items for coins.
+pub struct TradePlugin;
+
+impl Plugin for TradePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TraderSpawned>()
+            .init_resource::<OpenShop>()
+            .add_observer(systems::open_shop)
+            .add_observer(systems::handle_trade)
+            .add_systems(
+                Update,
+                spawn::spawn_trader
+                    .run_if(resource_equals(CollisionMapBuilt(true)))
+                    .run_if(resource_equals(TraderSpawned(false)))
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (systems::close_distant_shop, systems::restock_at_dawn)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnExit(GameState::Playing), systems::close_shop)
+            // The trader is world-scoped, so a freshly loaded world needs a new one
+            .add_systems(OnEnter(GameState::Loading), spawn::reset_trader_spawned);
+    }
+}
@@ -0,0 +1,96 @@
+// src/trade/spawn.rs
+use bevy::prelude::*;
+
+use super::components::Trader;
+use crate::characters::animation::{AnimationController, AnimationTimer, DEFAULT_ANIMATION_FRAME_TIME};
+use crate::characters::config::CharactersList;
+use crate::characters::facing::Facing;
+use crate::characters::spawn::{
+    character_feet_offset, create_character_atlas_layout, spawn_point, CharactersListResource,
+};
+use crate::characters::state::CharacterState;
+use crate::collision::CollisionMap;
+use crate::config::player::{COLLIDER_RADIUS, PLAYER_SCALE, PLAYER_Z_POSITION};
+use crate::config::trade::{TRADER_OFFSET_X, TRADER_OFFSET_Y};
+use crate::interaction::Interactable;
+use crate::map::Structures;
+use crate::rendering::YSort;
+use crate::state::WorldScoped;
+
+/// Character from `characters.ron` the trader is drawn as.
+const TRADER_CHARACTER: &str = "lantern_warden";
+
+/// Resource to track if this world's trader has been placed.
+#[derive(Resource, Default, PartialEq, Eq)]
+pub struct TraderSpawned(pub bool);
+
+pub fn reset_trader_spawned(mut spawned: ResMut<TraderSpawned>) {
+    spawned.0 = false;
+}
+
+/// Places the trader at the door of the ruin nearest the player's spawn
+/// point, or a short walk from spawn in a world without ruins.
+pub fn spawn_trader(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    characters_lists: Res<Assets<CharactersList>>,
+    characters_list_res: Option<Res<CharactersListResource>>,
+    collision_map: Option<Res<CollisionMap>>,
+    structures: Option<Res<Structures>>,
+    mut spawned: ResMut<TraderSpawned>,
+) {
+    let (Some(map), Some(list_res)) = (collision_map, characters_list_res) else {
+        return;
+    };
+    let Some(characters_list) = characters_lists.get(&list_res.handle) else {
+        return;
+    };
+    spawned.0 = true;
+
+    let Some(character_entry) = characters_list
+        .characters
+        .iter()
+        .find(|c| c.name == TRADER_CHARACTER)
+    else {
+        warn!("No '{}' character for the trader", TRADER_CHARACTER);
+        return;
+    };
+
+    let spawn = spawn_point(&map);
+    let nearest_ruin = structures.as_ref().and_then(|structures| {
+        structures
+            .0
+            .iter()
+            .copied()
+            .min_by(|a, b| a.distance_squared(spawn).total_cmp(&b.distance_squared(spawn)))
+    });
+    let desired = nearest_ruin.unwrap_or(spawn + Vec2::new(TRADER_OFFSET_X, TRADER_OFFSET_Y));
+    let position = if map.is_circle_clear(desired, COLLIDER_RADIUS) {
+        desired
+    } else {
+        map.find_nearest_walkable(map.world_to_grid(desired))
+            .map_or(desired, |cell| map.grid_to_world(cell.x, cell.y))
+    };
+
+    let layout = create_character_atlas_layout(&mut atlas_layouts, character_entry);
+    let texture = asset_server.load(&character_entry.texture_path);
+    commands.spawn((
+        Trader::default(),
+        Sprite::from_atlas_image(texture, TextureAtlas { layout, index: 0 }),
+        Transform::from_translation(position.extend(PLAYER_Z_POSITION))
+            .with_scale(Vec3::splat(PLAYER_SCALE)),
+        AnimationController::default(),
+        CharacterState::Idle,
+        Facing::default(),
+        YSort::new(character_feet_offset(character_entry)),
+        Interactable::default(),
+        AnimationTimer(Timer::from_seconds(
+            DEFAULT_ANIMATION_FRAME_TIME,
+            TimerMode::Repeating,
+        )),
+        character_entry.clone(),
+        WorldScoped,
+    ));
+    info!("Trader spawned at {:?}", position);
+}
@@ -0,0 +1,122 @@
+// src/trade/systems.rs
+use bevy::prelude::*;
+
+use super::components::{OpenShop, Trader};
+use super::events::{Trade, TradeAction};
+use crate::audio::{PlaySfx, SfxId};
+use crate::characters::input::Player;
+use crate::clock::TimeOfDay;
+use crate::config::trade::SHOP_CLOSE_DISTANCE;
+use crate::event_log::{LogCategory, LogEvent};
+use crate::interaction::Interact;
+use crate::inventory::{Inventory, ItemKind};
+use crate::ui::ShowToast;
+
+/// Observer that opens a trader's shop when the player interacts with it,
+/// or closes it if it was already open.
+pub fn open_shop(
+    interact: On<Interact>,
+    mut commands: Commands,
+    traders: Query<(), With<Trader>>,
+    mut shop: ResMut<OpenShop>,
+) {
+    if !traders.contains(interact.target) {
+        return;
+    }
+    shop.0 = if shop.0 == Some(interact.target) {
+        None
+    } else {
+        Some(interact.target)
+    };
+    commands.trigger(PlaySfx(SfxId::UiClick));
+}
+
+pub fn close_shop(mut shop: ResMut<OpenShop>) {
+    shop.set_if_neq(OpenShop(None));
+}
+
+/// Closes the shop once the player walks away from the trader.
+pub fn close_distant_shop(
+    mut shop: ResMut<OpenShop>,
+    player_query: Query<&Transform, With<Player>>,
+    traders: Query<&Transform, With<Trader>>,
+) {
+    let Some(trader) = shop.0 else {
+        return;
+    };
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+    let in_reach = traders.get(trader).is_ok_and(|transform| {
+        transform.translation.truncate().distance(player_transform.translation.truncate())
+            <= SHOP_CLOSE_DISTANCE
+    });
+    if !in_reach {
+        shop.0 = None;
+    }
+}
+
+/// Observer that moves one item and its price in coins between the player and a trader.
+pub fn handle_trade(
+    trade: On<Trade>,
+    mut commands: Commands,
+    mut traders: Query<&mut Trader>,
+    mut inventory: ResMut<Inventory>,
+) {
+    let Ok(mut trader) = traders.get_mut(trade.trader) else {
+        return;
+    };
+    let kind = trade.kind;
+
+    match trade.action {
+        TradeAction::Buy => {
+            let Some(price) = kind.price() else {
+                return;
+            };
+            if inventory.count(ItemKind::Coin) < price {
+                commands.trigger(ShowToast::new(format!("Not enough coins for {}", kind)));
+                return;
+            }
+            if !trader.take(kind) {
+                return;
+            }
+            inventory.remove(ItemKind::Coin, price);
+            inventory.add(kind);
+            commands.trigger(LogEvent::new(
+                LogCategory::Trade,
+                format!("Bought {} for {} coins", kind, price),
+            ));
+        }
+        TradeAction::Sell => {
+            let Some(price) = kind.sell_price() else {
+                return;
+            };
+            if !inventory.remove(kind, 1) {
+                return;
+            }
+            inventory.add_many(ItemKind::Coin, price);
+            trader.add(kind);
+            commands.trigger(LogEvent::new(
+                LogCategory::Trade,
+                format!("Sold {} for {} coins", kind, price),
+            ));
+        }
+    }
+    commands.trigger(PlaySfx(SfxId::Pickup));
+}
+
+/// Refills every trader's stock when night ends.
+pub fn restock_at_dawn(
+    clock: Res<TimeOfDay>,
+    mut was_night: Local<bool>,
+    mut traders: Query<&mut Trader>,
+) {
+    let is_night = clock.is_night();
+    if *was_night && !is_night {
+        for mut trader in traders.iter_mut() {
+            trader.restock();
+        }
+        info!("Traders restocked");
+    }
+    *was_night = is_night;
+}
@@ -0,0 +1,178 @@
+// src/ui/diagnostics_overlay.rs
+use bevy::diagnostic::{
+    DiagnosticPath, DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin,
+};
+use bevy::input::common_conditions::input_just_pressed;
+use bevy::prelude::*;
+
+use crate::collision::BUILD_TIME;
+use crate::config::ui::{FRAME_GRAPH_BARS, FRAME_GRAPH_HEIGHT, FRAME_GRAPH_MAX_MS};
+use crate::fog::MATERIAL_WRITES;
+use crate::map::diagnostics::{GENERATION_TIME, TILE_ENTITIES};
+
+const GOOD_FRAME_COLOR: Color = Color::srgb(0.3, 0.8, 0.35);
+const SLOW_FRAME_COLOR: Color = Color::srgb(0.9, 0.75, 0.2);
+const BAD_FRAME_COLOR: Color = Color::srgb(0.9, 0.25, 0.2);
+
+/// Plugin for the performance overlay in the bottom-left corner (F2).
+pub struct DiagnosticsOverlayPlugin;
+
+impl Plugin for DiagnosticsOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+        }
+        if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+            app.add_plugins(EntityCountDiagnosticsPlugin::default());
+        }
+
+        app.add_systems(Startup, spawn_diagnostics_overlay).add_systems(
+            Update,
+            (
+                toggle_diagnostics_overlay.run_if(input_just_pressed(KeyCode::F2)),
+                update_diagnostics_overlay.run_if(overlay_visible),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Root of the overlay; lives for the whole session and starts hidden.
+#[derive(Component)]
+pub struct DiagnosticsOverlay;
+
+#[derive(Component)]
+pub struct DiagnosticsText;
+
+/// One bar of the frame-time graph; 0 is the oldest frame shown.
+#[derive(Component)]
+pub struct FrameGraphBar(pub usize);
+
+fn spawn_diagnostics_overlay(mut commands: Commands) {
+    commands
+        .spawn((
+            DiagnosticsOverlay,
+            Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(16.0),
+                left: Val::Px(16.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.8)),
+            GlobalZIndex(20),
+        ))
+        .with_children(|overlay| {
+            overlay.spawn((
+                DiagnosticsText,
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            overlay
+                .spawn(Node {
+                    height: Val::Px(FRAME_GRAPH_HEIGHT),
+                    align_items: AlignItems::FlexEnd,
+                    column_gap: Val::Px(1.0),
+                    ..default()
+                })
+                .with_children(|graph| {
+                    for i in 0..FRAME_GRAPH_BARS {
+                        graph.spawn((
+                            FrameGraphBar(i),
+                            Node {
+                                width: Val::Px(2.0),
+                                height: Val::Px(0.0),
+                                ..default()
+                            },
+                            BackgroundColor(GOOD_FRAME_COLOR),
+                        ));
+                    }
+                });
+        });
+}
+
+fn toggle_diagnostics_overlay(mut overlays: Query<&mut Node, With<DiagnosticsOverlay>>) {
+    for mut node in overlays.iter_mut() {
+        node.display = match node.display {
+            Display::None => Display::Flex,
+            _ => Display::None,
+        };
+    }
+}
+
+fn overlay_visible(overlays: Query<&Node, With<DiagnosticsOverlay>>) -> bool {
+    overlays.iter().any(|node| node.display != Display::None)
+}
+
+/// Latest value of a diagnostic, or a dash before its first measurement.
+fn reading(store: &DiagnosticsStore, path: &DiagnosticPath, decimals: usize) -> String {
+    store
+        .get(path)
+        .and_then(|diagnostic| diagnostic.value())
+        .map_or_else(|| "-".to_string(), |value| format!("{:.*}", decimals, value))
+}
+
+fn frame_color(ms: f64) -> Color {
+    if ms <= 1000.0 / 60.0 {
+        GOOD_FRAME_COLOR
+    } else if ms <= 1000.0 / 30.0 {
+        SLOW_FRAME_COLOR
+    } else {
+        BAD_FRAME_COLOR
+    }
+}
+
+pub fn update_diagnostics_overlay(
+    store: Res<DiagnosticsStore>,
+    mut texts: Query<&mut Text, With<DiagnosticsText>>,
+    mut bars: Query<(&FrameGraphBar, &mut Node, &mut BackgroundColor)>,
+) {
+    let fps = store
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|diagnostic| diagnostic.smoothed())
+        .map_or_else(|| "-".to_string(), |fps| format!("{:.0}", fps));
+    let frame_time = store
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|diagnostic| diagnostic.smoothed())
+        .map_or_else(|| "-".to_string(), |ms| format!("{:.1}", ms));
+    // Averaged, since each frame either writes the fog or doesn't
+    let fog_writes = store
+        .get(&MATERIAL_WRITES)
+        .and_then(|diagnostic| diagnostic.average())
+        .map_or_else(|| "-".to_string(), |percent| format!("{:.0}", percent));
+
+    for mut text in texts.iter_mut() {
+        **text = format!(
+            "FPS: {} ({} ms)\nEntities: {}\nTile entities: {}\nCollision build: {} ms\nMap generation: {} ms\nFog writes: {}%",
+            fps,
+            frame_time,
+            reading(&store, &EntityCountDiagnosticsPlugin::ENTITY_COUNT, 0),
+            reading(&store, &TILE_ENTITIES, 0),
+            reading(&store, &BUILD_TIME, 1),
+            reading(&store, &GENERATION_TIME, 0),
+            fog_writes,
+        );
+    }
+
+    // Newest frames on the right; missing history leaves the left bars empty
+    let history: Vec<f64> = store
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .map(|diagnostic| diagnostic.values().copied().collect())
+        .unwrap_or_default();
+    let skip = FRAME_GRAPH_BARS.saturating_sub(history.len());
+    let recent = &history[history.len().saturating_sub(FRAME_GRAPH_BARS)..];
+
+    for (bar, mut node, mut color) in bars.iter_mut() {
+        let ms = bar.0.checked_sub(skip).and_then(|i| recent.get(i)).copied().unwrap_or(0.0);
+        let fill = (ms / FRAME_GRAPH_MAX_MS).min(1.0) as f32;
+        node.height = Val::Px(fill * FRAME_GRAPH_HEIGHT);
+        color.set_if_neq(BackgroundColor(frame_color(ms)));
+    }
+}
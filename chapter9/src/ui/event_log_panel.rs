@@ -0,0 +1,182 @@
+// src/ui/event_log_panel.rs
+use bevy::input::mouse::{AccumulatedMouseScroll, MouseScrollUnit};
+use bevy::prelude::*;
+
+use super::hud::Hud;
+use crate::controls::{action_just_pressed, ControlBindings, InputAction};
+use crate::event_log::EventLog;
+use crate::state::GameState;
+
+/// Pixels scrolled per mouse wheel line
+const SCROLL_LINE_PX: f32 = 24.0;
+
+/// Plugin for the scrollable history of gameplay events.
+pub struct EventLogPanelPlugin;
+
+impl Plugin for EventLogPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EventLogOpen>()
+            .add_systems(OnEnter(GameState::Playing), spawn_event_log_panel)
+            .add_systems(
+                Update,
+                (
+                    toggle_event_log.run_if(action_just_pressed(InputAction::EventLog)),
+                    rebuild_event_log.run_if(
+                        resource_changed::<EventLogOpen>.or(resource_changed::<EventLog>),
+                    ),
+                    scroll_event_log.run_if(resource_equals(EventLogOpen(true))),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Whether the event log screen is showing.
+#[derive(Resource, Default, PartialEq, Eq)]
+pub struct EventLogOpen(pub bool);
+
+/// Window around the log, hidden while the log is closed.
+#[derive(Component)]
+pub struct EventLogPanel;
+
+/// Scrolling column holding one row per entry, newest first.
+#[derive(Component)]
+pub struct EventLogList;
+
+pub fn spawn_event_log_panel(mut commands: Commands, bindings: Res<ControlBindings>) {
+    commands
+        .spawn((
+            Hud,
+            EventLogPanel,
+            Node {
+                display: Display::None,
+                position_type: PositionType::Absolute,
+                top: Val::Percent(12.0),
+                left: Val::Percent(25.0),
+                width: Val::Percent(50.0),
+                height: Val::Percent(70.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.92)),
+            GlobalZIndex(5),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(format!(
+                    "Event log  -  {} to close",
+                    bindings.describe(InputAction::EventLog)
+                )),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            panel.spawn((
+                EventLogList,
+                Node {
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                },
+                ScrollPosition::default(),
+            ));
+        });
+}
+
+pub fn toggle_event_log(mut open: ResMut<EventLogOpen>) {
+    open.0 = !open.0;
+}
+
+/// Shows or hides the window and refills the list while it's open.
+pub fn rebuild_event_log(
+    mut commands: Commands,
+    open: Res<EventLogOpen>,
+    log: Res<EventLog>,
+    mut panels: Query<&mut Node, With<EventLogPanel>>,
+    lists: Query<Entity, With<EventLogList>>,
+) {
+    let display = if open.0 { Display::Flex } else { Display::None };
+    for mut node in panels.iter_mut() {
+        node.display = display;
+    }
+    if !open.0 {
+        return;
+    }
+
+    for list in lists.iter() {
+        commands.entity(list).despawn_children();
+        commands.entity(list).with_children(|list| {
+            if log.is_empty() {
+                list.spawn((
+                    Text::new("Nothing has happened yet"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                ));
+            }
+            for entry in log.entries().rev() {
+                list.spawn(Node {
+                    column_gap: Val::Px(10.0),
+                    flex_shrink: 0.0,
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(entry.clock_label()),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                    ));
+                    row.spawn((
+                        Text::new(entry.category.label()),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(entry.category.color()),
+                        Node {
+                            width: Val::Px(96.0),
+                            ..default()
+                        },
+                    ));
+                    row.spawn((
+                        Text::new(entry.text.clone()),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+    }
+}
+
+/// Scrolls the list with the mouse wheel.
+pub fn scroll_event_log(
+    scroll: Res<AccumulatedMouseScroll>,
+    mut lists: Query<&mut ScrollPosition, With<EventLogList>>,
+) {
+    if scroll.delta.y == 0.0 {
+        return;
+    }
+    let pixels = match scroll.unit {
+        MouseScrollUnit::Line => scroll.delta.y * SCROLL_LINE_PX,
+        MouseScrollUnit::Pixel => scroll.delta.y,
+    };
+    for mut position in lists.iter_mut() {
+        position.y = (position.y - pixels).max(0.0);
+    }
+}
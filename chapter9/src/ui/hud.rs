@@ -0,0 +1,214 @@
+// src/ui/hud.rs
+use bevy::prelude::*;
+
+use crate::characters::input::Player;
+use crate::characters::stamina::Stamina;
+use crate::clock::TimeOfDay;
+use crate::combat::Health;
+use crate::inventory::{Hotbar, HotbarSlot, Inventory};
+use crate::state::GameState;
+
+const BAR_WIDTH: f32 = 200.0;
+const BAR_HEIGHT: f32 = 14.0;
+const SLOT_SIZE: f32 = 64.0;
+const SLOT_COLOR: Color = Color::srgba(0.1, 0.1, 0.12, 0.8);
+const SELECTED_SLOT_COLOR: Color = Color::srgba(0.45, 0.38, 0.15, 0.9);
+
+/// Plugin for the heads-up display shown while playing.
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Playing), spawn_hud)
+            .add_systems(OnExit(GameState::Playing), despawn_hud)
+            .add_systems(
+                Update,
+                (update_stat_bars, update_clock, update_hotbar_slots)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Root of the HUD, despawned when leaving `Playing`.
+#[derive(Component)]
+pub struct Hud;
+
+/// Which player stat a HUD bar shows.
+#[derive(Component, Clone, Copy)]
+pub enum StatBar {
+    Health,
+    Stamina,
+}
+
+#[derive(Component)]
+pub struct ClockText;
+
+/// A hotbar slot box and the index it displays.
+#[derive(Component)]
+pub struct HotbarSlotBox(pub usize);
+
+#[derive(Component)]
+pub struct HotbarSlotText(pub usize);
+
+fn spawn_stat_bar(parent: &mut ChildSpawnerCommands, bar: StatBar, color: Color) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(BAR_WIDTH),
+                height: Val::Px(BAR_HEIGHT),
+                margin: UiRect::bottom(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        ))
+        .with_children(|track| {
+            track.spawn((
+                bar,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(color),
+            ));
+        });
+}
+
+pub fn spawn_hud(mut commands: Commands, hotbar: Res<Hotbar>) {
+    commands
+        .spawn((
+            Hud,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::SpaceBetween,
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+        ))
+        .with_children(|root| {
+            // Top-left: stats and clock
+            root.spawn(Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            })
+            .with_children(|stats| {
+                spawn_stat_bar(stats, StatBar::Health, Color::srgb(0.8, 0.15, 0.15));
+                spawn_stat_bar(stats, StatBar::Stamina, Color::srgb(0.2, 0.7, 0.3));
+                stats.spawn((
+                    ClockText,
+                    Text::new(""),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+
+            // Bottom-center: hotbar
+            root.spawn(Node {
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(6.0),
+                ..default()
+            })
+            .with_children(|bar| {
+                for index in 0..hotbar.slots().len() {
+                    bar.spawn((
+                        HotbarSlotBox(index),
+                        Node {
+                            width: Val::Px(SLOT_SIZE),
+                            height: Val::Px(SLOT_SIZE),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(SLOT_COLOR),
+                    ))
+                    .with_children(|slot| {
+                        slot.spawn((
+                            HotbarSlotText(index),
+                            Text::new(""),
+                            TextFont {
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            TextLayout::new_with_justify(Justify::Center),
+                        ));
+                    });
+                }
+            });
+        });
+}
+
+pub fn despawn_hud(mut commands: Commands, query: Query<Entity, With<Hud>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn update_stat_bars(
+    player_query: Query<(&Health, &Stamina), With<Player>>,
+    mut bars: Query<(&StatBar, &mut Node)>,
+) {
+    let Ok((health, stamina)) = player_query.single() else {
+        return;
+    };
+
+    for (bar, mut node) in bars.iter_mut() {
+        let ratio = match bar {
+            StatBar::Health => health.ratio(),
+            StatBar::Stamina => stamina.ratio(),
+        };
+        let width = Val::Percent(ratio.clamp(0.0, 1.0) * 100.0);
+        if node.width != width {
+            node.width = width;
+        }
+    }
+}
+
+pub fn update_clock(clock: Res<TimeOfDay>, mut texts: Query<&mut Text, With<ClockText>>) {
+    let hours = clock.hours.floor() as u32;
+    let minutes = (clock.hours.fract() * 60.0).floor() as u32;
+    let period = if clock.is_night() { "Night" } else { "Day" };
+    let label = format!("{:02}:{:02}  {}", hours, minutes, period);
+
+    for mut text in texts.iter_mut() {
+        if **text != label {
+            **text = label.clone();
+        }
+    }
+}
+
+pub fn update_hotbar_slots(
+    hotbar: Res<Hotbar>,
+    inventory: Res<Inventory>,
+    mut boxes: Query<(&HotbarSlotBox, &mut BackgroundColor)>,
+    mut texts: Query<(&HotbarSlotText, &mut Text)>,
+) {
+    for (slot_box, mut color) in boxes.iter_mut() {
+        let target = if slot_box.0 == hotbar.selected_index() {
+            SELECTED_SLOT_COLOR
+        } else {
+            SLOT_COLOR
+        };
+        if color.0 != target {
+            color.0 = target;
+        }
+    }
+
+    for (slot_text, mut text) in texts.iter_mut() {
+        let slot = hotbar.slots()[slot_text.0];
+        let label = match slot {
+            HotbarSlot::Empty => String::new(),
+            HotbarSlot::Tool(_) => slot.to_string(),
+            HotbarSlot::Item(kind) => format!("{}\nx{}", slot, inventory.count(kind)),
+        };
+        if **text != label {
+            **text = label;
+        }
+    }
+}
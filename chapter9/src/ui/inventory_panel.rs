@@ -0,0 +1,181 @@
+// src/ui/inventory_panel.rs
+use bevy::ecs::relationship::RelatedSpawnerCommands;
+use bevy::prelude::*;
+
+use super::hud::Hud;
+use crate::config::ui::{INVENTORY_ICON_SIZE, PICKUP_HIGHLIGHT_SECS};
+use crate::inventory::{Inventory, ItemKind, ItemPickedUp};
+use crate::map::assets::TilemapHandles;
+use crate::map::tilemap::TILEMAP;
+use crate::state::GameState;
+
+const ROW_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.0);
+const HIGHLIGHT_COLOR: Color = Color::srgba(0.9, 0.8, 0.3, 0.45);
+
+/// Plugin for the item-count panel in the top-right corner of the HUD.
+pub struct InventoryPanelPlugin;
+
+impl Plugin for InventoryPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PickupHighlight>()
+            .add_observer(highlight_picked_up_item)
+            .add_systems(OnEnter(GameState::Playing), spawn_inventory_panel)
+            .add_systems(
+                Update,
+                (
+                    rebuild_inventory_rows.run_if(resource_changed::<Inventory>),
+                    fade_pickup_highlight,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Container whose children are one row per held item.
+#[derive(Component)]
+pub struct InventoryPanel;
+
+#[derive(Component)]
+pub struct InventoryRow(pub ItemKind);
+
+/// The item most recently picked up, flashed in the panel while the timer runs.
+#[derive(Resource)]
+pub struct PickupHighlight {
+    kind: Option<ItemKind>,
+    timer: Timer,
+}
+
+impl Default for PickupHighlight {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(PICKUP_HIGHLIGHT_SECS, TimerMode::Once);
+        timer.finish();
+        Self { kind: None, timer }
+    }
+}
+
+fn spawn_inventory_rows(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    inventory: &Inventory,
+    handles: Option<&TilemapHandles>,
+) {
+    let items = inventory.sorted_items();
+    if items.is_empty() {
+        parent.spawn((
+            Text::new("Inventory empty"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+        ));
+        return;
+    }
+
+    for (kind, count) in items {
+        parent
+            .spawn((
+                InventoryRow(kind),
+                Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    padding: UiRect::horizontal(Val::Px(4.0)),
+                    ..default()
+                },
+                BackgroundColor(ROW_COLOR),
+            ))
+            .with_children(|row| {
+                if let (Some(handles), Some(index)) =
+                    (handles, TILEMAP.sprite_index(kind.icon_sprite()))
+                {
+                    row.spawn((
+                        ImageNode::from_atlas_image(
+                            handles.image.clone(),
+                            TextureAtlas::from(handles.layout.clone()).with_index(index),
+                        ),
+                        Node {
+                            width: Val::Px(INVENTORY_ICON_SIZE),
+                            height: Val::Px(INVENTORY_ICON_SIZE),
+                            ..default()
+                        },
+                    ));
+                }
+                row.spawn((
+                    Text::new(format!("{} x{}", kind, count)),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+    }
+}
+
+pub fn spawn_inventory_panel(
+    mut commands: Commands,
+    inventory: Res<Inventory>,
+    handles: Option<Res<TilemapHandles>>,
+) {
+    commands
+        .spawn((
+            Hud,
+            InventoryPanel,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(16.0),
+                right: Val::Px(16.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.12, 0.7)),
+        ))
+        .with_children(|panel| spawn_inventory_rows(panel, &inventory, handles.as_deref()));
+}
+
+/// Rebuilds the rows only when the inventory actually changed.
+pub fn rebuild_inventory_rows(
+    mut commands: Commands,
+    inventory: Res<Inventory>,
+    handles: Option<Res<TilemapHandles>>,
+    panels: Query<Entity, With<InventoryPanel>>,
+) {
+    for panel in panels.iter() {
+        commands
+            .entity(panel)
+            .despawn_children()
+            .with_children(|panel| spawn_inventory_rows(panel, &inventory, handles.as_deref()));
+    }
+}
+
+fn highlight_picked_up_item(picked: On<ItemPickedUp>, mut highlight: ResMut<PickupHighlight>) {
+    highlight.kind = Some(picked.kind);
+    highlight.timer.reset();
+}
+
+pub fn fade_pickup_highlight(
+    time: Res<Time>,
+    mut highlight: ResMut<PickupHighlight>,
+    mut rows: Query<(&InventoryRow, &mut BackgroundColor)>,
+) {
+    if highlight.timer.is_finished() && highlight.kind.is_none() {
+        return;
+    }
+    highlight.timer.tick(time.delta());
+
+    let strength = 1.0 - highlight.timer.fraction();
+    let lit = HIGHLIGHT_COLOR.with_alpha(HIGHLIGHT_COLOR.alpha() * strength);
+    for (row, mut color) in rows.iter_mut() {
+        color.0 = if Some(row.0) == highlight.kind {
+            lit
+        } else {
+            ROW_COLOR
+        };
+    }
+
+    if highlight.timer.is_finished() {
+        highlight.kind = None;
+    }
+}
@@ -0,0 +1,31 @@
+// src/ui/mod.rs
+pub mod diagnostics_overlay;
+pub mod event_log_panel;
+pub mod hud;
+pub mod inventory_panel;
+pub mod region_caption;
+pub mod shop_panel;
+pub mod status_icons;
+pub mod toast;
+
+pub use toast::ShowToast;
+
+use bevy::prelude::*;
+
+/// Plugin for in-game UI overlays.
+pub struct GameUiPlugin;
+
+impl Plugin for GameUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            diagnostics_overlay::DiagnosticsOverlayPlugin,
+            event_log_panel::EventLogPanelPlugin,
+            hud::HudPlugin,
+            inventory_panel::InventoryPanelPlugin,
+            region_caption::RegionCaptionPlugin,
+            shop_panel::ShopPanelPlugin,
+            status_icons::StatusIconsPlugin,
+            toast::ToastPlugin,
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+// src/ui/region_caption.rs
+use bevy::prelude::*;
+
+use crate::config::ui::{REGION_CAPTION_FADE_SECS, REGION_CAPTION_SECS};
+use crate::map::RegionDiscovered;
+use crate::state::GameState;
+
+/// Plugin for the title shown across the screen on discovering a region.
+pub struct RegionCaptionPlugin;
+
+impl Plugin for RegionCaptionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(show_region_caption)
+            .add_systems(OnExit(GameState::Playing), despawn_region_captions)
+            .add_systems(Update, fade_region_captions.run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// A region title fading in, holding, then fading out.
+#[derive(Component)]
+pub struct RegionCaption {
+    shown: Timer,
+}
+
+fn show_region_caption(
+    discovered: On<RegionDiscovered>,
+    mut commands: Commands,
+    captions: Query<Entity, With<RegionCaption>>,
+) {
+    // A newer region replaces the title still on screen
+    for caption in captions.iter() {
+        commands.entity(caption).despawn();
+    }
+
+    commands
+        .spawn((
+            RegionCaption {
+                shown: Timer::from_seconds(REGION_CAPTION_SECS, TimerMode::Once),
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(18.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            GlobalZIndex(5),
+        ))
+        .with_children(|caption| {
+            caption.spawn((
+                Text::new(discovered.name.clone()),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE.with_alpha(0.0)),
+            ));
+            caption.spawn((
+                Text::new(format!("{:?}", discovered.biome)),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.8, 0.6).with_alpha(0.0)),
+            ));
+        });
+}
+
+/// Fades captions in and out and despawns them when done.
+pub fn fade_region_captions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut captions: Query<(Entity, &mut RegionCaption, &Children)>,
+    mut texts: Query<&mut TextColor>,
+) {
+    for (entity, mut caption, children) in captions.iter_mut() {
+        caption.shown.tick(time.delta());
+        if caption.shown.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let elapsed = caption.shown.elapsed_secs();
+        let left = caption.shown.remaining_secs();
+        let alpha = (elapsed.min(left) / REGION_CAPTION_FADE_SECS).min(1.0);
+        for child in children.iter() {
+            if let Ok(mut color) = texts.get_mut(child) {
+                color.0.set_alpha(alpha);
+            }
+        }
+    }
+}
+
+fn despawn_region_captions(mut commands: Commands, captions: Query<Entity, With<RegionCaption>>) {
+    for caption in captions.iter() {
+        commands.entity(caption).despawn();
+    }
+}
@@ -0,0 +1,293 @@
+// src/ui/shop_panel.rs
+use bevy::ecs::relationship::RelatedSpawnerCommands;
+use bevy::prelude::*;
+
+use super::hud::Hud;
+use crate::config::ui::INVENTORY_ICON_SIZE;
+use crate::controls::{ControlBindings, InputAction};
+use crate::inventory::{Inventory, ItemKind};
+use crate::map::assets::TilemapHandles;
+use crate::map::tilemap::TILEMAP;
+use crate::state::GameState;
+use crate::trade::{OpenShop, Trade, TradeAction, Trader};
+
+const BUTTON_COLOR: Color = Color::srgb(0.25, 0.25, 0.3);
+const HOVERED_BUTTON_COLOR: Color = Color::srgb(0.35, 0.35, 0.42);
+const PRESSED_BUTTON_COLOR: Color = Color::srgb(0.45, 0.38, 0.15);
+const UNAFFORDABLE_TEXT_COLOR: Color = Color::srgb(0.55, 0.4, 0.4);
+
+/// Plugin for the buy/sell window shown while a trader's shop is open.
+pub struct ShopPanelPlugin;
+
+impl Plugin for ShopPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Playing), spawn_shop_panel)
+            .add_systems(
+                Update,
+                (
+                    press_shop_buttons,
+                    style_shop_buttons,
+                    rebuild_shop_panel.run_if(
+                        resource_changed::<OpenShop>
+                            .or(resource_changed::<Inventory>)
+                            .or(trader_stock_changed),
+                    ),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Centered window listing the open trader's stock and what the player can sell.
+#[derive(Component)]
+pub struct ShopPanel;
+
+/// Buys or sells one of an item when pressed.
+#[derive(Component, Clone, Copy)]
+pub struct ShopButton {
+    pub kind: ItemKind,
+    pub action: TradeAction,
+}
+
+fn trader_stock_changed(traders: Query<(), Changed<Trader>>) -> bool {
+    !traders.is_empty()
+}
+
+pub fn spawn_shop_panel(mut commands: Commands) {
+    commands.spawn((
+        Hud,
+        ShopPanel,
+        Node {
+            display: Display::None,
+            position_type: PositionType::Absolute,
+            top: Val::Percent(18.0),
+            left: Val::Percent(30.0),
+            width: Val::Percent(40.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(8.0),
+            padding: UiRect::all(Val::Px(12.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.08, 0.08, 0.1, 0.92)),
+        GlobalZIndex(5),
+    ));
+}
+
+/// Redraws the window whenever the shop opens or closes, or either side's items change.
+pub fn rebuild_shop_panel(
+    mut commands: Commands,
+    shop: Res<OpenShop>,
+    inventory: Res<Inventory>,
+    bindings: Res<ControlBindings>,
+    handles: Option<Res<TilemapHandles>>,
+    traders: Query<&Trader>,
+    mut panels: Query<(Entity, &mut Node), With<ShopPanel>>,
+) {
+    let trader = shop.0.and_then(|entity| traders.get(entity).ok());
+    for (panel, mut node) in panels.iter_mut() {
+        commands.entity(panel).despawn_children();
+        let Some(trader) = trader else {
+            node.display = Display::None;
+            continue;
+        };
+        node.display = Display::Flex;
+        commands.entity(panel).with_children(|panel| {
+            spawn_shop_contents(panel, trader, &inventory, &bindings, handles.as_deref());
+        });
+    }
+}
+
+fn spawn_shop_contents(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    trader: &Trader,
+    inventory: &Inventory,
+    bindings: &ControlBindings,
+    handles: Option<&TilemapHandles>,
+) {
+    let coins = inventory.count(ItemKind::Coin);
+    parent.spawn((
+        Text::new(format!("Trader  -  you have {} coins", coins)),
+        TextFont {
+            font_size: 22.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+    ));
+
+    parent
+        .spawn(Node {
+            column_gap: Val::Px(24.0),
+            ..default()
+        })
+        .with_children(|columns| {
+            columns
+                .spawn(column_node())
+                .with_children(|column| {
+                    spawn_heading(column, "Buy");
+                    for (kind, count) in trader.sorted_stock() {
+                        let Some(price) = kind.price() else {
+                            continue;
+                        };
+                        spawn_item_row(
+                            column,
+                            handles,
+                            kind,
+                            format!("{} x{}", kind, count),
+                            ShopButton { kind, action: TradeAction::Buy },
+                            format!("{} c", price),
+                            coins >= price,
+                        );
+                    }
+                });
+
+            columns
+                .spawn(column_node())
+                .with_children(|column| {
+                    spawn_heading(column, "Sell");
+                    for (kind, count) in inventory.sorted_items() {
+                        let Some(price) = kind.sell_price() else {
+                            continue;
+                        };
+                        spawn_item_row(
+                            column,
+                            handles,
+                            kind,
+                            format!("{} x{}", kind, count),
+                            ShopButton { kind, action: TradeAction::Sell },
+                            format!("+{} c", price),
+                            true,
+                        );
+                    }
+                });
+        });
+
+    parent.spawn((
+        Text::new(format!(
+            "Press {} to close",
+            bindings.describe(InputAction::Interact)
+        )),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.7, 0.7, 0.7)),
+    ));
+}
+
+fn column_node() -> Node {
+    Node {
+        flex_grow: 1.0,
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(4.0),
+        ..default()
+    }
+}
+
+fn spawn_heading(parent: &mut RelatedSpawnerCommands<ChildOf>, text: &str) {
+    parent.spawn((
+        Text::new(text),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.9, 0.8, 0.3)),
+    ));
+}
+
+fn spawn_item_row(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    handles: Option<&TilemapHandles>,
+    kind: ItemKind,
+    label: String,
+    button: ShopButton,
+    price: String,
+    affordable: bool,
+) {
+    parent
+        .spawn(Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|row| {
+            if let (Some(handles), Some(index)) = (handles, TILEMAP.sprite_index(kind.icon_sprite())) {
+                row.spawn((
+                    ImageNode::from_atlas_image(
+                        handles.image.clone(),
+                        TextureAtlas::from(handles.layout.clone()).with_index(index),
+                    ),
+                    Node {
+                        width: Val::Px(INVENTORY_ICON_SIZE),
+                        height: Val::Px(INVENTORY_ICON_SIZE),
+                        ..default()
+                    },
+                ));
+            }
+            row.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    flex_grow: 1.0,
+                    ..default()
+                },
+            ));
+            row.spawn((
+                button,
+                Button,
+                Node {
+                    width: Val::Px(72.0),
+                    height: Val::Px(26.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(BUTTON_COLOR),
+            ))
+            .with_children(|button| {
+                button.spawn((
+                    Text::new(price),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(if affordable { Color::WHITE } else { UNAFFORDABLE_TEXT_COLOR }),
+                ));
+            });
+        });
+}
+
+pub fn press_shop_buttons(
+    mut commands: Commands,
+    shop: Res<OpenShop>,
+    buttons: Query<(&Interaction, &ShopButton), Changed<Interaction>>,
+) {
+    let Some(trader) = shop.0 else {
+        return;
+    };
+    for (interaction, button) in buttons.iter() {
+        if *interaction == Interaction::Pressed {
+            commands.trigger(Trade {
+                trader,
+                kind: button.kind,
+                action: button.action,
+            });
+        }
+    }
+}
+
+pub fn style_shop_buttons(
+    mut buttons: Query<(&Interaction, &mut BackgroundColor), With<ShopButton>>,
+) {
+    for (interaction, mut color) in buttons.iter_mut() {
+        color.set_if_neq(BackgroundColor(match interaction {
+            Interaction::Pressed => PRESSED_BUTTON_COLOR,
+            Interaction::Hovered => HOVERED_BUTTON_COLOR,
+            Interaction::None => BUTTON_COLOR,
+        }));
+    }
+}
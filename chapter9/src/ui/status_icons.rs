@@ -0,0 +1,106 @@
+// src/ui/status_icons.rs
+use bevy::prelude::*;
+
+use super::hud::Hud;
+use crate::characters::input::Player;
+use crate::state::GameState;
+use crate::status::{StatusEffects, StatusKind};
+
+const ICON_SIZE: f32 = 44.0;
+
+/// Plugin for the row of active status effects under the stat bars.
+pub struct StatusIconsPlugin;
+
+impl Plugin for StatusIconsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Playing), spawn_status_icons)
+            .add_systems(
+                Update,
+                update_status_icons.run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// One icon per status effect; hidden while the effect isn't active.
+#[derive(Component)]
+pub struct StatusIcon(pub StatusKind);
+
+#[derive(Component)]
+pub struct StatusIconText(pub StatusKind);
+
+pub fn spawn_status_icons(mut commands: Commands) {
+    commands
+        .spawn((
+            Hud,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(96.0),
+                left: Val::Px(16.0),
+                column_gap: Val::Px(6.0),
+                ..default()
+            },
+        ))
+        .with_children(|row| {
+            for kind in StatusKind::ALL {
+                row.spawn((
+                    StatusIcon(kind),
+                    Node {
+                        display: Display::None,
+                        width: Val::Px(ICON_SIZE),
+                        height: Val::Px(ICON_SIZE),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(kind.color()),
+                ))
+                .with_children(|icon| {
+                    icon.spawn((
+                        StatusIconText(kind),
+                        Text::new(""),
+                        TextFont {
+                            font_size: 11.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        TextLayout::new_with_justify(Justify::Center),
+                    ));
+                });
+            }
+        });
+}
+
+/// Shows each active effect with its stack count and seconds left.
+pub fn update_status_icons(
+    player_query: Query<&StatusEffects, With<Player>>,
+    mut icons: Query<(&StatusIcon, &mut Node)>,
+    mut texts: Query<(&StatusIconText, &mut Text)>,
+) {
+    let Ok(effects) = player_query.single() else {
+        return;
+    };
+
+    for (icon, mut node) in icons.iter_mut() {
+        let display = match effects.get(icon.0) {
+            Some(_) => Display::Flex,
+            None => Display::None,
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    for (icon_text, mut text) in texts.iter_mut() {
+        let Some(effect) = effects.get(icon_text.0) else {
+            continue;
+        };
+        let stacks = match effect.stacks {
+            1 => String::new(),
+            stacks => format!(" x{}", stacks),
+        };
+        let label = format!("{}{}\n{}s", effect.kind.label(), stacks, effect.remaining.ceil() as u32);
+        if **text != label {
+            **text = label;
+        }
+    }
+}
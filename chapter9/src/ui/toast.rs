@@ -0,0 +1,179 @@
+// src/ui/toast.rs
+use bevy::prelude::*;
+
+use crate::config::ui::{INVENTORY_ICON_SIZE, MAX_TOASTS, TOAST_DURATION_SECS, TOAST_FADE_SECS};
+use crate::inventory::ItemPickedUp;
+use crate::map::assets::TilemapHandles;
+use crate::map::tilemap::TILEMAP;
+
+const TOAST_COLOR: Color = Color::srgba(0.08, 0.08, 0.1, 0.85);
+
+/// Plugin for short-lived notifications stacked in the bottom-right corner.
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_toast_stack)
+            .add_observer(show_toast)
+            .add_observer(toast_on_pickup)
+            .add_systems(Update, fade_toasts);
+    }
+}
+
+/// Trigger to show a notification, e.g. `commands.trigger(ShowToast::new("Saved"))`.
+#[derive(Event, Clone)]
+pub struct ShowToast {
+    pub text: String,
+    /// Name of a tilemap atlas sprite drawn next to the text
+    pub icon: Option<&'static str>,
+    pub duration: f32,
+}
+
+impl ShowToast {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            icon: None,
+            duration: TOAST_DURATION_SECS,
+        }
+    }
+
+    pub fn with_icon(mut self, sprite_name: &'static str) -> Self {
+        self.icon = Some(sprite_name);
+        self
+    }
+
+    pub fn with_duration(mut self, secs: f32) -> Self {
+        self.duration = secs;
+        self
+    }
+}
+
+/// Column that holds the live toasts; lives for the whole session so
+/// notifications work in every state.
+#[derive(Component)]
+pub struct ToastStack;
+
+#[derive(Component)]
+pub struct Toast {
+    remaining: Timer,
+}
+
+fn spawn_toast_stack(mut commands: Commands) {
+    commands.spawn((
+        ToastStack,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(96.0),
+            right: Val::Px(16.0),
+            flex_direction: FlexDirection::ColumnReverse,
+            align_items: AlignItems::FlexEnd,
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        GlobalZIndex(10),
+    ));
+}
+
+fn show_toast(
+    toast: On<ShowToast>,
+    mut commands: Commands,
+    handles: Option<Res<TilemapHandles>>,
+    stacks: Query<(Entity, Option<&Children>), With<ToastStack>>,
+) {
+    let Ok((stack, children)) = stacks.single() else {
+        return;
+    };
+
+    // Drop the oldest toasts so the stack never grows past the limit
+    if let Some(children) = children {
+        let overflow = (children.len() + 1).saturating_sub(MAX_TOASTS);
+        for old in children.iter().take(overflow) {
+            commands.entity(old).despawn();
+        }
+    }
+
+    let icon = toast
+        .icon
+        .and_then(|name| TILEMAP.sprite_index(name))
+        .zip(handles.as_deref());
+
+    commands.entity(stack).with_children(|stack| {
+        stack
+            .spawn((
+                Toast {
+                    remaining: Timer::from_seconds(toast.duration, TimerMode::Once),
+                },
+                Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(TOAST_COLOR),
+            ))
+            .with_children(|row| {
+                if let Some((index, handles)) = icon {
+                    row.spawn((
+                        ImageNode::from_atlas_image(
+                            handles.image.clone(),
+                            TextureAtlas::from(handles.layout.clone()).with_index(index),
+                        ),
+                        Node {
+                            width: Val::Px(INVENTORY_ICON_SIZE),
+                            height: Val::Px(INVENTORY_ICON_SIZE),
+                            ..default()
+                        },
+                    ));
+                }
+                row.spawn((
+                    Text::new(toast.text.clone()),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+    });
+}
+
+fn toast_on_pickup(picked: On<ItemPickedUp>, mut commands: Commands) {
+    commands.trigger(
+        ShowToast::new(format!("Picked up {}", picked.kind)).with_icon(picked.kind.icon_sprite()),
+    );
+}
+
+/// Counts toasts down, fading them over their last moments before despawning.
+pub fn fade_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toasts: Query<(Entity, &mut Toast, &mut BackgroundColor, &Children)>,
+    mut contents: Query<(Option<&mut TextColor>, Option<&mut ImageNode>)>,
+) {
+    for (entity, mut toast, mut background, children) in toasts.iter_mut() {
+        toast.remaining.tick(time.delta());
+        if toast.remaining.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let left = toast.remaining.remaining_secs();
+        if left >= TOAST_FADE_SECS {
+            continue;
+        }
+        let alpha = left / TOAST_FADE_SECS;
+        background.0 = TOAST_COLOR.with_alpha(TOAST_COLOR.alpha() * alpha);
+        for child in children.iter() {
+            let Ok((text_color, image)) = contents.get_mut(child) else {
+                continue;
+            };
+            if let Some(mut text_color) = text_color {
+                text_color.0.set_alpha(alpha);
+            }
+            if let Some(mut image) = image {
+                image.color.set_alpha(alpha);
+            }
+        }
+    }
+}
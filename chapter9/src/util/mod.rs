@@ -0,0 +1,4 @@
+// src/util/mod.rs
+//! Small helpers shared by systems across the game.
+
+pub mod picking;
@@ -0,0 +1,87 @@
+// src/util/picking.rs
+//! Converting between the cursor, the screen and the world.
+//!
+//! `CursorPicker` bundles the window, main camera, collision map and spatial
+//! hash so a system can ask what's under the cursor in one call:
+//!
+//! ```ignore
+//! fn inspect(picker: CursorPicker) {
+//!     if let Some(cell) = picker.tile() {
+//!         info!("Hovering {:?}", cell);
+//!     }
+//! }
+//! ```
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::camera::MainCamera;
+use crate::collision::{CollisionMap, SpatialHash};
+
+/// World position seen at `screen`, a position in window coordinates.
+///
+/// Goes through the camera's transform, zoom and viewport, so it holds in
+/// free-fly mode and with split or letterboxed viewports.
+pub fn screen_to_world(camera: &Camera, camera_transform: &GlobalTransform, screen: Vec2) -> Option<Vec2> {
+    camera.viewport_to_world_2d(camera_transform, screen).ok()
+}
+
+/// Window position a world position is drawn at, if the camera sees it.
+pub fn world_to_screen(camera: &Camera, camera_transform: &GlobalTransform, world: Vec2) -> Option<Vec2> {
+    camera.world_to_viewport(camera_transform, world.extend(0.0)).ok()
+}
+
+/// Everything needed to find what's under the cursor.
+#[derive(SystemParam)]
+pub struct CursorPicker<'w, 's> {
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<MainCamera>>,
+    map: Option<Res<'w, CollisionMap>>,
+    hash: Option<Res<'w, SpatialHash>>,
+}
+
+impl CursorPicker<'_, '_> {
+    /// World position under the cursor, or `None` if the cursor is outside
+    /// the window.
+    pub fn world_position(&self) -> Option<Vec2> {
+        let cursor = self.windows.single().ok()?.cursor_position()?;
+        let (camera, camera_transform) = self.cameras.single().ok()?;
+        screen_to_world(camera, camera_transform, cursor)
+    }
+
+    /// Window position a world position is drawn at through the main camera.
+    pub fn world_to_screen(&self, world: Vec2) -> Option<Vec2> {
+        let (camera, camera_transform) = self.cameras.single().ok()?;
+        world_to_screen(camera, camera_transform, world)
+    }
+
+    /// The collision map, once it's built.
+    pub fn map(&self) -> Option<&CollisionMap> {
+        self.map.as_deref()
+    }
+
+    /// Collision map cell under the cursor. May be out of bounds; check with
+    /// `CollisionMap::in_bounds` before indexing.
+    pub fn tile(&self) -> Option<IVec2> {
+        let world = self.world_position()?;
+        Some(self.map()?.world_to_grid(world))
+    }
+
+    /// Entities with a `Collider` within `radius` of the cursor, nearest first.
+    pub fn entities_within(&self, radius: f32) -> Vec<Entity> {
+        let (Some(world), Some(hash)) = (self.world_position(), self.hash.as_deref()) else {
+            return Vec::new();
+        };
+        let mut found: Vec<(Entity, f32)> = hash
+            .query_radius(world, radius)
+            .map(|(entity, position)| (entity, world.distance_squared(position)))
+            .collect();
+        found.sort_by(|a, b| a.1.total_cmp(&b.1));
+        found.into_iter().map(|(entity, _)| entity).collect()
+    }
+
+    /// The entity with a `Collider` nearest the cursor within `radius`.
+    pub fn entity_under_cursor(&self, radius: f32) -> Option<Entity> {
+        self.entities_within(radius).into_iter().next()
+    }
+}
@@ -0,0 +1,66 @@
+// src/wind/components.rs
+use bevy::prelude::*;
+
+use crate::clock::GameTimer;
+use crate::config::wind::{
+    CALM_STRENGTH, CANOPY_SWAY_PX, GUST_INTERVAL_SECS, PLANT_SWAY_PX, SWAY_SPEED, SWAY_WAVE,
+};
+use crate::rendering::CullBand;
+
+/// The wind blowing over the whole map.
+///
+/// Strength eases toward a new gust every `GUST_INTERVAL_SECS`; foliage
+/// reads it to sway. Runs on the game clock, so it stills while paused.
+#[derive(Resource, Debug, Clone)]
+pub struct Wind {
+    /// Current strength, 0 for still air and around 1 for a gust
+    pub strength: f32,
+    /// Strength the wind is easing toward
+    pub gust: f32,
+    /// Set while it rains, for stronger gusts
+    pub raining: bool,
+    pub(crate) gust_timer: GameTimer,
+    /// Game seconds of wind so far, the phase of every sway
+    pub(crate) elapsed: f32,
+}
+
+impl Default for Wind {
+    fn default() -> Self {
+        Self {
+            strength: CALM_STRENGTH,
+            gust: CALM_STRENGTH,
+            raining: false,
+            gust_timer: GameTimer::from_seconds(GUST_INTERVAL_SECS, TimerMode::Repeating),
+            elapsed: 0.0,
+        }
+    }
+}
+
+impl Wind {
+    /// How far, from -1 to 1 times the strength, something at `world_y`
+    /// leans right now.
+    pub fn sway(&self, world_y: f32) -> f32 {
+        self.strength * (self.elapsed * SWAY_SPEED + world_y * SWAY_WAVE).sin()
+    }
+}
+
+/// Sways a sprite sideways with the `Wind`, by shifting its anchor so the
+/// transform (and everything that reads it) stays put.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(CullBand)]
+pub struct Sway {
+    /// Furthest the sprite moves at full strength, in pixels
+    pub amplitude: f32,
+}
+
+impl Sway {
+    /// Tree tops; trunks stay still.
+    pub fn canopy() -> Self {
+        Self { amplitude: CANOPY_SWAY_PX }
+    }
+
+    /// Small plants, bushes and reeds.
+    pub fn plant() -> Self {
+        Self { amplitude: PLANT_SWAY_PX }
+    }
+}
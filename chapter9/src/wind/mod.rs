@@ -0,0 +1,27 @@
+// src/wind/mod.rs
+mod components;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::state::GameState;
+
+pub use components::{Sway, Wind};
+
+/// Plugin for the wind and the foliage swaying in it.
+///
+/// `Wind` gusts over time, harder while it rains. Tree canopies, plants and
+/// reeds with a `Sway` lean with it, so the generated forests don't sit
+/// perfectly still.
+pub struct WindPlugin;
+
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Wind>().add_systems(
+            Update,
+            (systems::update_wind, systems::sway_foliage)
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
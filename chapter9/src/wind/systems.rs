@@ -0,0 +1,43 @@
+// src/wind/systems.rs
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use rand::Rng;
+
+use super::components::{Sway, Wind};
+use crate::clock::GameClock;
+use crate::config::wind::{CALM_STRENGTH, GUST_EASE, GUST_STRENGTH, RAIN_MULTIPLIER};
+use crate::map::tilemap::TILEMAP;
+use crate::rendering::CullBand;
+
+/// Picks a new gust now and then and eases the wind toward it.
+pub fn update_wind(clock: Res<GameClock>, mut wind: ResMut<Wind>) {
+    let delta = clock.delta_secs();
+    if delta == 0.0 {
+        return;
+    }
+    wind.elapsed += delta;
+
+    if wind.gust_timer.tick(&clock).just_finished() {
+        let multiplier = if wind.raining { RAIN_MULTIPLIER } else { 1.0 };
+        wind.gust = rand::thread_rng().gen_range(CALM_STRENGTH..GUST_STRENGTH) * multiplier;
+    }
+    let ease = 1.0 - (-GUST_EASE * delta).exp();
+    wind.strength += (wind.gust - wind.strength) * ease;
+}
+
+/// Shifts on-screen foliage sideways with the wind.
+pub fn sway_foliage(
+    wind: Res<Wind>,
+    mut foliage: Query<(&Sway, &CullBand, &GlobalTransform, &mut Anchor)>,
+) {
+    // Foliage is all one atlas tile wide
+    let width = TILEMAP.tile_size().x as f32;
+    for (sway, band, transform, mut anchor) in foliage.iter_mut() {
+        if !band.is_on_screen() {
+            continue;
+        }
+        // The anchor is in sprite widths and points the other way
+        let offset = wind.sway(transform.translation().y) * sway.amplitude;
+        anchor.0.x = -offset / width;
+    }
+}
@@ -0,0 +1,117 @@
+// tests/headless.rs
+//! Whole-game checks on the windowless app from `chapter9::headless`.
+use std::collections::{HashSet, VecDeque};
+
+use bevy::prelude::*;
+use chapter9::collision::TileType;
+use chapter9::headless::{self, InputAction, READY_TIMEOUT_TICKS};
+
+const SEED: u64 = 42;
+
+/// Ticks to hold each direction; long enough to cross several tiles
+const WALK_TICKS: u32 = 240;
+
+const WALK_DIRECTIONS: [InputAction; 4] = [
+    InputAction::MoveLeft,
+    InputAction::MoveDown,
+    InputAction::MoveRight,
+    InputAction::MoveUp,
+];
+
+fn ready_app(seed: u64) -> App {
+    let mut app = headless::headless_app(seed);
+    assert!(
+        headless::run_until_ready(&mut app, READY_TIMEOUT_TICKS),
+        "world for seed {seed} was not ready after {READY_TIMEOUT_TICKS} ticks"
+    );
+    app
+}
+
+/// Walks the player in each direction in turn, calling `on_tick` after
+/// every tick.
+fn walk_square(app: &mut App, mut on_tick: impl FnMut(&mut App)) {
+    for action in WALK_DIRECTIONS {
+        headless::press(app, action);
+        for _ in 0..WALK_TICKS {
+            headless::step(app, 1);
+            on_tick(app);
+        }
+        headless::release(app, action);
+    }
+}
+
+#[test]
+fn same_seed_gives_same_world_and_movement() {
+    let mut first = ready_app(SEED);
+    let mut second = ready_app(SEED);
+
+    let checksum = |app: &App| headless::collision_map(app).map(headless::collision_checksum);
+    assert_eq!(checksum(&first), checksum(&second), "collision maps differ");
+    assert_eq!(
+        headless::player_position(&mut first),
+        headless::player_position(&mut second),
+        "player spawned in different places"
+    );
+
+    walk_square(&mut first, |_| {});
+    walk_square(&mut second, |_| {});
+    assert_eq!(
+        headless::player_position(&mut first),
+        headless::player_position(&mut second),
+        "same input moved the player to different places"
+    );
+}
+
+#[test]
+fn water_is_ringed_by_shore_from_spawn() {
+    let mut app = ready_app(SEED);
+    let spawn = headless::player_position(&mut app).expect("player spawned");
+    let map = headless::collision_map(&app).expect("collision map built");
+    let start = map.world_to_grid(spawn);
+    assert!(map.is_walkable(start.x, start.y), "player spawned on a blocked tile at {start}");
+
+    // Flood fill over the moves the pathfinder allows. Walkable ground may
+    // only touch water through shore: anywhere else the player could walk
+    // up to open water with nothing marking the edge.
+    let mut reached = HashSet::from([start]);
+    let mut frontier = VecDeque::from([start]);
+    let mut shore_cells = 0;
+    while let Some(cell) = frontier.pop_front() {
+        if map.get_tile(cell.x, cell.y) == Some(TileType::Shore) {
+            shore_cells += 1;
+        } else {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let next = cell + IVec2::new(dx, dy);
+                    assert_ne!(
+                        map.get_tile(next.x, next.y),
+                        Some(TileType::Water),
+                        "{:?} at {cell} borders water at {next} without shore",
+                        map.get_tile(cell.x, cell.y)
+                    );
+                }
+            }
+        }
+        for next in map.get_neighbors(cell) {
+            if reached.insert(next) {
+                frontier.push_back(next);
+            }
+        }
+    }
+    assert!(shore_cells > 0, "no shore reachable from spawn, so no water edge was checked");
+}
+
+#[test]
+fn walking_from_spawn_never_enters_water() {
+    let mut app = ready_app(SEED);
+    walk_square(&mut app, |app| {
+        let position = headless::player_position(app).expect("player spawned");
+        let map = headless::collision_map(app).expect("collision map built");
+        let cell = map.world_to_grid(position);
+        assert_ne!(
+            map.get_tile(cell.x, cell.y),
+            Some(TileType::Water),
+            "player walked into water at {cell}"
+        );
+    });
+}